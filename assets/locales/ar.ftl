@@ -0,0 +1,7 @@
+-app-name = إيبوي
+
+greeting = مرحبًا بك في { -app-name }، { $name }!
+unread-messages = لديك { $count } رسائل غير مقروءة.
+switch-locale =
+    اضغط على المسافة للتبديل
+    بين الإنجليزية والعربية.
@@ -0,0 +1,7 @@
+-app-name = Epui
+
+greeting = Welcome to { -app-name }, { $name }!
+unread-messages = You have { $count } unread messages.
+switch-locale =
+    Press Space to switch
+    between English and Arabic.
@@ -0,0 +1,96 @@
+#![feature(test)]
+
+extern crate test;
+
+use bevy::prelude::{App, BuildWorldChildren, Entity, World};
+use epui::{
+    prelude::{AutoZUpdate, Position, Size},
+    property::{
+        update::{AutoVisibleRegionUpdate, UiUpdatePropertiesPlugin},
+        VisibleRegion, ZLevel,
+    },
+};
+use test::Bencher;
+
+const DEEP_TREE_DEPTH: usize = 1_000;
+const WIDE_TREE_WIDTH: usize = 10_000;
+
+fn spawn_element(world: &mut World, parent: Option<Entity>) -> Entity {
+    let mut element = world.spawn((
+        Position::new(0, 0),
+        Size::new(100, 100),
+        ZLevel::default(),
+        AutoZUpdate,
+        VisibleRegion::default(),
+        AutoVisibleRegionUpdate,
+    ));
+
+    if let Some(parent) = parent {
+        element.set_parent(parent);
+    }
+
+    element.id()
+}
+
+// Returns the app with the propagation already settled & the root of the tree
+fn deep_tree() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(UiUpdatePropertiesPlugin);
+
+    let root = spawn_element(&mut app.world, None);
+    let mut parent = root;
+
+    for _ in 0..DEEP_TREE_DEPTH {
+        parent = spawn_element(&mut app.world, Some(parent));
+    }
+
+    app.update();
+    (app, root)
+}
+
+fn wide_tree() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(UiUpdatePropertiesPlugin);
+
+    let root = spawn_element(&mut app.world, None);
+
+    for _ in 0..WIDE_TREE_WIDTH {
+        spawn_element(&mut app.world, Some(root));
+    }
+
+    app.update();
+    (app, root)
+}
+
+#[bench]
+fn deep_tree_z_level_change(bencher: &mut Bencher) {
+    let (mut app, root) = deep_tree();
+    let mut z_level = 0;
+
+    bencher.iter(|| {
+        z_level = (z_level + 1) % 2;
+        app.world.get_mut::<ZLevel>(root).unwrap().0 = z_level;
+
+        app.update();
+    });
+}
+
+#[bench]
+fn wide_tree_position_change(bencher: &mut Bencher) {
+    let (mut app, root) = wide_tree();
+    let mut x = 0;
+
+    bencher.iter(|| {
+        x = (x + 1) % 100;
+        app.world.get_mut::<Position>(root).unwrap().x = x;
+
+        app.update();
+    });
+}
+
+#[bench]
+fn wide_tree_unchanged(bencher: &mut Bencher) {
+    let (mut app, _) = wide_tree();
+
+    bencher.iter(|| app.update());
+}
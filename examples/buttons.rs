@@ -1,124 +1,74 @@
-use std::time::Duration;
-
 use bevy::{
     log::info,
     prelude::{
-        App, BuildChildren, Color, Commands, Component, Entity, EventReader, Query, Startup,
-        Update, With,
+        App, Commands, Entity, EventReader, Has, Input, KeyCode, Query, Res, Startup, Update, With,
     },
     DefaultPlugins,
 };
 
-use epui::{
-    event::ClickEvent,
-    prelude::*,
-    property::{
-        collision::BoxCollisionBundle,
-        state::{click::ClickEffectTransition, hover::HoverEffectTransition},
-    },
-};
+use epui::prelude::*;
 
 fn main() {
     App::new()
         .add_systems(Startup, setup)
         .add_plugins(DefaultPlugins)
         .add_plugins(UiPlugin)
-        .add_systems(Update, on_button_click)
+        .add_systems(Update, (on_button_activated, toggle_disabled))
         .run();
 }
 
-#[derive(Component)]
-struct Button;
-
 fn setup(mut commands: Commands) {
     commands.spawn(UiCameraBundle::default());
 
-    commands
-        .spawn((
-            UiBoxBundle {
-                position: Position::new(50, 50),
-                size: Size::new(200, 60),
-
-                color: ColoredElement::new(Color::GRAY),
-
-                ..Default::default()
-            },
-            CornersRoundness::from_scalar(1.0f32),
-            ClickEffectTransition::new(
-                ColoredElement::new(Color::DARK_GREEN),
-                Duration::from_millis(200),
-                Duration::from_millis(400),
-            ),
-            HoverEffectTransition::new(
-                ColoredElement::new(Color::DARK_GRAY),
-                Duration::from_millis(100),
-                Duration::from_millis(200),
-            ),
-            BoxCollisionBundle::new(),
-            Button,
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                UiTextBundle {
-                    text: UiText(String::from("Button A")),
-                    font_size: FontSize(32),
-
-                    position: Position::new(60, 60),
-                    size: Size::new(180, 40),
-
-                    ..Default::default()
-                },
-                BoxCollisionBundle::new(),
-            ));
-        });
-
-    commands
-        .spawn((
-            UiBoxBundle {
-                position: Position::new(50, 150),
-                size: Size::new(200, 60),
-                color: ColoredElement::new(Color::GRAY),
-
-                ..Default::default()
-            },
-            ClickEffectTransition::new(
-                ColoredElement::new(Color::DARK_GREEN),
-                Duration::from_millis(200),
-                Duration::from_millis(400),
-            ),
-            HoverEffectTransition::new(
-                ColoredElement::new(Color::DARK_GRAY),
-                Duration::from_millis(100),
-                Duration::from_millis(200),
-            ),
-            AABBCollisionBundle::new(),
-            Button,
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                UiTextBundle {
-                    text: UiText(String::from("Button B")),
-                    font_size: FontSize(32),
-
-                    position: Position::new(60, 160),
-                    size: Size::new(180, 40),
-
-                    ..Default::default()
-                },
-                BoxCollisionBundle::new(),
-            ));
-        });
+    commands.spawn(UiButtonBundle {
+        position: Position::new(50, 50),
+        ..UiButtonBundle::new("Button A")
+    });
+
+    commands.spawn(UiButtonBundle {
+        position: Position::new(50, 150),
+        corners_roundness: CornersRoundness::from_scalar(0.0),
+        ..UiButtonBundle::new("Button B")
+    });
+
+    commands.spawn((
+        UiButtonBundle {
+            position: Position::new(50, 250),
+            ..UiButtonBundle::new("Disabled")
+        },
+        Disabled,
+    ));
 }
 
-fn on_button_click(
-    buttons: Query<Entity, With<Button>>,
-    mut click_events: EventReader<ClickEvent>,
+// Tab focuses the buttons in turn, Enter or Space then activates the focused one
+fn on_button_activated(
+    buttons: Query<&ButtonLabel>,
+    mut activated_events: EventReader<ButtonActivated>,
 ) {
-    for click_event in click_events.read() {
-        let Ok(_) = buttons.get(click_event.element) else {
+    for activated_event in activated_events.read() {
+        let Ok(label) = buttons.get(activated_event.button) else {
             continue;
         };
 
-        info!("Button got clicked !");
+        info!("{} got activated !", label.text);
+    }
+}
+
+// D toggles which buttons are disabled
+fn toggle_disabled(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    buttons: Query<(Entity, Has<Disabled>), With<UiButton>>,
+) {
+    if !keys.just_pressed(KeyCode::D) {
+        return;
+    }
+
+    for (button, disabled) in buttons.iter() {
+        if disabled {
+            commands.entity(button).remove::<Disabled>();
+        } else {
+            commands.entity(button).insert(Disabled);
+        }
     }
 }
@@ -0,0 +1,66 @@
+use bevy::{
+    log::info,
+    prelude::{App, Commands, EventReader, Startup, Update},
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_table_events)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+const ITEMS: [&str; 6] = ["Sword", "Shield", "Potion", "Arrow", "Helmet", "Ring"];
+const RARITIES: [&str; 4] = ["Common", "Uncommon", "Rare", "Legendary"];
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    // Only the rows in view get entities, so long tables stay cheap
+    let rows = (0..20_000)
+        .map(|index: usize| {
+            vec![
+                format!("{} #{}", ITEMS[index % ITEMS.len()], index + 1),
+                RARITIES[index * 7 % RARITIES.len()].to_string(),
+                (index * 37 % 99 + 1).to_string(),
+                format!("{:.1}", (index * 13 % 250) as f32 / 10.0),
+            ]
+        })
+        .collect();
+
+    commands.spawn(UiDataTableBundle {
+        position: Position::new(50, 50),
+        size: Size::new(640, 500),
+        ..UiDataTableBundle::new(
+            DataTable::new([
+                DataColumn::new("Item", 220).with_min_width(100),
+                DataColumn::new("Rarity", 140),
+                DataColumn::new("Quantity", 120).with_alignment(TextAlign::Right),
+                DataColumn::new("Weight", 120).with_alignment(TextAlign::Right),
+            ])
+            .with_rows(rows)
+            .with_sort(0, SortOrder::Ascending),
+        )
+    });
+}
+
+fn print_table_events(
+    mut sort_changed_events: EventReader<DataTableSortChangedEvent>,
+    mut selection_changed_events: EventReader<DataTableSelectionChangedEvent>,
+) {
+    for sort_changed_event in sort_changed_events.read() {
+        info!(
+            "Sorted by column {} ({:?})",
+            sort_changed_event.sort.column, sort_changed_event.sort.order
+        );
+    }
+
+    for selection_changed_event in selection_changed_events.read() {
+        info!("Selected rows {:?}", selection_changed_event.selected);
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::{
+    log::info,
+    prelude::{App, BuildChildren, Color, Commands, EventReader, Startup, Update},
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_selections)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    // The panel clips its children, the open option list still extends past it & covers the button below
+    commands
+        .spawn(UiBoxBundle {
+            position: Position::new(40, 40),
+            size: Size::new(240, 80),
+            color: ColoredElement::new(Color::ALICE_BLUE),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn(UiDropdownBundle {
+                position: Position::new(60, 62),
+                ..UiDropdownBundle::new(
+                    Dropdown::new([
+                        "Apple",
+                        "Apricot",
+                        "Banana",
+                        "Blueberry",
+                        "Cherry",
+                        "Grape",
+                        "Lemon",
+                    ])
+                    .with_placeholder("Pick a fruit"),
+                )
+            });
+        });
+
+    commands.spawn(UiButtonBundle {
+        position: Position::new(40, 160),
+        ..UiButtonBundle::new("Covered")
+    });
+
+    commands.spawn(UiDropdownBundle {
+        position: Position::new(320, 62),
+        ..UiDropdownBundle::new(Dropdown::new(["Small", "Medium", "Large"]).with_selected(1))
+    });
+}
+
+fn print_selections(mut selection_changed_events: EventReader<SelectionChangedEvent>) {
+    for selection_changed_event in selection_changed_events.read() {
+        info!(
+            "{:?} selected {} ({})",
+            selection_changed_event.dropdown,
+            selection_changed_event.value,
+            selection_changed_event.index
+        );
+    }
+}
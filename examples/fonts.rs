@@ -0,0 +1,52 @@
+use bevy::{
+    prelude::{App, AssetServer, Color, Commands, Res, Startup},
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(UiCameraBundle::default());
+
+    let styles = [
+        ("Regular", FontWeight::NORMAL, FontStyle::Normal),
+        ("Bold", FontWeight::BOLD, FontStyle::Normal),
+        ("Italic", FontWeight::NORMAL, FontStyle::Italic),
+        ("Bold Italic", FontWeight::BOLD, FontStyle::Italic),
+    ];
+
+    for (index, (text, font_weight, font_style)) in styles.into_iter().enumerate() {
+        commands.spawn(UiTextBundle {
+            text: UiText(String::from(text)),
+            font_size: FontSize(32),
+            font_weight,
+            font_style,
+            color: ColoredElement::new(Color::BLACK),
+
+            position: Position::new(50, 50 + index as u32 * 50),
+            size: Size::new(500, 50),
+
+            ..Default::default()
+        });
+    }
+
+    commands.spawn(UiTextBundle {
+        text: UiText(String::from("fn main() { println!(\"Loaded font\"); }")),
+        font_size: FontSize(24),
+        font_family: FontFamily::Font(asset_server.load("fonts/DejaVuSansMono.ttf")),
+        color: ColoredElement::new(Color::DARK_GREEN),
+
+        position: Position::new(50, 270),
+        size: Size::new(700, 50),
+
+        ..Default::default()
+    });
+}
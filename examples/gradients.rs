@@ -0,0 +1,73 @@
+use std::{f32::consts::PI, time::Duration};
+
+use bevy::{
+    prelude::{App, Color, Commands, Startup, Vec2},
+    DefaultPlugins,
+};
+
+use epui::{prelude::*, property::state::hover::HoverEffectTransition};
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    let fills = [
+        Fill::linear(
+            PI / 2.0,
+            vec![
+                ColorStop::new(Color::ORANGE_RED, 0.0),
+                ColorStop::new(Color::GOLD, 1.0),
+            ],
+        ),
+        Fill::radial(
+            Vec2::splat(0.5),
+            1.0,
+            vec![
+                ColorStop::new(Color::WHITE, 0.0),
+                ColorStop::new(Color::MIDNIGHT_BLUE, 1.0),
+            ],
+        ),
+        Fill::conic(
+            Vec2::splat(0.5),
+            0.0,
+            vec![
+                ColorStop::new(Color::RED, 0.0),
+                ColorStop::new(Color::YELLOW, 0.25),
+                ColorStop::new(Color::GREEN, 0.5),
+                ColorStop::new(Color::BLUE, 0.75),
+                ColorStop::new(Color::RED, 1.0),
+            ],
+        ),
+    ];
+
+    for (index, fill) in fills.into_iter().enumerate() {
+        commands.spawn((
+            UiBoxBundle {
+                position: Position::new(40 + index as u32 * 220, 40),
+                size: Size::new(200, 200),
+                ..Default::default()
+            },
+            CornersRoundness::from_scalar(0.2f32),
+            fill,
+            HoverEffectTransition::new(
+                Fill::linear(
+                    PI,
+                    vec![
+                        ColorStop::new(Color::TEAL, 0.0),
+                        ColorStop::new(Color::PURPLE, 1.0),
+                    ],
+                ),
+                Duration::from_millis(400),
+                Duration::from_millis(400),
+            ),
+            BoxCollisionBundle::new(),
+        ));
+    }
+}
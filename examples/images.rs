@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{App, AssetServer, Color, Commands, Res, Startup, Vec2},
+    DefaultPlugins,
+};
+
+use epui::{prelude::*, property::ZLevel};
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(UiCameraBundle::default());
+
+    let icons = asset_server.load("icons.png");
+
+    for (index, fit) in [
+        ImageFit::Fill,
+        ImageFit::Contain,
+        ImageFit::Cover,
+        ImageFit::None,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        commands.spawn(UiBoxBundle {
+            position: Position::new(40 + index as u32 * 180, 40),
+            size: Size::new(160, 100),
+            color: ColoredElement::new(Color::GRAY),
+            ..Default::default()
+        });
+
+        commands.spawn(UiImageBundle {
+            image: UiImage::new(icons.clone()),
+            fit,
+
+            position: Position::new(40 + index as u32 * 180, 40),
+            size: Size::new(160, 100),
+
+            z_level: ZLevel(1),
+
+            ..Default::default()
+        });
+    }
+
+    for index in 0..2 {
+        commands.spawn((
+            UiImageBundle {
+                image: UiImage::new(icons.clone()),
+                color: ColoredElement::new(if index == 0 {
+                    Color::WHITE
+                } else {
+                    Color::GOLD
+                }),
+
+                position: Position::new(40 + index as u32 * 120, 180),
+                size: Size::new(100, 100),
+
+                ..Default::default()
+            },
+            TextureAtlas::new(TextureAtlasLayout::grid(Vec2::splat(32.0), 2, 1), index),
+            CornersRoundness::from_scalar(1.0f32),
+        ));
+    }
+
+    commands.spawn((
+        UiImageBundle {
+            image: UiImage::new(icons.clone()),
+
+            position: Position::new(300, 180),
+            size: Size::new(64, 64),
+
+            ..Default::default()
+        },
+        TextureAtlas::new(TextureAtlasLayout::grid(Vec2::splat(32.0), 2, 1), 1),
+        NineSlice::from_inset(10.0).with_modes(SliceScaleMode::Tile, SliceScaleMode::Stretch),
+        Transition::new(Size::new(320, 200), Duration::from_millis(2000)),
+    ));
+}
@@ -0,0 +1,68 @@
+use bevy::{
+    prelude::{App, AssetServer, Color, Commands, Input, KeyCode, Res, ResMut, Startup, Update},
+    DefaultPlugins,
+};
+
+use epui::{prelude::*, property::ZLevel};
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, switch_locale)
+        // Arabic needs a font with its glyphs, the bundled one doesn't have them
+        .insert_resource(UiFontSettings {
+            load_system_fonts: true,
+        })
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(UiCameraBundle::default());
+
+    commands.insert_resource(
+        UiLocale::new("en-US")
+            .with_translation("en-US", asset_server.load("locales/en-US.ftl"))
+            .with_translation("ar", asset_server.load("locales/ar.ftl")),
+    );
+
+    let messages = [
+        LocalizedText::new("greeting").with_arg("name", "Alex"),
+        LocalizedText::new("unread-messages").with_arg("count", 3),
+        LocalizedText::new("switch-locale"),
+    ];
+
+    for (index, localized_text) in messages.into_iter().enumerate() {
+        let position = Position::new(40, 40 + index as u32 * 80);
+        let size = Size::new(400, 60);
+
+        commands.spawn(UiBoxBundle {
+            position: position.clone(),
+            size: size.clone(),
+            color: ColoredElement::new(Color::GRAY),
+            ..Default::default()
+        });
+
+        // Start alignment follows the paragraph direction, so the Arabic messages are right aligned
+        commands.spawn((
+            UiTextBundle {
+                font_size: FontSize(20),
+                position,
+                size,
+                z_level: ZLevel(1),
+                ..Default::default()
+            },
+            localized_text,
+        ));
+    }
+}
+
+fn switch_locale(keys: Res<Input<KeyCode>>, mut locale: ResMut<UiLocale>) {
+    if keys.just_pressed(KeyCode::Space) {
+        locale.current = match locale.current.as_str() {
+            "ar" => String::from("en-US"),
+            _ => String::from("ar"),
+        };
+    }
+}
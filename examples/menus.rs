@@ -0,0 +1,94 @@
+use bevy::{
+    log::info,
+    prelude::{App, Color, Commands, EventReader, KeyCode, Startup, Update},
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_activations)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    commands.spawn(UiMenuBarBundle::new(MenuBar::new([
+        MenuItem::submenu(
+            "File",
+            [
+                MenuItem::new("new", "New")
+                    .with_accelerator(MenuAccelerator::ctrl(KeyCode::N))
+                    .into(),
+                MenuItem::new("open", "Open…")
+                    .with_accelerator(MenuAccelerator::ctrl(KeyCode::O))
+                    .into(),
+                MenuItem::submenu(
+                    "Open Recent",
+                    [
+                        MenuItem::new("recent-1", "scene.ron").into(),
+                        MenuItem::new("recent-2", "level_2.ron").into(),
+                    ],
+                )
+                .into(),
+                MenuEntry::Separator,
+                MenuItem::new("save", "Save")
+                    .with_accelerator(MenuAccelerator::ctrl(KeyCode::S))
+                    .into(),
+                MenuItem::new("save-as", "Save As…")
+                    .with_accelerator(MenuAccelerator::ctrl(KeyCode::S).with_shift())
+                    .with_disabled(true)
+                    .into(),
+            ],
+        ),
+        MenuItem::submenu(
+            "View",
+            [
+                MenuItem::new("grid", "Show Grid").with_checked(true).into(),
+                MenuItem::new("gizmos", "Show Gizmos")
+                    .with_checked(false)
+                    .into(),
+            ],
+        ),
+        MenuItem::submenu("Help", [MenuItem::new("about", "About").into()]),
+    ])));
+
+    commands.spawn((
+        UiBoxBundle {
+            position: Position::new(100, 120),
+            size: Size::new(300, 200),
+            color: ColoredElement::new(Color::ALICE_BLUE),
+            ..Default::default()
+        },
+        BoxCollisionBundle::default(),
+        ContextMenu::new([
+            MenuItem::new("cut", "Cut").into(),
+            MenuItem::new("copy", "Copy").into(),
+            MenuItem::new("paste", "Paste").with_disabled(true).into(),
+            MenuEntry::Separator,
+            MenuItem::submenu(
+                "Align",
+                [
+                    MenuItem::new("align-left", "Left").into(),
+                    MenuItem::new("align-center", "Center").into(),
+                    MenuItem::new("align-right", "Right").into(),
+                ],
+            )
+            .into(),
+        ]),
+    ));
+}
+
+fn print_activations(mut activated_events: EventReader<MenuItemActivatedEvent>) {
+    for activated_event in activated_events.read() {
+        match activated_event.checked {
+            Some(checked) => info!("{} is now {}", activated_event.id, checked),
+            None => info!("Activated {}", activated_event.id),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use bevy::{
+    hierarchy::HierarchyQueryExt,
+    log::info,
+    prelude::{
+        App, BuildChildren, Color, Commands, Component, DespawnRecursiveExt, EventReader, Parent,
+        Query, Startup, Update, With,
+    },
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (on_button_activated, on_modal_closed))
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+#[derive(Component)]
+enum DialogButton {
+    Open,
+    OpenStacked,
+    Close,
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    commands.spawn((
+        UiButtonBundle {
+            position: Position::new(50, 50),
+            ..UiButtonBundle::new("Delete")
+        },
+        DialogButton::Open,
+    ));
+
+    // Blocked while a modal is open
+    commands.spawn(UiButtonBundle {
+        position: Position::new(50, 150),
+        ..UiButtonBundle::new("Behind")
+    });
+}
+
+fn spawn_dialog(commands: &mut Commands, position: Position, message: &str, stacked: bool) {
+    commands
+        .spawn(UiModalBundle::default())
+        .with_children(|modal| {
+            modal
+                .spawn(UiBoxBundle {
+                    position: position.clone(),
+                    size: Size::new(440, 180),
+                    color: ColoredElement::new(Color::WHITE),
+                    ..Default::default()
+                })
+                .insert(CornersRoundness::from_scalar(0.1))
+                .with_children(|dialog| {
+                    dialog.spawn(UiTextBundle {
+                        text: UiText(message.to_string()),
+                        position: Position::new(position.x + 20, position.y + 20),
+                        size: Size::new(400, 40),
+                        ..Default::default()
+                    });
+
+                    let (label, button) = if stacked {
+                        ("Really?", DialogButton::OpenStacked)
+                    } else {
+                        ("Confirm", DialogButton::Close)
+                    };
+
+                    dialog.spawn((
+                        UiButtonBundle {
+                            position: Position::new(position.x + 20, position.y + 100),
+                            ..UiButtonBundle::new(label)
+                        },
+                        button,
+                    ));
+
+                    dialog.spawn((
+                        UiButtonBundle {
+                            position: Position::new(position.x + 220, position.y + 100),
+                            ..UiButtonBundle::new("Cancel")
+                        },
+                        DialogButton::Close,
+                    ));
+                });
+        });
+}
+
+fn on_button_activated(
+    mut commands: Commands,
+    buttons: Query<&DialogButton>,
+    parents: Query<&Parent>,
+    modals: Query<(), With<Modal>>,
+    mut activated_events: EventReader<ButtonActivated>,
+) {
+    for activated_event in activated_events.read() {
+        let Ok(dialog_button) = buttons.get(activated_event.button) else {
+            continue;
+        };
+
+        match dialog_button {
+            DialogButton::Open => spawn_dialog(
+                &mut commands,
+                Position::new(80, 80),
+                "Delete the file?",
+                true,
+            ),
+            DialogButton::OpenStacked => spawn_dialog(
+                &mut commands,
+                Position::new(140, 140),
+                "This can't be undone.",
+                false,
+            ),
+            DialogButton::Close => {
+                let modal = parents
+                    .iter_ancestors(activated_event.button)
+                    .find(|ancestor| modals.contains(*ancestor));
+
+                if let Some(modal) = modal {
+                    commands.entity(modal).despawn_recursive();
+                }
+            }
+        }
+    }
+}
+
+fn on_modal_closed(mut modal_closed_events: EventReader<ModalClosedEvent>) {
+    for modal_closed_event in modal_closed_events.read() {
+        info!("{:?} closed with Escape", modal_closed_event.modal);
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::{
+    log::info,
+    prelude::{App, Color, Commands, EventReader, Startup, Update},
+    DefaultPlugins,
+};
+
+use epui::{prelude::*, property::collision::BoxCollisionBundle};
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_links)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    commands.spawn((
+        UiRichTextBundle {
+            text: UiRichText::new([
+                TextSpan::new("Rich text mixes "),
+                TextSpan::new("bold").bold(),
+                TextSpan::new(", "),
+                TextSpan::new("italic").italic(),
+                TextSpan::new(", "),
+                TextSpan::new("colored").with_color(Color::RED),
+                TextSpan::new(", "),
+                TextSpan::new("monospace").with_family(FontFamily::Monospace),
+                TextSpan::new(", "),
+                TextSpan::new("underlined").underlined(),
+                TextSpan::new(" and "),
+                TextSpan::new("struck through").struck_through(),
+                TextSpan::new(" spans. Clicking "),
+                TextSpan::new("this link")
+                    .with_color(Color::BLUE)
+                    .underlined()
+                    .with_link("https://bevyengine.org"),
+                TextSpan::new(" sends an event."),
+            ]),
+            font_size: FontSize(24),
+            position: Position::new(40, 40),
+            size: Size::new(500, 200),
+            ..Default::default()
+        },
+        BoxCollisionBundle::new(),
+    ));
+}
+
+fn print_links(mut link_clicked_events: EventReader<TextLinkClickedEvent>) {
+    for link_clicked_event in link_clicked_events.read() {
+        info!("Clicked link {}", link_clicked_event.link);
+    }
+}
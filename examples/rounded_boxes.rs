@@ -12,6 +12,7 @@ fn main() {
         .add_systems(Startup, setup)
         .add_plugins(DefaultPlugins)
         .add_plugins(UiPlugin)
+        .insert_resource(UiMsaa::Off)
         .run();
 }
 
@@ -23,7 +24,7 @@ fn setup(mut commands: Commands) {
             commands.spawn((
                 UiBoxBundle {
                     position: Position::new(x * 60, y * 60),
-                    size: Size::new(50, 50),
+                    size: Size::new(50, 50 - (x % 2) * 20),
                     color: ColoredElement::new(Color::hsl(
                         (x as f32 / 10.0) * 360.0,
                         y as f32 / 10.0,
@@ -32,6 +33,11 @@ fn setup(mut commands: Commands) {
                     ..Default::default()
                 },
                 CornersRoundness::from_scalar(0.0f32),
+                if x % 2 == 0 {
+                    CornersShape::Circular
+                } else {
+                    CornersShape::Elliptical
+                },
                 Transition::new(
                     CornersRoundness::from_scalar(0.5f32),
                     Duration::from_millis(1000),
@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{App, Color, Commands, Startup, Vec2},
+    DefaultPlugins,
+};
+
+use epui::{prelude::*, property::state::hover::HoverEffectTransition};
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    commands.spawn((
+        UiBoxBundle {
+            position: Position::new(60, 60),
+            size: Size::new(240, 160),
+            color: ColoredElement::new(Color::WHITE),
+            ..Default::default()
+        },
+        CornersRoundness::from_scalar(0.2f32),
+        BoxShadow::new(
+            Vec2::new(0.0, 6.0),
+            16.0,
+            0.0,
+            Color::rgba(0.0, 0.0, 0.0, 0.3),
+        ),
+        HoverEffectTransition::new(
+            BoxShadow::new(
+                Vec2::new(0.0, 12.0),
+                32.0,
+                2.0,
+                Color::rgba(0.0, 0.0, 0.0, 0.4),
+            ),
+            Duration::from_millis(150),
+            Duration::from_millis(300),
+        ),
+        BoxCollisionBundle::new(),
+    ));
+
+    commands.spawn((
+        UiBoxBundle {
+            position: Position::new(360, 60),
+            size: Size::new(240, 160),
+            color: ColoredElement::new(Color::ALICE_BLUE),
+            ..Default::default()
+        },
+        CornersRoundness::from_scalar(0.5f32),
+        BoxShadows(vec![
+            BoxShadow::new(
+                Vec2::new(-6.0, -6.0),
+                12.0,
+                0.0,
+                Color::rgba(1.0, 0.0, 0.0, 0.5),
+            ),
+            BoxShadow::new(
+                Vec2::new(6.0, 6.0),
+                12.0,
+                0.0,
+                Color::rgba(0.0, 0.0, 1.0, 0.5),
+            ),
+            BoxShadow::new_inset(
+                Vec2::new(0.0, 2.0),
+                8.0,
+                0.0,
+                Color::rgba(0.0, 0.0, 0.0, 0.4),
+            ),
+        ]),
+    ));
+}
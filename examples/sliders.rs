@@ -0,0 +1,74 @@
+use bevy::{
+    log::info,
+    prelude::{App, Commands, EventReader, Startup, Update},
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_changes)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    // Volume, continuous
+    commands.spawn(UiSliderBundle {
+        slider: Slider {
+            value: 0.8,
+            ..Default::default()
+        },
+        position: Position::new(40, 40),
+        ..Default::default()
+    });
+
+    // Field of view in steps of 5 degrees
+    commands.spawn(UiSliderBundle {
+        slider: Slider {
+            min: 60.0,
+            max: 120.0,
+            step: 5.0,
+            value: 90.0,
+        },
+        position: Position::new(40, 100),
+        ..Default::default()
+    });
+
+    commands.spawn(UiRangeSliderBundle {
+        slider: RangeSlider {
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            low: 20.0,
+            high: 80.0,
+        },
+        position: Position::new(40, 160),
+        ..Default::default()
+    });
+
+    commands.spawn(UiSliderBundle {
+        slider: Slider {
+            value: 0.5,
+            ..Default::default()
+        },
+        orientation: SliderOrientation::Vertical,
+        position: Position::new(300, 40),
+        size: Size::new(24, 200),
+        ..Default::default()
+    });
+}
+
+fn print_changes(mut changed_events: EventReader<SliderChangedEvent>) {
+    for changed_event in changed_events.read() {
+        info!(
+            "{:?} changed to {:?}",
+            changed_event.slider, changed_event.value
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use bevy::{
+    prelude::{App, Color, Commands, Startup},
+    DefaultPlugins,
+};
+
+use epui::{prelude::*, property::ZLevel};
+
+const PARAGRAPH: &str = "The quick brown fox jumps over the lazy dog, then keeps running across the whole field until it gets out of sight.";
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    let layouts = [
+        (TextAlign::Left, VerticalAlign::Top, TextWrap::Word),
+        (TextAlign::Center, VerticalAlign::Center, TextWrap::Word),
+        (TextAlign::Right, VerticalAlign::Bottom, TextWrap::Glyph),
+        (TextAlign::Justify, VerticalAlign::Top, TextWrap::Word),
+    ];
+
+    for (index, (text_align, vertical_align, text_wrap)) in layouts.into_iter().enumerate() {
+        let position = Position::new(40 + index as u32 * 220, 40);
+        let size = Size::new(200, 160);
+
+        commands.spawn(UiBoxBundle {
+            position: position.clone(),
+            size: size.clone(),
+            color: ColoredElement::new(Color::GRAY),
+            ..Default::default()
+        });
+
+        commands.spawn((
+            UiTextBundle {
+                text: UiText(String::from(PARAGRAPH)),
+                position,
+                size,
+                z_level: ZLevel(1),
+                ..Default::default()
+            },
+            text_align,
+            vertical_align,
+            text_wrap,
+        ));
+    }
+
+    for (index, text_overflow) in [TextOverflow::Clip, TextOverflow::Ellipsis]
+        .into_iter()
+        .enumerate()
+    {
+        let position = Position::new(40 + index as u32 * 220, 240);
+        let size = Size::new(200, 60);
+
+        commands.spawn(UiBoxBundle {
+            position: position.clone(),
+            size: size.clone(),
+            color: ColoredElement::new(Color::GRAY),
+            ..Default::default()
+        });
+
+        commands.spawn((
+            UiTextBundle {
+                text: UiText(String::from(PARAGRAPH)),
+                position,
+                size,
+                z_level: ZLevel(1),
+                ..Default::default()
+            },
+            LineHeight::Pixels(28.0),
+            text_overflow,
+        ));
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::{
+    log::info,
+    prelude::{App, Color, Commands, EventReader, Startup, Update},
+    DefaultPlugins,
+};
+
+use epui::{prelude::*, property::ZLevel};
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_copied_text)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    commands.spawn(UiBoxBundle {
+        position: Position::new(40, 40),
+        size: Size::new(400, 120),
+        color: ColoredElement::new(Color::ALICE_BLUE),
+        ..Default::default()
+    });
+
+    commands.spawn((
+        UiTextBundle {
+            text: UiText(String::from(
+                "Error 0x2A: couldn't open the save file. Drag over this message to select it, then press Ctrl+C to copy it.",
+            )),
+            font_size: FontSize(20),
+            position: Position::new(50, 50),
+            size: Size::new(380, 100),
+            z_level: ZLevel(2),
+            ..Default::default()
+        },
+        SelectableText::default(),
+    ));
+}
+
+// An app would put the text in its clipboard here
+fn print_copied_text(mut copy_events: EventReader<TextCopyEvent>) {
+    for copy_event in copy_events.read() {
+        info!("Copied \"{}\"", copy_event.text);
+    }
+}
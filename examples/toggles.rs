@@ -0,0 +1,81 @@
+use bevy::{
+    log::info,
+    prelude::{App, BuildChildren, Color, Commands, EventReader, Startup, Update},
+    DefaultPlugins,
+};
+
+use epui::{prelude::*, property::ZLevel};
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_changes)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    commands.spawn(UiCheckboxBundle {
+        position: Position::new(40, 40),
+        ..Default::default()
+    });
+
+    commands.spawn(UiCheckboxBundle {
+        position: Position::new(80, 40),
+        checked: Checked(true),
+        ..Default::default()
+    });
+
+    commands.spawn(UiToggleSwitchBundle {
+        position: Position::new(40, 100),
+        ..Default::default()
+    });
+
+    commands.spawn((
+        UiToggleSwitchBundle {
+            position: Position::new(110, 100),
+            checked: Checked(true),
+            ..Default::default()
+        },
+        Disabled,
+    ));
+
+    // The group is a box holding the radio buttons, any entity with children works
+    commands
+        .spawn((
+            UiBoxBundle {
+                position: Position::new(40, 160),
+                size: Size::new(140, 40),
+                color: ColoredElement::new(Color::ALICE_BLUE),
+                ..Default::default()
+            },
+            RadioGroup {
+                selected: Some(String::from("medium")),
+            },
+        ))
+        .with_children(|parent| {
+            for (index, value) in ["low", "medium", "high"].into_iter().enumerate() {
+                parent.spawn(UiRadioButtonBundle {
+                    position: Position::new(50 + index as u32 * 44, 169),
+                    z_level: ZLevel(1),
+                    ..UiRadioButtonBundle::new(value)
+                });
+            }
+        });
+}
+
+fn print_changes(
+    mut toggled_events: EventReader<ValueChangedEvent<bool>>,
+    mut selected_events: EventReader<ValueChangedEvent<String>>,
+) {
+    for toggled_event in toggled_events.read() {
+        info!("{:?} is now {}", toggled_event.element, toggled_event.value);
+    }
+
+    for selected_event in selected_events.read() {
+        info!("Selected {}", selected_event.value);
+    }
+}
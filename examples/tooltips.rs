@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{App, Color, Commands, Startup},
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    // There's no room above the window's edge, so this one flips below the button
+    commands.spawn((
+        UiButtonBundle {
+            position: Position::new(50, 10),
+            ..UiButtonBundle::new("Top")
+        },
+        Tooltip::new("Saves the document"),
+    ));
+
+    commands.spawn((
+        UiButtonBundle {
+            position: Position::new(50, 150),
+            ..UiButtonBundle::new("Right")
+        },
+        Tooltip::new("Shown quickly on the right side, longer contents wrap onto several lines once they reach the maximum width")
+            .with_delay(Duration::from_millis(150))
+            .with_placement(TooltipPlacement::Right),
+    ));
+
+    commands.spawn((
+        UiBoxBundle {
+            position: Position::new(50, 290),
+            size: Size::new(60, 60),
+            color: ColoredElement::new(Color::ORANGE),
+            ..Default::default()
+        },
+        BoxCollisionBundle::default(),
+        Tooltip::new("Any element with a collision can have a tooltip")
+            .with_placement(TooltipPlacement::Bottom),
+    ));
+}
@@ -0,0 +1,127 @@
+use bevy::{
+    log::info,
+    prelude::{
+        App, BuildChildren, ChildBuilder, Children, Commands, EventReader, Parent, Query, Startup,
+        Update,
+    },
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (print_tree_events, move_dropped_nodes))
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    commands
+        .spawn(UiTreeViewBundle {
+            position: Position::new(50, 50),
+            ..Default::default()
+        })
+        .with_children(|tree| {
+            spawn_node(tree, "Scene", true, |scene| {
+                spawn_node(scene, "Camera", false, |_| {});
+                spawn_node(scene, "Lights", false, |lights| {
+                    spawn_node(lights, "Sun", false, |_| {});
+                    spawn_node(lights, "Fill", false, |_| {});
+                });
+                spawn_node(scene, "Player", true, |player| {
+                    spawn_node(player, "Body", false, |_| {});
+                    spawn_node(player, "Weapon", false, |_| {});
+                });
+            });
+            spawn_node(tree, "UI", false, |ui| {
+                spawn_node(ui, "Health Bar", false, |_| {});
+            });
+        });
+}
+
+fn spawn_node(
+    parent: &mut ChildBuilder,
+    label: &str,
+    expanded: bool,
+    children: impl FnOnce(&mut ChildBuilder),
+) {
+    parent
+        .spawn(UiTreeNodeBundle::new(
+            TreeNode::new(label).with_expanded(expanded),
+        ))
+        .with_children(children);
+}
+
+fn print_tree_events(
+    nodes: Query<&TreeNode>,
+    mut toggled_events: EventReader<TreeNodeToggledEvent>,
+    mut selection_changed_events: EventReader<TreeSelectionChangedEvent>,
+) {
+    for toggled_event in toggled_events.read() {
+        if let Ok(node) = nodes.get(toggled_event.node) {
+            info!("{} expanded: {}", node.label, toggled_event.expanded);
+        }
+    }
+
+    for selection_changed_event in selection_changed_events.read() {
+        let labels = nodes
+            .iter_many(&selection_changed_event.selected)
+            .map(|node| node.label.as_str())
+            .collect::<Vec<_>>();
+
+        info!("Selected {:?}", labels);
+    }
+}
+
+// The tree only reports drops, reparenting the nodes is up to the app
+fn move_dropped_nodes(
+    mut commands: Commands,
+    mut nodes: Query<&mut TreeNode>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut dropped_events: EventReader<TreeNodeDroppedEvent>,
+) {
+    for dropped_event in dropped_events.read() {
+        let target = dropped_event.target;
+
+        if dropped_event.placement == TreeDropPlacement::Into {
+            commands.entity(target).push_children(&dropped_event.nodes);
+
+            if let Ok(mut node) = nodes.get_mut(target) {
+                node.expanded = true;
+            }
+
+            continue;
+        }
+
+        let Ok(parent) = parents.get(target) else {
+            continue;
+        };
+
+        // The dropped nodes get taken out of their parent before being inserted back
+        let index = children
+            .get(parent.get())
+            .map(|siblings| {
+                siblings
+                    .iter()
+                    .filter(|sibling| !dropped_event.nodes.contains(sibling))
+                    .position(|sibling| *sibling == target)
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default();
+
+        let index = match dropped_event.placement {
+            TreeDropPlacement::After => index + 1,
+            _ => index,
+        };
+
+        commands
+            .entity(parent.get())
+            .insert_children(index, &dropped_event.nodes);
+    }
+}
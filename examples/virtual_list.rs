@@ -0,0 +1,45 @@
+use bevy::{
+    prelude::{App, BuildChildren, Color, Commands, Startup},
+    DefaultPlugins,
+};
+
+use epui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_systems(Startup, setup)
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiPlugin)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(UiCameraBundle::default());
+
+    // Only the rows in view get entities, scrolling rebuilds the ones scrolled into view
+    commands.spawn(UiVirtualListBundle {
+        position: Position::new(50, 50),
+        size: Size::new(320, 500),
+        ..UiVirtualListBundle::new(
+            VirtualList::new(100_000, RowHeight::Fixed(28)),
+            VirtualListRowBuilder::new(|row_commands, row| {
+                let color = if row.index % 2 == 0 {
+                    Color::WHITE
+                } else {
+                    Color::rgb(0.94, 0.94, 0.96)
+                };
+
+                row_commands
+                    .insert(ColoredElement::new(color))
+                    .with_children(|row_builder| {
+                        row_builder.spawn(UiTextBundle {
+                            text: UiText(format!("Item #{}", row.index + 1)),
+                            position: Position::new(row.position.x + 10, row.position.y + 4),
+                            size: Size::new(row.size.width - 20, 20),
+                            ..Default::default()
+                        });
+                    });
+            }),
+        )
+    });
+}
@@ -5,12 +5,13 @@ use bevy::{
     ecs::query::QueryItem,
     prelude::{
         Bundle, Camera, Color, Commands, Component, Entity, EventReader, EventWriter, FromWorld,
-        GlobalTransform, IntoSystemConfigs, OrthographicProjection, Plugin, Query, UVec2, Update,
-        With, World,
+        GlobalTransform, IntoSystemConfigs, Msaa, OrthographicProjection, Plugin, Query, Resource,
+        UVec2, Update, With, World,
     },
     render::{
         camera::{CameraRenderGraph, ExtractedCamera},
         extract_component::{ExtractComponent, ExtractComponentPlugin},
+        extract_resource::{ExtractResource, ExtractResourcePlugin},
         primitives::Frustum,
         render_graph::{NodeRunError, RenderGraphApp, ViewNode, ViewNodeRunner},
         render_phase::{
@@ -31,8 +32,12 @@ pub struct UiCameraPlugin;
 
 impl Plugin for UiCameraPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_systems(Update, redraw_on_resize)
-            .add_plugins(ExtractComponentPlugin::<UiCamera>::default());
+        app.init_resource::<UiMsaa>()
+            .add_systems(Update, redraw_on_resize)
+            .add_plugins((
+                ExtractComponentPlugin::<UiCamera>::default(),
+                ExtractResourcePlugin::<UiMsaa>::default(),
+            ));
     }
 
     fn finish(&self, app: &mut bevy::prelude::App) {
@@ -41,7 +46,9 @@ impl Plugin for UiCameraPlugin {
             .add_systems(ExtractSchedule, extract_ui_camera_phases)
             .add_systems(
                 Render,
-                sort_phase_system::<UiPhaseItem>.in_set(RenderSet::PhaseSort),
+                (sort_phase_system::<UiPhaseItem>, batch_ui_phase_items)
+                    .chain()
+                    .in_set(RenderSet::PhaseSort),
             )
             .add_render_sub_graph(GRAPH_NAME)
             .add_render_graph_node::<ViewNodeRunner<UiPassNode>>(GRAPH_NAME, UiPassNode::NAME)
@@ -69,6 +76,23 @@ fn redraw_on_resize(
 #[extract_component_filter(With<Camera>)]
 pub struct UiCamera;
 
+// Off renders the UI pass without multisampling, anti-aliasing of the elements comes from their shaders
+#[derive(Resource, ExtractResource, Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum UiMsaa {
+    #[default]
+    Inherit,
+    Off,
+}
+
+impl UiMsaa {
+    pub fn samples(&self, msaa: &Msaa) -> u32 {
+        match self {
+            UiMsaa::Inherit => msaa.samples(),
+            UiMsaa::Off => 1,
+        }
+    }
+}
+
 #[derive(Bundle)]
 pub struct UiCameraBundle {
     pub camera: Camera,
@@ -94,20 +118,47 @@ impl Default for UiCameraBundle {
     }
 }
 
+// Paint order of the parts of elements sharing the same z level & tree order (e.g. a box with a text on it)
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UiPhaseLayer {
+    OuterShadow,
+    Box,
+    Image,
+    InnerShadow,
+    Text,
+}
+
 #[derive(Debug, Clone)]
 pub struct UiPhaseItem {
     pub entity: Entity,
     pub z_index: u32,
+    pub tree_order: u32,
+    pub layer: UiPhaseLayer,
 
     pub draw_function: DrawFunctionId,
     pub cached_render_pipeline_id: CachedRenderPipelineId,
 
     pub batch_range: Range<u32>,
     pub dynamic_offset: Option<NonMaxU32>,
+
+    // Whether consecutive items of the same draw function & pipeline can be drawn as one instanced draw
+    pub batchable: bool,
+}
+
+impl UiPhaseItem {
+    fn can_batch_with(&self, next: &UiPhaseItem) -> bool {
+        self.batchable
+            && next.batchable
+            && self.entity == next.entity
+            && self.draw_function == next.draw_function
+            && self.cached_render_pipeline_id == next.cached_render_pipeline_id
+            && self.dynamic_offset == next.dynamic_offset
+            && self.batch_range.end == next.batch_range.start
+    }
 }
 
 impl PhaseItem for UiPhaseItem {
-    type SortKey = u32;
+    type SortKey = (u32, u32, UiPhaseLayer);
 
     fn draw_function(&self) -> DrawFunctionId {
         self.draw_function
@@ -118,11 +169,12 @@ impl PhaseItem for UiPhaseItem {
     }
 
     fn sort_key(&self) -> Self::SortKey {
-        self.z_index
+        (self.z_index, self.tree_order, self.layer)
     }
 
     fn sort(items: &mut [Self]) {
-        items.sort_unstable_by_key(|item| item.z_index);
+        // Stable so items with equal keys keep the order they were queued in
+        items.sort_by_key(|item| item.sort_key());
     }
 
     fn batch_range(&self) -> &Range<u32> {
@@ -165,6 +217,25 @@ fn extract_ui_camera_phases(
     }
 }
 
+// Merges consecutive sorted items whose instances are contiguous in the same buffer into a single draw
+fn batch_ui_phase_items(mut phases: Query<&mut RenderPhase<UiPhaseItem>>) {
+    for mut phase in phases.iter_mut() {
+        let items = std::mem::take(&mut phase.items);
+        let mut batched_items: Vec<UiPhaseItem> = Vec::with_capacity(items.len());
+
+        for item in items {
+            match batched_items.last_mut() {
+                Some(last) if last.can_batch_with(&item) => {
+                    last.batch_range.end = item.batch_range.end;
+                }
+                _ => batched_items.push(item),
+            }
+        }
+
+        phase.items = batched_items;
+    }
+}
+
 pub struct UiPassNode;
 
 impl FromWorld for UiPassNode {
@@ -193,13 +264,20 @@ impl ViewNode for UiPassNode {
     ) -> Result<(), NodeRunError> {
         let view_entity = graph.view_entity();
 
+        let operations = Operations {
+            load: LoadOp::Clear(Color::WHITE.into()),
+            store: true,
+        };
+
+        let color_attachment = match world.get_resource::<UiMsaa>() {
+            Some(UiMsaa::Off) => view_target.get_unsampled_color_attachment(operations),
+            _ => view_target.get_color_attachment(operations),
+        };
+
         {
             let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
                 label: Some("ui_render_pass"),
-                color_attachments: &[Some(view_target.get_color_attachment(Operations {
-                    load: LoadOp::Clear(Color::WHITE.into()),
-                    store: true,
-                }))],
+                color_attachments: &[Some(color_attachment)],
                 depth_stencil_attachment: None,
             });
 
@@ -5,8 +5,8 @@ use bevy::{
     ecs::system::lifetimeless::SRes,
     log::error,
     prelude::{
-        AssetServer, Bundle, Color, Component, Entity, Handle, IntoSystemConfigs, Plugin, Query,
-        Rect, Res, ResMut, Resource, Shader, Vec2, Vec4, With,
+        AssetServer, Bundle, Component, Entity, Handle, IntoSystemConfigs, Msaa, Plugin, Query,
+        Rect, Ref, Res, ResMut, Resource, Shader, UVec2, Vec2, Vec4, With,
     },
     render::{
         render_phase::{
@@ -14,10 +14,11 @@ use bevy::{
             RenderPhase, SetItemPipeline,
         },
         render_resource::{
-            BlendState, BufferUsages, BufferVec, CachedRenderPipelineId, ColorTargetState,
-            ColorWrites, FragmentState, FrontFace, MultisampleState, PipelineCache, PolygonMode,
-            PrimitiveState, PrimitiveTopology, RenderPipelineDescriptor, TextureFormat,
-            VertexAttribute, VertexBufferLayout, VertexFormat, VertexState, VertexStepMode,
+            BlendState, Buffer, BufferDescriptor, BufferUsages, CachedRenderPipelineId,
+            ColorTargetState, ColorWrites, FragmentState, FrontFace, MultisampleState,
+            PipelineCache, PolygonMode, PrimitiveState, PrimitiveTopology,
+            RenderPipelineDescriptor, TextureFormat, VertexAttribute, VertexBufferLayout,
+            VertexFormat, VertexState, VertexStepMode,
         },
         renderer::{RenderDevice, RenderQueue},
         texture::BevyDefault,
@@ -28,11 +29,13 @@ use bevy::{
 use bytemuck_derive::{Pod, Zeroable};
 
 use crate::{
-    camera::{PhysicalViewportSize, UiPhaseItem},
+    camera::{PhysicalViewportSize, UiMsaa, UiPhaseItem, UiPhaseLayer},
     prelude::AutoZUpdate,
     property::{
-        state::CurrentlyActive, update::AutoVisibleRegionUpdate, ColoredElement, CornersRoundness,
-        Position, Size, VisibleRegion, ZLevel,
+        fill::{Fill, MAX_GRADIENT_STOPS},
+        state::{ActiveChanged, CurrentlyActive},
+        update::{AutoVisibleRegionUpdate, TreeOrder},
+        ColoredElement, CornersRoundness, CornersShape, Position, Size, VisibleRegion, ZLevel,
     },
 };
 
@@ -41,13 +44,15 @@ pub(crate) struct UiBoxPlugin;
 impl Plugin for UiBoxPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         embedded_asset!(app, "src/", "box.wgsl");
+        embedded_asset!(app, "src/", "rounded_box.wgsl");
     }
 
     fn finish(&self, app: &mut bevy::prelude::App) {
-        let box_shader = app
-            .world
-            .resource::<AssetServer>()
-            .load("embedded://epui/element/box.wgsl");
+        let asset_server = app.world.resource::<AssetServer>();
+        let box_shader = asset_server.load("embedded://epui/element/box.wgsl");
+        let rounded_box_shader = asset_server.load("embedded://epui/element/rounded_box.wgsl");
+
+        app.insert_resource(RoundedBoxShader(rounded_box_shader));
 
         let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
@@ -83,30 +88,56 @@ pub struct UiBoxBundle {
 }
 
 #[derive(Resource, Default)]
-struct BoxPipeline(Option<CachedRenderPipelineId>);
+struct BoxPipeline(Option<(CachedRenderPipelineId, u32)>);
 
 #[derive(Clone, Copy, Zeroable, Pod)]
 #[repr(C)]
 struct InstanceData {
-    vertices: [[f32; 2]; 4], // Vertices (Vec2[4])
-    color: [f32; 4],         // Color (RGBA)
+    vertices: [[f32; 2]; 4],     // Vertices (Vec2[4])
+    gradient_geometry: [f32; 4], // Gradient Geometry { Angle } / { Center X, Center Y, Radius/Angle } (Vec4)
+
+    box_center: [f32; 2],    // Vec4: Box Center (Vec2)
+    box_half_size: [f32; 2], // + Box Half Size (Vec2)
+
+    corner_radii_x: [f32; 4], // Corners Horizontal Radii { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
 
-    corner_center: [f32; 2], // Vec4: Corner Center (Vec2)
-    corner_half_whd: f32,    // + Corner Width & Height Difference (f32)
-    half_min_axis: f32,      // + Half Minimum Axis (f32)
+    gradient_offsets: [f32; MAX_GRADIENT_STOPS], // Gradient Stops Offsets (Vec4[2])
+    gradient_colors: [u32; MAX_GRADIENT_STOPS],  // Gradient Stops Colors, packed RGBA8 (UVec4[2])
+    gradient_kind_and_count: [u32; 2], // Gradient Kind { Solid, Linear, Radial, Conic } + Stops Count (UVec2)
 
-    corners_roundness: [f32; 4], // Corners Roundness { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
+    corner_radii_y: [f32; 4], // Corners Vertical Radii { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
 }
 
 impl InstanceData {
     fn new(
         vertices: [Vec2; 4],
-        color: Color,
-        corner_center: Vec2,
-        corner_half_whd: f32,
-        half_min_axis: f32,
-        corners_roundness: Vec4,
+        fill: &Fill,
+        box_center: Vec2,
+        box_half_size: Vec2,
+        corner_radii_x: Vec4,
+        corner_radii_y: Vec4,
     ) -> InstanceData {
+        let (gradient_kind, gradient_geometry) = match fill {
+            Fill::Solid(_) => (0, Vec4::ZERO),
+            Fill::LinearGradient { angle, .. } => (1, Vec4::new(*angle, 0.0, 0.0, 0.0)),
+            Fill::RadialGradient { center, radius, .. } => {
+                (2, Vec4::new(center.x, center.y, *radius, 0.0))
+            }
+            Fill::ConicGradient { center, angle, .. } => {
+                (3, Vec4::new(center.x, center.y, *angle, 0.0))
+            }
+        };
+
+        let stops = fill.normalized_stops();
+
+        let mut gradient_offsets = [0.0; MAX_GRADIENT_STOPS];
+        let mut gradient_colors = [0; MAX_GRADIENT_STOPS];
+
+        for (index, stop) in stops.iter().enumerate() {
+            gradient_offsets[index] = stop.offset;
+            gradient_colors[index] = stop.color.as_rgba_u32();
+        }
+
         InstanceData {
             vertices: [
                 vertices[0].into(),
@@ -114,33 +145,74 @@ impl InstanceData {
                 vertices[2].into(),
                 vertices[3].into(),
             ],
-            color: color.rgba_to_vec4().into(),
+            gradient_geometry: gradient_geometry.into(),
 
-            corner_center: corner_center.into(),
-            corner_half_whd,
-            half_min_axis,
+            box_center: box_center.into(),
+            box_half_size: box_half_size.into(),
 
-            corners_roundness: corners_roundness.into(),
+            corner_radii_x: corner_radii_x.into(),
+
+            gradient_offsets,
+            gradient_colors,
+            gradient_kind_and_count: [gradient_kind, stops.len() as u32],
+
+            corner_radii_y: corner_radii_y.into(),
         }
     }
 }
 
-#[derive(Resource)]
+// Instances are kept per view (their vertices depend on the viewport) in draw order & only the changed ones are re-uploaded
+#[derive(Resource, Default)]
 struct BoxBuffers {
-    instances: BufferVec<InstanceData>,
+    views: EntityHashMap<Entity, BoxViewBuffer>,
+}
+
+#[derive(Default)]
+struct BoxViewBuffer {
+    viewport_size: UVec2,
+    last_frame: u32,
+
+    buffer: Option<Buffer>,
+    capacity: usize,
+
+    order: Vec<Entity>,
 }
 
-impl Default for BoxBuffers {
-    fn default() -> Self {
-        BoxBuffers {
-            instances: BufferVec::new(BufferUsages::VERTEX),
+impl BoxViewBuffer {
+    fn upload(&self, instances: &[InstanceData], start: usize, render_queue: &RenderQueue) {
+        let Some(buffer) = self.buffer.as_ref() else {
+            return;
+        };
+
+        render_queue.write_buffer(
+            buffer,
+            (start * std::mem::size_of::<InstanceData>()) as u64,
+            bytemuck::cast_slice(instances),
+        );
+    }
+
+    fn reserve(&mut self, count: usize, render_device: &RenderDevice) {
+        if self.buffer.is_some() && count <= self.capacity {
+            return;
         }
+
+        self.capacity = count.max(self.capacity * 2).max(1);
+        self.buffer = Some(render_device.create_buffer(&BufferDescriptor {
+            label: Some("box_instance_buffer"),
+            size: (self.capacity * std::mem::size_of::<InstanceData>()) as u64,
+            usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        }));
     }
 }
 
 #[derive(Resource)]
 struct BoxShader(Handle<Shader>);
 
+// Imported by the box, image & shadow shaders, kept loaded for their pipelines to find it
+#[derive(Resource)]
+struct RoundedBoxShader(#[allow(dead_code)] Handle<Shader>);
+
 impl Deref for BoxShader {
     type Target = Handle<Shader>;
 
@@ -149,20 +221,101 @@ impl Deref for BoxShader {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 struct BoxInstance {
     position: Position,
     size: Size,
 
     visible_region: VisibleRegion,
-    color: ColoredElement,
+    fill: Fill,
 
     corners_roundness: CornersRoundness,
+    corners_shape: CornersShape,
     z_level: ZLevel,
+    tree_order: TreeOrder,
+}
+
+impl BoxInstance {
+    fn instance_data(&self, x_pixel_unit: f32, y_pixel_unit: f32) -> InstanceData {
+        let full_region = Rect::from_corners(
+            Vec2::from(self.position.clone()),
+            Vec2::from(self.position.clone()) + Vec2::from(self.size.clone()),
+        );
+
+        let actual_visible_region = Rect::from(self.visible_region.clone()).intersect(full_region);
+
+        let left_top_corner = Vec2::new(
+            (x_pixel_unit * actual_visible_region.min.x) - 1.0,
+            1.0 - (y_pixel_unit * actual_visible_region.min.y),
+        );
+
+        let right_top_corner = Vec2::new(
+            left_top_corner.x + (x_pixel_unit * actual_visible_region.width()),
+            left_top_corner.y,
+        );
+
+        let left_bottom_corner = Vec2::new(
+            left_top_corner.x,
+            left_top_corner.y - (y_pixel_unit * actual_visible_region.height()),
+        );
+
+        let right_bottom_corner = Vec2::new(right_top_corner.x, left_bottom_corner.y);
+
+        let half_size = full_region.half_size();
+        let (corner_radii_x, corner_radii_y) =
+            corner_radii(&self.corners_roundness, self.corners_shape, half_size);
+
+        InstanceData::new(
+            [
+                left_top_corner,
+                right_top_corner,
+                left_bottom_corner,
+                right_bottom_corner,
+            ],
+            &self.fill,
+            full_region.center(),
+            half_size,
+            corner_radii_x,
+            corner_radii_y,
+        )
+    }
+}
+
+// Horizontal & vertical radii of the corners { Left-Top, Right-Top, Left-Bottom, Right-Bottom } of an element
+pub(crate) fn corner_radii(
+    corners_roundness: &CornersRoundness,
+    corners_shape: CornersShape,
+    half_size: Vec2,
+) -> (Vec4, Vec4) {
+    let corners_roundness = Vec4::from(corners_roundness.clone());
+
+    match corners_shape {
+        CornersShape::Circular => (
+            corners_roundness * half_size.min_element(),
+            corners_roundness * half_size.min_element(),
+        ),
+        CornersShape::Elliptical => (
+            corners_roundness * half_size.x,
+            corners_roundness * half_size.y,
+        ),
+    }
+}
+
+#[derive(Debug)]
+struct ExtractedBox {
+    instance: BoxInstance,
+    changed: bool,
+    last_seen: u32,
 }
 
 #[derive(Debug, Default, Resource)]
-struct ExtractedBoxes(EntityHashMap<Entity, BoxInstance>);
+struct ExtractedBoxes {
+    boxes: EntityHashMap<Entity, ExtractedBox>,
+
+    // Set when boxes were added, removed or moved in z level / tree order, which changes the draw order
+    order_changed: bool,
+    frame: u32,
+}
 
 fn extract_boxes(
     boxes: Extract<
@@ -173,18 +326,52 @@ fn extract_boxes(
                 &CurrentlyActive<Size>,
                 &CurrentlyActive<VisibleRegion>,
                 &CurrentlyActive<ColoredElement>,
+                Option<&CurrentlyActive<Fill>>,
                 Option<&CurrentlyActive<CornersRoundness>>,
+                Option<Ref<CornersShape>>,
                 Option<&CurrentlyActive<ZLevel>>,
+                Option<Ref<TreeOrder>>,
+                (
+                    ActiveChanged<Position>,
+                    ActiveChanged<Size>,
+                    ActiveChanged<VisibleRegion>,
+                    Option<ActiveChanged<Fill>>,
+                    Option<ActiveChanged<CornersRoundness>>,
+                    Option<ActiveChanged<ZLevel>>,
+                ),
             ),
             With<UiBox>,
         >,
     >,
     mut extracted_boxes: ResMut<ExtractedBoxes>,
 ) {
-    extracted_boxes.0.clear();
-
-    for (entity, position, size, visible_region, colored_element, corners_roundness, z_level) in
-        boxes.iter()
+    let extracted_boxes = &mut *extracted_boxes;
+
+    extracted_boxes.frame = extracted_boxes.frame.wrapping_add(1);
+    extracted_boxes.order_changed = false;
+
+    let frame = extracted_boxes.frame;
+
+    for (
+        entity,
+        position,
+        size,
+        visible_region,
+        colored_element,
+        fill,
+        corners_roundness,
+        corners_shape,
+        z_level,
+        tree_order,
+        (
+            position_changed,
+            size_changed,
+            visible_region_changed,
+            fill_changed,
+            corners_roundness_changed,
+            z_level_changed,
+        ),
+    ) in boxes.iter()
     {
         let full_region = Rect::from_corners(
             Vec2::from(position.clone()),
@@ -199,23 +386,85 @@ fn extract_boxes(
             continue;
         }
 
-        extracted_boxes.0.insert(
-            entity,
-            BoxInstance {
-                position: position.clone(),
-                size: size.clone(),
-                visible_region: visible_region.clone(),
-                color: colored_element.clone(),
-                corners_roundness: corners_roundness.cloned().unwrap_or_default(),
-                z_level: z_level.cloned().unwrap_or_default(),
-            },
-        );
+        let box_instance = || BoxInstance {
+            position: position.clone(),
+            size: size.clone(),
+            visible_region: visible_region.clone(),
+            fill: fill
+                .cloned()
+                .unwrap_or_else(|| Fill::Solid(colored_element.color)),
+            corners_roundness: corners_roundness.cloned().unwrap_or_default(),
+            corners_shape: corners_shape.as_deref().copied().unwrap_or_default(),
+            z_level: z_level.cloned().unwrap_or_default(),
+            tree_order: tree_order.as_deref().copied().unwrap_or_default(),
+        };
+
+        let Some(extracted_box) = extracted_boxes.boxes.get_mut(&entity) else {
+            extracted_boxes.boxes.insert(
+                entity,
+                ExtractedBox {
+                    instance: box_instance(),
+                    changed: true,
+                    last_seen: frame,
+                },
+            );
+
+            extracted_boxes.order_changed = true;
+            continue;
+        };
+
+        extracted_box.last_seen = frame;
+
+        // Missing optional components can't be change detected, their cached value must be the default one
+        let cached = &extracted_box.instance;
+        extracted_box.changed = position_changed
+            || size_changed
+            || visible_region_changed
+            || fill_changed.unwrap_or(cached.fill != Fill::Solid(colored_element.color))
+            || corners_roundness_changed
+                .unwrap_or(cached.corners_roundness != CornersRoundness::default())
+            || corners_shape.as_ref().map_or(
+                cached.corners_shape != CornersShape::default(),
+                |corners_shape| corners_shape.is_changed(),
+            )
+            || z_level_changed.unwrap_or(cached.z_level != ZLevel::default())
+            || tree_order
+                .as_ref()
+                .map_or(cached.tree_order != TreeOrder::default(), |tree_order| {
+                    tree_order.is_changed()
+                });
+
+        if !extracted_box.changed {
+            continue;
+        }
+
+        let instance = box_instance();
+
+        if extracted_box.instance.z_level != instance.z_level
+            || extracted_box.instance.tree_order != instance.tree_order
+        {
+            extracted_boxes.order_changed = true;
+        }
+
+        extracted_box.instance = instance;
+    }
+
+    let boxes_count = extracted_boxes.boxes.len();
+
+    extracted_boxes
+        .boxes
+        .retain(|_, extracted_box| extracted_box.last_seen == frame);
+
+    if extracted_boxes.boxes.len() != boxes_count {
+        extracted_boxes.order_changed = true;
     }
 }
 
-fn queue_boxes(
+pub(crate) fn queue_boxes(
     mut box_pipeline: ResMut<BoxPipeline>,
     pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    ui_msaa: Res<UiMsaa>,
 
     mut view_query: Query<(Entity, &PhysicalViewportSize, &mut RenderPhase<UiPhaseItem>)>,
     mut box_buffers: ResMut<BoxBuffers>,
@@ -226,86 +475,132 @@ fn queue_boxes(
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
 ) {
-    let pipeline = box_pipeline.0.get_or_insert_with(|| {
-        pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
-            label: Some("box_pipeline_desc".into()),
-            layout: vec![],
-            vertex: VertexState {
-                entry_point: "vs_main".into(),
-                shader: box_shader.0.clone(),
-                shader_defs: vec![],
-                buffers: vec![VertexBufferLayout {
-                    step_mode: VertexStepMode::Instance,
-                    attributes: vec![
-                        VertexAttribute {
-                            format: VertexFormat::Float32x2,
-                            offset: 0,
-                            shader_location: 0,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x2,
-                            offset: std::mem::size_of::<[f32; 2]>() as u64,
-                            shader_location: 1,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x2,
-                            offset: std::mem::size_of::<[[f32; 2]; 2]>() as u64,
-                            shader_location: 2,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x2,
-                            offset: std::mem::size_of::<[[f32; 2]; 3]>() as u64,
-                            shader_location: 3,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x4,
-                            offset: std::mem::size_of::<[[f32; 2]; 4]>() as u64,
-                            shader_location: 4,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x4,
-                            offset: std::mem::size_of::<[[f32; 2]; 6]>() as u64, // f32x4 = f32x2 * 2
-                            shader_location: 5,
-                        },
-                        VertexAttribute {
-                            format: VertexFormat::Float32x4,
-                            offset: std::mem::size_of::<[[f32; 2]; 8]>() as u64, // f32x4 = f32x2 * 2
-                            shader_location: 6,
-                        },
-                    ],
-                    array_stride: std::mem::size_of::<InstanceData>() as u64,
-                }],
-            },
-            fragment: Some(FragmentState {
-                entry_point: "fs_main".into(),
-                shader: box_shader.0.clone(),
-                shader_defs: vec![],
-                targets: vec![Some(ColorTargetState {
-                    format: TextureFormat::bevy_default(),
-                    blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
-                    write_mask: ColorWrites::ALL,
-                })],
+    let samples = ui_msaa.samples(&msaa);
+
+    if box_pipeline
+        .0
+        .is_some_and(|(_, pipeline_samples)| pipeline_samples != samples)
+    {
+        box_pipeline.0 = None;
+    }
+
+    let (pipeline, _) = box_pipeline.0.get_or_insert_with(|| {
+        (
+            pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+                label: Some("box_pipeline_desc".into()),
+                layout: vec![],
+                vertex: VertexState {
+                    entry_point: "vs_main".into(),
+                    shader: box_shader.0.clone(),
+                    shader_defs: vec![],
+                    buffers: vec![VertexBufferLayout {
+                        step_mode: VertexStepMode::Instance,
+                        attributes: vec![
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: 0,
+                                shader_location: 0,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[f32; 2]>() as u64,
+                                shader_location: 1,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[[f32; 2]; 2]>() as u64,
+                                shader_location: 2,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[[f32; 2]; 3]>() as u64,
+                                shader_location: 3,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 4]>() as u64,
+                                shader_location: 4,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 6]>() as u64, // f32x4 = f32x2 * 2
+                                shader_location: 5,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 8]>() as u64, // f32x4 = f32x2 * 2
+                                shader_location: 6,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 10]>() as u64,
+                                shader_location: 7,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 12]>() as u64,
+                                shader_location: 8,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Uint32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 14]>() as u64, // u32 = f32 in size
+                                shader_location: 9,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Uint32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 16]>() as u64,
+                                shader_location: 10,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Uint32x2,
+                                offset: std::mem::size_of::<[[f32; 2]; 18]>() as u64,
+                                shader_location: 11,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 19]>() as u64,
+                                shader_location: 12,
+                            },
+                        ],
+                        array_stride: std::mem::size_of::<InstanceData>() as u64,
+                    }],
+                },
+                fragment: Some(FragmentState {
+                    entry_point: "fs_main".into(),
+                    shader: box_shader.0.clone(),
+                    shader_defs: vec![],
+                    targets: vec![Some(ColorTargetState {
+                        format: TextureFormat::bevy_default(),
+                        blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                        write_mask: ColorWrites::ALL,
+                    })],
+                }),
+                push_constant_ranges: vec![],
+                primitive: PrimitiveState {
+                    topology: PrimitiveTopology::TriangleList,
+                    polygon_mode: PolygonMode::Fill,
+                    conservative: false,
+                    cull_mode: None,
+                    front_face: FrontFace::Ccw,
+                    strip_index_format: None,
+                    unclipped_depth: false,
+                },
+                multisample: MultisampleState {
+                    count: samples,
+                    ..Default::default()
+                },
+                depth_stencil: None,
             }),
-            push_constant_ranges: vec![],
-            primitive: PrimitiveState {
-                topology: PrimitiveTopology::TriangleList,
-                polygon_mode: PolygonMode::Fill,
-                conservative: false,
-                cull_mode: None,
-                front_face: FrontFace::Ccw,
-                strip_index_format: None,
-                unclipped_depth: false,
-            },
-            multisample: MultisampleState {
-                count: 4,
-                ..Default::default()
-            },
-            depth_stencil: None,
-        })
+            samples,
+        )
     });
 
     let draw_function_id = draw_functions.read().id::<RenderBoxCommand>();
-    let mut instances = Vec::new();
+    let frame = extracted_boxes.frame;
+
+    box_buffers
+        .views
+        .retain(|camera_entity, _| view_query.contains(*camera_entity));
 
     for (camera_entity, viewport_size, mut ui_phase) in view_query.iter_mut() {
         let Some(viewport_size) = viewport_size.0 else {
@@ -315,95 +610,89 @@ fn queue_boxes(
         let x_pixel_unit = 2.0 / viewport_size.x as f32;
         let y_pixel_unit = 2.0 / viewport_size.y as f32;
 
-        let mut instance = 0;
-        box_buffers.instances.clear();
-
-        let boxes_count = extracted_boxes.0.len();
-
-        instances.reserve(boxes_count);
-        box_buffers.instances.reserve(boxes_count, &render_device);
-        ui_phase.items.reserve(boxes_count);
-
-        for (
-            _,
-            BoxInstance {
-                position,
-                size,
-                visible_region,
-                color,
-                z_level,
-                corners_roundness,
-            },
-        ) in extracted_boxes.0.iter()
-        {
-            let full_region = Rect::from_corners(
-                Vec2::from(position.clone()),
-                Vec2::from(position.clone()) + Vec2::from(size.clone()),
-            );
-
-            let actual_visible_region = Rect::from(visible_region.clone()).intersect(full_region);
-
-            let left_top_corner = Vec2::new(
-                (x_pixel_unit * actual_visible_region.min.x as f32) - 1.0,
-                1.0 - (y_pixel_unit * actual_visible_region.min.y as f32),
-            );
-
-            let right_top_corner = Vec2::new(
-                left_top_corner.x + (x_pixel_unit * actual_visible_region.width() as f32),
-                left_top_corner.y,
-            );
-
-            let left_bottom_corner = Vec2::new(
-                left_top_corner.x,
-                left_top_corner.y - (y_pixel_unit * actual_visible_region.height() as f32),
-            );
+        let view_buffer = box_buffers.views.entry(camera_entity).or_default();
+
+        // A view that missed a frame or got resized can't rely on the previous changes, so it's fully rebuilt
+        let rebuild = extracted_boxes.order_changed
+            || view_buffer.buffer.is_none()
+            || view_buffer.viewport_size != viewport_size
+            || view_buffer.last_frame.wrapping_add(1) != frame;
+
+        view_buffer.viewport_size = viewport_size;
+        view_buffer.last_frame = frame;
+
+        if rebuild {
+            view_buffer.order.clear();
+            view_buffer
+                .order
+                .extend(extracted_boxes.boxes.keys().copied());
+
+            view_buffer.order.sort_by_key(|entity| {
+                let instance = &extracted_boxes.boxes[entity].instance;
+
+                (instance.z_level.0, instance.tree_order.0, entity.index())
+            });
+
+            let instances = view_buffer
+                .order
+                .iter()
+                .map(|entity| {
+                    extracted_boxes.boxes[entity]
+                        .instance
+                        .instance_data(x_pixel_unit, y_pixel_unit)
+                })
+                .collect::<Vec<_>>();
+
+            view_buffer.reserve(instances.len(), &render_device);
+            view_buffer.upload(&instances, 0, &render_queue);
+        } else {
+            // Consecutive changed instances are written together
+            let mut changed_instances = Vec::new();
+            let mut changed_start = 0;
+
+            for (index, entity) in view_buffer.order.iter().enumerate() {
+                let extracted_box = &extracted_boxes.boxes[entity];
+
+                if extracted_box.changed {
+                    if changed_instances.is_empty() {
+                        changed_start = index;
+                    }
+
+                    changed_instances.push(
+                        extracted_box
+                            .instance
+                            .instance_data(x_pixel_unit, y_pixel_unit),
+                    );
+                } else if !changed_instances.is_empty() {
+                    view_buffer.upload(&changed_instances, changed_start, &render_queue);
+                    changed_instances.clear();
+                }
+            }
 
-            let right_bottom_corner = Vec2::new(right_top_corner.x, left_bottom_corner.y);
+            if !changed_instances.is_empty() {
+                view_buffer.upload(&changed_instances, changed_start, &render_queue);
+            }
+        }
 
-            let corner_center = Vec2::new(
-                (size.width as f32 / 2.0) + position.x as f32,
-                (size.height as f32 / 2.0) + position.y as f32,
-            );
+        // Boxes are in paint order in the buffer, so consecutive boxes get merged into a single instanced draw
+        for (index, entity) in view_buffer.order.iter().enumerate() {
+            let instance = &extracted_boxes.boxes[entity].instance;
 
-            let corners_roundness = Vec4::from(corners_roundness.clone());
-            let min_half_unit = u32::min(size.width, size.height) as f32 / 2.0;
-            let corner_half_whd = (size.width as f32 - size.height as f32) / 2.0; // Positive = Width > Height, Negative = Width < Height
-
-            instances.push(InstanceData::new(
-                [
-                    left_top_corner,
-                    right_top_corner,
-                    left_bottom_corner,
-                    right_bottom_corner,
-                ],
-                color.color,
-                corner_center,
-                corner_half_whd,
-                min_half_unit,
-                (1.0 - corners_roundness) * min_half_unit,
-            ));
-
-            let ui_phase_item = UiPhaseItem {
+            ui_phase.add(UiPhaseItem {
                 entity: camera_entity,
-                z_index: z_level.0,
+                z_index: instance.z_level.0,
+                tree_order: instance.tree_order.0,
+                layer: UiPhaseLayer::Box,
 
                 draw_function: draw_function_id,
                 cached_render_pipeline_id: *pipeline,
 
-                batch_range: instance..instance + 1,
+                batch_range: index as u32..index as u32 + 1,
                 dynamic_offset: None,
-            };
-
-            ui_phase.add(ui_phase_item);
-            instance += 1;
+                batchable: true,
+            });
         }
     }
-
-    box_buffers.instances.extend(instances);
-
-    box_buffers
-        .instances
-        .write_buffer(&render_device, &render_queue);
 }
 
 type RenderBoxCommand = (SetItemPipeline, DrawBox);
@@ -422,7 +711,12 @@ impl RenderCommand<UiPhaseItem> for DrawBox {
         pass: &mut bevy::render::render_phase::TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
         {
-            let vertex_buffer = match box_buffers.into_inner().instances.buffer() {
+            let vertex_buffer = match box_buffers
+                .into_inner()
+                .views
+                .get(&item.entity)
+                .and_then(|view_buffer| view_buffer.buffer.as_ref())
+            {
                 Some(buffer) => buffer,
                 None => {
                     error!("Couldn't set vertex buffer because it's not present");
@@ -1,123 +1,146 @@
+#import epui::rounded_box::rounded_box_distance
+
 struct InstanceInput {
     @location(0) top_left_position: vec2<f32>, 
     @location(1) top_right_position: vec2<f32>, 
     @location(2) bottom_left_position: vec2<f32>, 
     @location(3) bottom_right_position: vec2<f32>, 
 
-    @location(4) color: vec4<f32>, 
+    @location(4) gradient_geometry: vec4<f32>, 
+
+    @location(5) box_center_and_half_size: vec4<f32>, 
+    @location(6) corner_radii_x: vec4<f32>, 
 
-    @location(5) corner_center_and_half_whd_and_radius: vec4<f32>, 
-    @location(6) corner_roundness_shifts: vec4<f32>
+    @location(7) gradient_offsets_low: vec4<f32>, 
+    @location(8) gradient_offsets_high: vec4<f32>, 
+    @location(9) gradient_colors_low: vec4<u32>, 
+    @location(10) gradient_colors_high: vec4<u32>, 
+    @location(11) gradient_kind_and_count: vec2<u32>, 
+
+    @location(12) corner_radii_y: vec4<f32>
 }
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>, 
 
-    @location(0) color: vec4<f32>, 
-    @location(1) center_pixel: vec2<f32>, 
-    @location(2) corner_roundness: vec3<f32>
+    @location(0) gradient_geometry: vec4<f32>, 
+    @location(1) box_center_and_half_size: vec4<f32>, 
+    @location(2) corner_radii_x: vec4<f32>, 
+    @location(3) corner_radii_y: vec4<f32>, 
+
+    @location(4) gradient_offsets_low: vec4<f32>, 
+    @location(5) gradient_offsets_high: vec4<f32>, 
+    @location(6) @interpolate(flat) gradient_colors_low: vec4<u32>, 
+    @location(7) @interpolate(flat) gradient_colors_high: vec4<u32>, 
+    @location(8) @interpolate(flat) gradient_kind_and_count: vec2<u32>
 }
 
+const PI: f32 = 3.141592653589793;
+
 @vertex
 fn vs_main(@builtin(vertex_index) in_vertex_index: u32, in_instance: InstanceInput) -> VertexOutput {    
     var vertex_output: VertexOutput;
 
     var position = in_instance.top_left_position;
-    var corner_roundness_shift = in_instance.corner_roundness_shifts.x;
 
     if in_vertex_index == 1u || in_vertex_index == 3u {
         position = in_instance.top_right_position;
-        corner_roundness_shift = in_instance.corner_roundness_shifts.y;
     }
 
     if in_vertex_index == 2u || in_vertex_index == 4u {
         position = in_instance.bottom_left_position;
-        corner_roundness_shift = in_instance.corner_roundness_shifts.z;
     }
 
     if in_vertex_index == 5u {
         position = in_instance.bottom_right_position;
-        corner_roundness_shift = in_instance.corner_roundness_shifts.w;
     }
 
     vertex_output.position = vec4<f32>(position, 0.0, 1.0);
-    vertex_output.color = in_instance.color;
+    vertex_output.gradient_geometry = in_instance.gradient_geometry;
+    vertex_output.gradient_offsets_low = in_instance.gradient_offsets_low;
+    vertex_output.gradient_offsets_high = in_instance.gradient_offsets_high;
+    vertex_output.gradient_colors_low = in_instance.gradient_colors_low;
+    vertex_output.gradient_colors_high = in_instance.gradient_colors_high;
+    vertex_output.gradient_kind_and_count = in_instance.gradient_kind_and_count;
     
-    vertex_output.center_pixel = in_instance.corner_center_and_half_whd_and_radius.xy;
-    vertex_output.corner_roundness = vec3<f32>(corner_roundness_shift, in_instance.corner_center_and_half_whd_and_radius.zw);
+    vertex_output.box_center_and_half_size = in_instance.box_center_and_half_size;
+    vertex_output.corner_radii_x = in_instance.corner_radii_x;
+    vertex_output.corner_radii_y = in_instance.corner_radii_y;
     
     return vertex_output;
 }
 
-@fragment
-fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    var roundness_center = input.center_pixel;
-    
-    let position_relative_to_center = input.position.xy - roundness_center;
-    
-    let width_height_half_difference = input.corner_roundness.y;
-    let width_height_half_difference_abs = abs(width_height_half_difference);
+fn fill_color(input: VertexOutput) -> vec4<f32> {
+    let kind = input.gradient_kind_and_count.x;
+    let count = input.gradient_kind_and_count.y;
 
-    if width_height_half_difference >= 0.0 {
-        if abs(position_relative_to_center.x) <= width_height_half_difference_abs {
-            return input.color;
-        }
+    if kind == 0u || count <= 1u {
+        return unpack4x8unorm(input.gradient_colors_low.x);
+    }
 
-        if position_relative_to_center.x >= 0.0 {
-            roundness_center.x += width_height_half_difference_abs;
-        } else {
-            roundness_center.x -= width_height_half_difference_abs;
-        }
-    } else {
-        if abs(position_relative_to_center.y) <= width_height_half_difference_abs {
-            return input.color;
-        }
+    let size = input.box_center_and_half_size.zw * 2.0;
+    let relative_position = input.position.xy - input.box_center_and_half_size.xy;
+    let geometry = input.gradient_geometry;
 
-        if position_relative_to_center.y >= 0.0 {
-            roundness_center.y += width_height_half_difference_abs;
-        } else {
-            roundness_center.y -= width_height_half_difference_abs;
-        }
-    }
+    var offset = 0.0;
 
-    if position_relative_to_center.x >= 0.0 {
-        roundness_center.x += input.corner_roundness.x;
+    if kind == 1u {
+        let direction = vec2<f32>(sin(geometry.x), -cos(geometry.x));
+        let gradient_length = abs(size.x * direction.x) + abs(size.y * direction.y);
 
-        if input.position.x < roundness_center.x {
-            return input.color;
-        }
+        offset = dot(relative_position, direction) / max(gradient_length, 0.0001) + 0.5;
+    } else if kind == 2u {
+        let center = (geometry.xy - 0.5) * size;
+        let radius = max(size * 0.5 * geometry.z, vec2<f32>(0.0001));
+
+        offset = length((relative_position - center) / radius);
     } else {
-        roundness_center.x -= input.corner_roundness.x;
+        let center = (geometry.xy - 0.5) * size;
+        let direction = relative_position - center;
 
-        if input.position.x > roundness_center.x {
-            return input.color;
-        }
+        offset = fract((atan2(direction.x, -direction.y) - geometry.z) / (2.0 * PI));
     }
 
-    if position_relative_to_center.y >= 0.0 {
-        roundness_center.y += input.corner_roundness.x;
+    var offsets = array<f32, 8>(
+        input.gradient_offsets_low.x, input.gradient_offsets_low.y, input.gradient_offsets_low.z, input.gradient_offsets_low.w,
+        input.gradient_offsets_high.x, input.gradient_offsets_high.y, input.gradient_offsets_high.z, input.gradient_offsets_high.w
+    );
 
-        if input.position.y < roundness_center.y {
-            return input.color;
-        }
-    } else {
-        roundness_center.y -= input.corner_roundness.x;
+    var colors = array<u32, 8>(
+        input.gradient_colors_low.x, input.gradient_colors_low.y, input.gradient_colors_low.z, input.gradient_colors_low.w,
+        input.gradient_colors_high.x, input.gradient_colors_high.y, input.gradient_colors_high.z, input.gradient_colors_high.w
+    );
+
+    if offset <= offsets[0] {
+        return unpack4x8unorm(colors[0]);
+    }
 
-        if input.position.y > roundness_center.y {
-            return input.color;
+    for (var i = 1u; i < count; i++) {
+        if offset <= offsets[i] {
+            let range = max(offsets[i] - offsets[i - 1u], 0.0001);
+            return mix(unpack4x8unorm(colors[i - 1u]), unpack4x8unorm(colors[i]), (offset - offsets[i - 1u]) / range);
         }
     }
 
-    let pixel_distance = distance(input.position.xy, roundness_center);
-    let check_distance = input.corner_roundness.z - input.corner_roundness.x;
+    return unpack4x8unorm(colors[count - 1u]);
+}
 
-    if check_distance - 0.2 > pixel_distance {
-        return input.color;
-    }
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let color = fill_color(input);
 
-    if check_distance > pixel_distance {
-        return vec4<f32>(input.color.xyz, (input.color.w / 4.0) * 3.0);
-    }
+    let center = input.box_center_and_half_size.xy;
+    let half_size = input.box_center_and_half_size.zw;
 
-    return vec4<f32>(0.0, 0.0, 0.0, 0.0);
-}
\ No newline at end of file
+    let distance = rounded_box_distance(
+        input.position.xy,
+        vec4<f32>(center - half_size, center + half_size),
+        input.corner_radii_x,
+        input.corner_radii_y
+    );
+
+    // Pixel coverage of the shape, the edge is smoothed over a single pixel without relying on MSAA
+    let alpha = color.a * clamp(0.5 - distance, 0.0, 1.0);
+
+    return vec4<f32>(color.rgb * alpha, alpha);
+}
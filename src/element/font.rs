@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use bevy::{
+    asset::{io::Reader, Asset, AssetLoader, AsyncReadExt, Handle, LoadContext},
+    ecs::system::{lifetimeless::SResMut, SystemParamItem},
+    log::error,
+    prelude::{Component, ReflectComponent, Resource},
+    reflect::{Reflect, TypePath},
+    render::render_asset::{PrepareAssetError, RenderAsset, RenderAssets},
+    utils::{BoxedFuture, HashSet},
+};
+use glyphon::{
+    cosmic_text::{fontdb, Family, Style, Weight},
+    FontSystem,
+};
+
+// Bundled so text renders the same whatever fonts are installed on the system
+const FALLBACK_FONTS: [&[u8]; 4] = [
+    include_bytes!("fonts/DejaVuSans.ttf"),
+    include_bytes!("fonts/DejaVuSans-Bold.ttf"),
+    include_bytes!("fonts/DejaVuSans-Oblique.ttf"),
+    include_bytes!("fonts/DejaVuSans-BoldOblique.ttf"),
+];
+const FALLBACK_FONT_FAMILY: &str = "DejaVu Sans";
+
+// Only the regular face is bundled, bold & italic monospace text falls back to it
+const FALLBACK_MONOSPACE_FONT: &[u8] = include_bytes!("fonts/DejaVuSansMono.ttf");
+const FALLBACK_MONOSPACE_FONT_FAMILY: &str = "DejaVu Sans Mono";
+
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct UiFont {
+    data: Arc<Vec<u8>>,
+}
+
+impl UiFont {
+    pub fn from_bytes(data: Vec<u8>) -> UiFont {
+        UiFont {
+            data: Arc::new(data),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct UiFontLoader;
+
+impl AssetLoader for UiFontLoader {
+    type Asset = UiFont;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut data = Vec::new();
+            reader.read_to_end(&mut data).await?;
+
+            Ok(UiFont::from_bytes(data))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ttf", "otf"]
+    }
+}
+
+// The family name the font got registered with in the render world font system
+#[derive(Debug, Clone)]
+pub struct LoadedUiFont {
+    pub family: String,
+}
+
+impl RenderAsset for UiFont {
+    type ExtractedAsset = UiFont;
+    type PreparedAsset = LoadedUiFont;
+    type Param = SResMut<UiFontSystem>;
+
+    fn extract_asset(&self) -> Self::ExtractedAsset {
+        self.clone()
+    }
+
+    fn prepare_asset(
+        font: Self::ExtractedAsset,
+        font_system: &mut SystemParamItem<Self::Param>,
+    ) -> Result<Self::PreparedAsset, PrepareAssetError<Self::ExtractedAsset>> {
+        let font_database = font_system.0.db_mut();
+
+        let known_faces = font_database
+            .faces()
+            .map(|face| face.id)
+            .collect::<HashSet<_>>();
+
+        font_database.load_font_source(fontdb::Source::Binary(font.data));
+
+        let family = font_database
+            .faces()
+            .filter(|face| !known_faces.contains(&face.id))
+            .find_map(|face| face.families.first())
+            .map(|(family, _)| family.clone());
+
+        let Some(family) = family else {
+            error!("Couldn't find a font face in the loaded font, using the fallback font instead");
+
+            return Ok(LoadedUiFont {
+                family: FALLBACK_FONT_FAMILY.to_string(),
+            });
+        };
+
+        Ok(LoadedUiFont { family })
+    }
+}
+
+#[derive(Resource, Debug, Default, Clone)]
+pub struct UiFontSettings {
+    // Makes the installed fonts available by name, the generic families still resolve to the bundled font
+    pub load_system_fonts: bool,
+}
+
+// Lives in the render world, holds the bundled fallback font & every loaded UiFont
+#[derive(Resource)]
+pub struct UiFontSystem(pub(crate) FontSystem);
+
+impl UiFontSystem {
+    pub(crate) fn new(settings: &UiFontSettings) -> UiFontSystem {
+        let mut font_database = fontdb::Database::new();
+        for font in FALLBACK_FONTS {
+            font_database.load_font_data(font.to_vec());
+        }
+        font_database.load_font_data(FALLBACK_MONOSPACE_FONT.to_vec());
+
+        if settings.load_system_fonts {
+            font_database.load_system_fonts();
+        }
+
+        font_database.set_sans_serif_family(FALLBACK_FONT_FAMILY);
+        font_database.set_serif_family(FALLBACK_FONT_FAMILY);
+        font_database.set_monospace_family(FALLBACK_MONOSPACE_FONT_FAMILY);
+        font_database.set_cursive_family(FALLBACK_FONT_FAMILY);
+        font_database.set_fantasy_family(FALLBACK_FONT_FAMILY);
+
+        UiFontSystem(FontSystem::new_with_locale_and_db(
+            String::from("en-US"),
+            font_database,
+        ))
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum FontFamily {
+    #[default]
+    SansSerif,
+    Serif,
+    Monospace,
+    Name(String),
+    Font(Handle<UiFont>),
+}
+
+impl FontFamily {
+    pub fn name(name: impl Into<String>) -> FontFamily {
+        FontFamily::Name(name.into())
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct FontWeight(pub u16);
+
+impl FontWeight {
+    pub const THIN: FontWeight = FontWeight(100);
+    pub const LIGHT: FontWeight = FontWeight(300);
+    pub const NORMAL: FontWeight = FontWeight(400);
+    pub const MEDIUM: FontWeight = FontWeight(500);
+    pub const SEMIBOLD: FontWeight = FontWeight(600);
+    pub const BOLD: FontWeight = FontWeight(700);
+    pub const BLACK: FontWeight = FontWeight(900);
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        FontWeight::NORMAL
+    }
+}
+
+impl From<FontWeight> for Weight {
+    fn from(weight: FontWeight) -> Self {
+        Weight(weight.0)
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum FontStyle {
+    #[default]
+    Normal,
+    Italic,
+    Oblique,
+}
+
+impl From<FontStyle> for Style {
+    fn from(style: FontStyle) -> Self {
+        match style {
+            FontStyle::Normal => Style::Normal,
+            FontStyle::Italic => Style::Italic,
+            FontStyle::Oblique => Style::Oblique,
+        }
+    }
+}
+
+// Resolves to a glyphon family, None when the font asset isn't loaded into the font system yet
+pub(crate) fn resolve_family<'a>(
+    family: &'a FontFamily,
+    loaded_fonts: &'a RenderAssets<UiFont>,
+) -> Option<Family<'a>> {
+    match family {
+        FontFamily::SansSerif => Some(Family::SansSerif),
+        FontFamily::Serif => Some(Family::Serif),
+        FontFamily::Monospace => Some(Family::Monospace),
+        FontFamily::Name(name) => Some(Family::Name(name)),
+        FontFamily::Font(font) => loaded_fonts
+            .get(font)
+            .map(|loaded_font| Family::Name(&loaded_font.family)),
+    }
+}
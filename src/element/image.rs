@@ -0,0 +1,950 @@
+use std::ops::Deref;
+
+use bevy::{
+    asset::{embedded_asset, AssetId},
+    ecs::system::lifetimeless::SRes,
+    log::error,
+    prelude::{
+        AssetEvent, AssetServer, Bundle, Color, Component, Entity, EventReader, FromWorld, Handle,
+        Image, IntoSystemConfigs, Msaa, Plugin, Query, Rect, ReflectComponent, Res, ResMut,
+        Resource, Shader, Vec2, World,
+    },
+    reflect::Reflect,
+    render::{
+        render_asset::RenderAssets,
+        render_phase::{
+            AddRenderCommand, DrawFunctions, PhaseItem, RenderCommand, RenderCommandResult,
+            RenderPhase, SetItemPipeline,
+        },
+        render_resource::{
+            BindGroup, BindGroupEntries, BindGroupLayout, BindGroupLayoutDescriptor,
+            BindGroupLayoutEntry, BindingType, BlendState, BufferUsages, BufferVec,
+            CachedRenderPipelineId, ColorTargetState, ColorWrites, FragmentState, FrontFace,
+            MultisampleState, PipelineCache, PolygonMode, PrimitiveState, PrimitiveTopology,
+            RenderPipelineDescriptor, SamplerBindingType, ShaderStages, TextureFormat,
+            TextureSampleType, TextureViewDimension, VertexAttribute, VertexBufferLayout,
+            VertexFormat, VertexState, VertexStepMode,
+        },
+        renderer::{RenderDevice, RenderQueue},
+        texture::BevyDefault,
+        Extract, ExtractSchedule, Render, RenderApp, RenderSet,
+    },
+    utils::HashMap,
+};
+use bytemuck_derive::{Pod, Zeroable};
+
+use crate::{
+    camera::{PhysicalViewportSize, UiMsaa, UiPhaseItem, UiPhaseLayer},
+    element::r#box::corner_radii,
+    prelude::AutoZUpdate,
+    property::{
+        state::CurrentlyActive,
+        update::{AutoVisibleRegionUpdate, TreeOrder},
+        ColoredElement, CornersRoundness, CornersShape, Position, Size, VisibleRegion, ZLevel,
+    },
+};
+
+pub(crate) struct UiImagePlugin;
+
+impl Plugin for UiImagePlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        embedded_asset!(app, "src/", "image.wgsl");
+    }
+
+    fn finish(&self, app: &mut bevy::prelude::App) {
+        let image_shader = app
+            .world
+            .resource::<AssetServer>()
+            .load("embedded://epui/element/image.wgsl");
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .insert_resource(ImageShader(image_shader))
+            .init_resource::<ImagePipeline>()
+            .init_resource::<ImageMeta>()
+            .init_resource::<ExtractedImages>()
+            .add_render_command::<UiPhaseItem, RenderImageCommand>()
+            .add_systems(ExtractSchedule, (extract_image_events, extract_images))
+            .add_systems(Render, queue_images.in_set(RenderSet::Queue));
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiImage {
+    pub texture: Handle<Image>,
+}
+
+impl UiImage {
+    #[inline]
+    pub fn new(texture: Handle<Image>) -> UiImage {
+        UiImage { texture }
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum ImageFit {
+    #[default]
+    Fill,
+    Contain,
+    Cover,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub enum TextureAtlasLayout {
+    Grid {
+        tile_size: Vec2,
+        columns: u32,
+        rows: u32,
+
+        padding: Vec2,
+        offset: Vec2,
+    },
+    Rects(Vec<Rect>),
+}
+
+impl TextureAtlasLayout {
+    pub fn grid(tile_size: Vec2, columns: u32, rows: u32) -> TextureAtlasLayout {
+        TextureAtlasLayout::Grid {
+            tile_size,
+            columns,
+            rows,
+
+            padding: Vec2::ZERO,
+            offset: Vec2::ZERO,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            TextureAtlasLayout::Grid { columns, rows, .. } => (*columns * *rows) as usize,
+            TextureAtlasLayout::Rects(rects) => rects.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Texture region of the index in pixels
+    pub fn rect(&self, index: usize) -> Option<Rect> {
+        match self {
+            TextureAtlasLayout::Grid {
+                tile_size,
+                columns,
+                padding,
+                offset,
+                ..
+            } => {
+                if index >= self.len() || *columns == 0 {
+                    return None;
+                }
+
+                let cell = Vec2::new(
+                    (index as u32 % *columns) as f32,
+                    (index as u32 / *columns) as f32,
+                );
+
+                let min = *offset + cell * (*tile_size + *padding);
+                Some(Rect::from_corners(min, min + *tile_size))
+            }
+            TextureAtlasLayout::Rects(rects) => rects.get(index).copied(),
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct TextureAtlas {
+    pub layout: TextureAtlasLayout,
+    pub index: usize,
+}
+
+impl TextureAtlas {
+    #[inline]
+    pub fn new(layout: TextureAtlasLayout, index: usize) -> TextureAtlas {
+        TextureAtlas { layout, index }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+pub enum SliceScaleMode {
+    #[default]
+    Stretch,
+    Tile,
+}
+
+// Insets are in source pixels, corners are drawn unscaled & only edges/center get stretched or tiled
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct NineSlice {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+
+    pub edges: SliceScaleMode,
+    pub center: SliceScaleMode,
+}
+
+impl NineSlice {
+    #[inline]
+    pub fn new(left: f32, right: f32, top: f32, bottom: f32) -> NineSlice {
+        NineSlice {
+            left,
+            right,
+            top,
+            bottom,
+
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn from_inset(inset: f32) -> NineSlice {
+        NineSlice::new(inset, inset, inset, inset)
+    }
+
+    #[inline]
+    pub fn with_modes(self, edges: SliceScaleMode, center: SliceScaleMode) -> NineSlice {
+        NineSlice {
+            edges,
+            center,
+            ..self
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct UiImageBundle {
+    pub image: UiImage,
+    pub fit: ImageFit,
+    pub color: ColoredElement,
+
+    pub position: Position,
+    pub size: Size,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl Default for UiImageBundle {
+    fn default() -> Self {
+        Self {
+            image: Default::default(),
+            fit: Default::default(),
+            color: ColoredElement::new(Color::WHITE),
+
+            position: Default::default(),
+            size: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct ImagePipeline {
+    layout: BindGroupLayout,
+    pipeline: Option<(CachedRenderPipelineId, u32)>,
+}
+
+impl FromWorld for ImagePipeline {
+    fn from_world(world: &mut World) -> Self {
+        let layout =
+            world
+                .resource::<RenderDevice>()
+                .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                    label: Some("image_bind_group_layout"),
+                    entries: &[
+                        BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Texture {
+                                sample_type: TextureSampleType::Float { filterable: true },
+                                view_dimension: TextureViewDimension::D2,
+                                multisampled: false,
+                            },
+                            count: None,
+                        },
+                        BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                            count: None,
+                        },
+                    ],
+                });
+
+        ImagePipeline {
+            layout,
+            pipeline: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Zeroable, Pod)]
+#[repr(C)]
+struct ImageInstanceData {
+    vertices: [[f32; 2]; 4], // Vertices (Vec2[4])
+
+    uv_rect: [f32; 4], // UV Rect { Min U, Min V, Max U, Max V } (Vec4)
+    color: [f32; 4],   // Tint Color (RGBA)
+
+    box_rect: [f32; 4],    // Element Rect { Min X, Min Y, Max X, Max Y } (Vec4)
+    box_radii_x: [f32; 4], // Element Corners Horizontal Radii { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
+    box_radii_y: [f32; 4], // Element Corners Vertical Radii { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
+}
+
+#[derive(Resource)]
+struct ImageMeta {
+    instances: BufferVec<ImageInstanceData>,
+
+    // Kept across frames, dropped when their image gets modified or removed
+    bind_groups: HashMap<AssetId<Image>, BindGroup>,
+    instance_images: Vec<AssetId<Image>>,
+}
+
+impl Default for ImageMeta {
+    fn default() -> Self {
+        ImageMeta {
+            instances: BufferVec::new(BufferUsages::VERTEX),
+
+            bind_groups: HashMap::new(),
+            instance_images: Vec::new(),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct ImageShader(Handle<Shader>);
+
+impl Deref for ImageShader {
+    type Target = Handle<Shader>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug)]
+struct ImageInstance {
+    image: AssetId<Image>,
+    atlas_rect: Option<Rect>,
+    fit: ImageFit,
+    nine_slice: Option<NineSlice>,
+
+    position: Position,
+    size: Size,
+
+    visible_region: VisibleRegion,
+    color: ColoredElement,
+
+    corners_roundness: CornersRoundness,
+    corners_shape: CornersShape,
+    z_level: ZLevel,
+    tree_order: TreeOrder,
+}
+
+#[derive(Debug, Default, Resource)]
+struct ExtractedImages(Vec<ImageInstance>);
+
+fn extract_images(
+    images: Extract<
+        Query<(
+            &UiImage,
+            Option<&TextureAtlas>,
+            Option<&ImageFit>,
+            Option<&NineSlice>,
+            &CurrentlyActive<Position>,
+            &CurrentlyActive<Size>,
+            &CurrentlyActive<VisibleRegion>,
+            &CurrentlyActive<ColoredElement>,
+            Option<&CurrentlyActive<CornersRoundness>>,
+            Option<&CornersShape>,
+            Option<&CurrentlyActive<ZLevel>>,
+            Option<&TreeOrder>,
+        )>,
+    >,
+    mut extracted_images: ResMut<ExtractedImages>,
+) {
+    extracted_images.0.clear();
+
+    for (
+        image,
+        texture_atlas,
+        fit,
+        nine_slice,
+        position,
+        size,
+        visible_region,
+        colored_element,
+        corners_roundness,
+        corners_shape,
+        z_level,
+        tree_order,
+    ) in images.iter()
+    {
+        let atlas_rect = match texture_atlas {
+            Some(texture_atlas) => {
+                let Some(atlas_rect) = texture_atlas.layout.rect(texture_atlas.index) else {
+                    error!(
+                        "Texture atlas index {} is out of bounds ({} textures)",
+                        texture_atlas.index,
+                        texture_atlas.layout.len()
+                    );
+
+                    continue;
+                };
+
+                Some(atlas_rect)
+            }
+            None => None,
+        };
+
+        extracted_images.0.push(ImageInstance {
+            image: image.texture.id(),
+            atlas_rect,
+            fit: fit.copied().unwrap_or_default(),
+            nine_slice: nine_slice.cloned(),
+
+            position: position.clone(),
+            size: size.clone(),
+
+            visible_region: visible_region.clone(),
+            color: colored_element.clone(),
+
+            corners_roundness: corners_roundness.cloned().unwrap_or_default(),
+            corners_shape: corners_shape.copied().unwrap_or_default(),
+            z_level: z_level.cloned().unwrap_or_default(),
+            tree_order: tree_order.copied().unwrap_or_default(),
+        });
+    }
+}
+
+fn extract_image_events(
+    mut image_events: Extract<EventReader<AssetEvent<Image>>>,
+    mut image_meta: ResMut<ImageMeta>,
+) {
+    for event in image_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            image_meta.bind_groups.remove(id);
+        }
+    }
+}
+
+// Returns the region the image gets drawn to & the normalized region of the source it samples
+fn fit_image(fit: ImageFit, element_rect: Rect, source_size: Vec2) -> (Rect, Rect) {
+    let full_uv = Rect::new(0.0, 0.0, 1.0, 1.0);
+
+    if source_size.min_element() <= 0.0 {
+        return (element_rect, full_uv);
+    }
+
+    let element_size = element_rect.size();
+    let center = element_rect.center();
+
+    match fit {
+        ImageFit::Fill => (element_rect, full_uv),
+        ImageFit::Contain => {
+            let scale = (element_size / source_size).min_element();
+
+            (Rect::from_center_size(center, source_size * scale), full_uv)
+        }
+        ImageFit::Cover => {
+            let scale = (element_size / source_size).max_element();
+
+            (
+                element_rect,
+                Rect::from_center_size(Vec2::splat(0.5), element_size / (source_size * scale)),
+            )
+        }
+        ImageFit::None => {
+            let full_rect = Rect::from_center_size(center, source_size);
+            let drawn_rect = full_rect.intersect(element_rect);
+
+            (
+                drawn_rect,
+                Rect::from_corners(
+                    (drawn_rect.min - full_rect.min) / source_size,
+                    (drawn_rect.max - full_rect.min) / source_size,
+                ),
+            )
+        }
+    }
+}
+
+// Splits a single axis into { Start, Middle, End } segments of (drawn start, drawn end, source start, source end)
+fn slice_axis(
+    element_start: f32,
+    element_length: f32,
+    source_length: f32,
+    start_inset: f32,
+    end_inset: f32,
+) -> [(f32, f32, f32, f32); 3] {
+    let insets = start_inset + end_inset;
+
+    // Corners shrink proportionally when the element is smaller than both insets
+    let scale = if insets > element_length && insets > 0.0 {
+        element_length / insets
+    } else {
+        1.0
+    };
+
+    let drawn_start = start_inset * scale;
+    let drawn_end = end_inset * scale;
+
+    [
+        (element_start, element_start + drawn_start, 0.0, start_inset),
+        (
+            element_start + drawn_start,
+            element_start + element_length - drawn_end,
+            start_inset,
+            source_length - end_inset,
+        ),
+        (
+            element_start + element_length - drawn_end,
+            element_start + element_length,
+            source_length - end_inset,
+            source_length,
+        ),
+    ]
+}
+
+// Repeats a segment along its axis at the source size, cropping the last tile
+fn tile_segment(segment: (f32, f32, f32, f32), mode: SliceScaleMode) -> Vec<(f32, f32, f32, f32)> {
+    let (drawn_start, drawn_end, source_start, source_end) = segment;
+    let source_length = source_end - source_start;
+
+    if mode == SliceScaleMode::Stretch || source_length <= 0.0 {
+        return vec![segment];
+    }
+
+    let mut tiles = Vec::new();
+    let mut tile_start = drawn_start;
+
+    while tile_start < drawn_end {
+        let tile_end = (tile_start + source_length).min(drawn_end);
+
+        tiles.push((
+            tile_start,
+            tile_end,
+            source_start,
+            source_start + (tile_end - tile_start),
+        ));
+
+        tile_start = tile_end;
+    }
+
+    tiles
+}
+
+fn slice_image(nine_slice: &NineSlice, element_rect: Rect, source_size: Vec2) -> Vec<(Rect, Rect)> {
+    if source_size.min_element() <= 0.0 {
+        return Vec::new();
+    }
+
+    let columns = slice_axis(
+        element_rect.min.x,
+        element_rect.width(),
+        source_size.x,
+        nine_slice.left,
+        nine_slice.right,
+    );
+
+    let rows = slice_axis(
+        element_rect.min.y,
+        element_rect.height(),
+        source_size.y,
+        nine_slice.top,
+        nine_slice.bottom,
+    );
+
+    let mut pieces = Vec::with_capacity(9);
+
+    for (row_index, row) in rows.into_iter().enumerate() {
+        for (column_index, column) in columns.into_iter().enumerate() {
+            let is_center = row_index == 1 && column_index == 1;
+            let mode = if is_center {
+                nine_slice.center
+            } else {
+                nine_slice.edges
+            };
+
+            // Corners are never repeated, edges only repeat along their length
+            let column_mode = if column_index == 1 {
+                mode
+            } else {
+                SliceScaleMode::Stretch
+            };
+
+            let row_mode = if row_index == 1 {
+                mode
+            } else {
+                SliceScaleMode::Stretch
+            };
+
+            for row_tile in tile_segment(row, row_mode) {
+                for column_tile in tile_segment(column, column_mode) {
+                    let drawn_rect =
+                        Rect::new(column_tile.0, row_tile.0, column_tile.1, row_tile.1);
+
+                    if drawn_rect.is_empty() {
+                        continue;
+                    }
+
+                    pieces.push((
+                        drawn_rect,
+                        Rect::new(
+                            column_tile.2 / source_size.x,
+                            row_tile.2 / source_size.y,
+                            column_tile.3 / source_size.x,
+                            row_tile.3 / source_size.y,
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    pieces
+}
+
+pub(crate) fn queue_images(
+    mut image_pipeline: ResMut<ImagePipeline>,
+    pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    ui_msaa: Res<UiMsaa>,
+
+    mut view_query: Query<(Entity, &PhysicalViewportSize, &mut RenderPhase<UiPhaseItem>)>,
+    mut image_meta: ResMut<ImageMeta>,
+    extracted_images: Res<ExtractedImages>,
+    gpu_images: Res<RenderAssets<Image>>,
+    draw_functions: Res<DrawFunctions<UiPhaseItem>>,
+
+    image_shader: Res<ImageShader>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+) {
+    let ImagePipeline { layout, pipeline } = image_pipeline.as_mut();
+    let samples = ui_msaa.samples(&msaa);
+
+    if pipeline.is_some_and(|(_, pipeline_samples)| pipeline_samples != samples) {
+        *pipeline = None;
+    }
+
+    let (pipeline, _) = *pipeline.get_or_insert_with(|| {
+        (
+            pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+                label: Some("image_pipeline_desc".into()),
+                layout: vec![layout.clone()],
+                vertex: VertexState {
+                    entry_point: "vs_main".into(),
+                    shader: image_shader.0.clone(),
+                    shader_defs: vec![],
+                    buffers: vec![VertexBufferLayout {
+                        step_mode: VertexStepMode::Instance,
+                        attributes: vec![
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: 0,
+                                shader_location: 0,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[f32; 2]>() as u64,
+                                shader_location: 1,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[[f32; 2]; 2]>() as u64,
+                                shader_location: 2,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[[f32; 2]; 3]>() as u64,
+                                shader_location: 3,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 4]>() as u64,
+                                shader_location: 4,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 6]>() as u64, // f32x4 = f32x2 * 2
+                                shader_location: 5,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 8]>() as u64,
+                                shader_location: 6,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 10]>() as u64,
+                                shader_location: 7,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 12]>() as u64,
+                                shader_location: 8,
+                            },
+                        ],
+                        array_stride: std::mem::size_of::<ImageInstanceData>() as u64,
+                    }],
+                },
+                fragment: Some(FragmentState {
+                    entry_point: "fs_main".into(),
+                    shader: image_shader.0.clone(),
+                    shader_defs: vec![],
+                    targets: vec![Some(ColorTargetState {
+                        format: TextureFormat::bevy_default(),
+                        blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                        write_mask: ColorWrites::ALL,
+                    })],
+                }),
+                push_constant_ranges: vec![],
+                primitive: PrimitiveState {
+                    topology: PrimitiveTopology::TriangleList,
+                    polygon_mode: PolygonMode::Fill,
+                    conservative: false,
+                    cull_mode: None,
+                    front_face: FrontFace::Ccw,
+                    strip_index_format: None,
+                    unclipped_depth: false,
+                },
+                multisample: MultisampleState {
+                    count: samples,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+            }),
+            samples,
+        )
+    });
+
+    let draw_function_id = draw_functions.read().id::<RenderImageCommand>();
+    let mut instances = Vec::new();
+
+    let ImageMeta {
+        instances: instances_buffer,
+        bind_groups,
+        instance_images,
+    } = image_meta.as_mut();
+
+    // Every view gets its own instances in the same buffer, the index keeps running across views
+    let mut instance = 0;
+    instances_buffer.clear();
+    instance_images.clear();
+
+    let images_count = extracted_images.0.len();
+    instances.reserve(images_count * view_query.iter().len());
+
+    for (camera_entity, viewport_size, mut ui_phase) in view_query.iter_mut() {
+        let Some(viewport_size) = viewport_size.0 else {
+            continue;
+        };
+
+        let x_pixel_unit = 2.0 / viewport_size.x as f32;
+        let y_pixel_unit = 2.0 / viewport_size.y as f32;
+
+        ui_phase.items.reserve(images_count);
+
+        for ImageInstance {
+            image,
+            atlas_rect,
+            fit,
+            nine_slice,
+            position,
+            size,
+            visible_region,
+            color,
+            corners_roundness,
+            corners_shape,
+            z_level,
+            tree_order,
+        } in extracted_images.0.iter()
+        {
+            let Some(gpu_image) = gpu_images.get(*image) else {
+                continue;
+            };
+
+            let element_rect = Rect::from_corners(
+                Vec2::from(position.clone()),
+                Vec2::from(position.clone()) + Vec2::from(size.clone()),
+            );
+
+            let source_rect = atlas_rect.unwrap_or(Rect::from_corners(Vec2::ZERO, gpu_image.size));
+
+            let pieces = match nine_slice {
+                Some(nine_slice) => slice_image(nine_slice, element_rect, source_rect.size()),
+                None => vec![fit_image(*fit, element_rect, source_rect.size())],
+            };
+
+            let (box_radii_x, box_radii_y) =
+                corner_radii(corners_roundness, *corners_shape, element_rect.half_size());
+
+            let first_instance = instance;
+
+            for (drawn_rect, uv_rect) in pieces {
+                let actual_visible_region =
+                    Rect::from(visible_region.clone()).intersect(drawn_rect);
+
+                if actual_visible_region.is_empty() {
+                    continue;
+                }
+
+                // Crop the sampled region the same way the drawn region got clipped
+                let uv_scale = uv_rect.size() / drawn_rect.size().max(Vec2::splat(f32::EPSILON));
+                let clipped_uv_rect = Rect::from_corners(
+                    uv_rect.min + (actual_visible_region.min - drawn_rect.min) * uv_scale,
+                    uv_rect.min + (actual_visible_region.max - drawn_rect.min) * uv_scale,
+                );
+
+                let texture_uv_rect = Rect::from_corners(
+                    (source_rect.min + clipped_uv_rect.min * source_rect.size()) / gpu_image.size,
+                    (source_rect.min + clipped_uv_rect.max * source_rect.size()) / gpu_image.size,
+                );
+
+                let left_top_corner = Vec2::new(
+                    (x_pixel_unit * actual_visible_region.min.x) - 1.0,
+                    1.0 - (y_pixel_unit * actual_visible_region.min.y),
+                );
+
+                let right_top_corner = Vec2::new(
+                    left_top_corner.x + (x_pixel_unit * actual_visible_region.width()),
+                    left_top_corner.y,
+                );
+
+                let left_bottom_corner = Vec2::new(
+                    left_top_corner.x,
+                    left_top_corner.y - (y_pixel_unit * actual_visible_region.height()),
+                );
+
+                let right_bottom_corner = Vec2::new(right_top_corner.x, left_bottom_corner.y);
+
+                instances.push(ImageInstanceData {
+                    vertices: [
+                        left_top_corner.into(),
+                        right_top_corner.into(),
+                        left_bottom_corner.into(),
+                        right_bottom_corner.into(),
+                    ],
+
+                    uv_rect: [
+                        texture_uv_rect.min.x,
+                        texture_uv_rect.min.y,
+                        texture_uv_rect.max.x,
+                        texture_uv_rect.max.y,
+                    ],
+                    color: color.color.rgba_to_vec4().into(),
+
+                    box_rect: [
+                        element_rect.min.x,
+                        element_rect.min.y,
+                        element_rect.max.x,
+                        element_rect.max.y,
+                    ],
+                    box_radii_x: box_radii_x.into(),
+                    box_radii_y: box_radii_y.into(),
+                });
+
+                instance_images.push(*image);
+                instance += 1;
+            }
+
+            if first_instance == instance {
+                continue;
+            }
+
+            if !bind_groups.contains_key(image) {
+                bind_groups.insert(
+                    *image,
+                    render_device.create_bind_group(
+                        "image_bind_group",
+                        layout,
+                        &BindGroupEntries::sequential((
+                            &gpu_image.texture_view,
+                            &gpu_image.sampler,
+                        )),
+                    ),
+                );
+            }
+
+            // All the pieces of an image share the same texture, so they're drawn in a single instanced draw
+            let ui_phase_item = UiPhaseItem {
+                entity: camera_entity,
+                z_index: z_level.0,
+                tree_order: tree_order.0,
+                layer: UiPhaseLayer::Image,
+
+                draw_function: draw_function_id,
+                cached_render_pipeline_id: pipeline,
+
+                batch_range: first_instance..instance,
+                dynamic_offset: None,
+                batchable: false,
+            };
+
+            ui_phase.add(ui_phase_item);
+        }
+    }
+
+    instances_buffer.extend(instances);
+    instances_buffer.write_buffer(&render_device, &render_queue);
+}
+
+type RenderImageCommand = (SetItemPipeline, DrawImage);
+
+struct DrawImage;
+impl RenderCommand<UiPhaseItem> for DrawImage {
+    type Param = SRes<ImageMeta>;
+    type ViewWorldQuery = ();
+    type ItemWorldQuery = ();
+
+    fn render<'w>(
+        item: &UiPhaseItem,
+        _view: bevy::ecs::query::ROQueryItem<'w, Self::ViewWorldQuery>,
+        _entity: bevy::ecs::query::ROQueryItem<'w, Self::ItemWorldQuery>,
+        image_meta: bevy::ecs::system::SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut bevy::render::render_phase::TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let image_meta = image_meta.into_inner();
+
+        let Some(bind_group) = image_meta
+            .instance_images
+            .get(item.batch_range().start as usize)
+            .and_then(|image| image_meta.bind_groups.get(image))
+        else {
+            error!("Couldn't find the bind group of the image being drawn");
+            return RenderCommandResult::Failure;
+        };
+
+        let vertex_buffer = match image_meta.instances.buffer() {
+            Some(buffer) => buffer,
+            None => {
+                error!("Couldn't set vertex buffer because it's not present");
+                return RenderCommandResult::Failure;
+            }
+        };
+
+        pass.set_bind_group(0, bind_group, &[]);
+        pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+        pass.draw(0..6, item.batch_range().clone());
+
+        RenderCommandResult::Success
+    }
+}
@@ -0,0 +1,72 @@
+#import epui::rounded_box::rounded_box_distance
+
+struct InstanceInput {
+    @location(0) top_left_position: vec2<f32>,
+    @location(1) top_right_position: vec2<f32>,
+    @location(2) bottom_left_position: vec2<f32>,
+    @location(3) bottom_right_position: vec2<f32>,
+
+    @location(4) uv_rect: vec4<f32>,
+    @location(5) color: vec4<f32>,
+
+    @location(6) box_rect: vec4<f32>,
+    @location(7) box_radii_x: vec4<f32>,
+    @location(8) box_radii_y: vec4<f32>
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+
+    @location(0) uv: vec2<f32>,
+    @location(1) color: vec4<f32>,
+
+    @location(2) box_rect: vec4<f32>,
+    @location(3) box_radii_x: vec4<f32>,
+    @location(4) box_radii_y: vec4<f32>
+}
+
+@group(0) @binding(0) var image_texture: texture_2d<f32>;
+@group(0) @binding(1) var image_sampler: sampler;
+
+@vertex
+fn vs_main(@builtin(vertex_index) in_vertex_index: u32, in_instance: InstanceInput) -> VertexOutput {
+    var vertex_output: VertexOutput;
+
+    var position = in_instance.top_left_position;
+    var uv = in_instance.uv_rect.xy;
+
+    if in_vertex_index == 1u || in_vertex_index == 3u {
+        position = in_instance.top_right_position;
+        uv = in_instance.uv_rect.zy;
+    }
+
+    if in_vertex_index == 2u || in_vertex_index == 4u {
+        position = in_instance.bottom_left_position;
+        uv = in_instance.uv_rect.xw;
+    }
+
+    if in_vertex_index == 5u {
+        position = in_instance.bottom_right_position;
+        uv = in_instance.uv_rect.zw;
+    }
+
+    vertex_output.position = vec4<f32>(position, 0.0, 1.0);
+    vertex_output.uv = uv;
+    vertex_output.color = in_instance.color;
+
+    vertex_output.box_rect = in_instance.box_rect;
+    vertex_output.box_radii_x = in_instance.box_radii_x;
+    vertex_output.box_radii_y = in_instance.box_radii_y;
+
+    return vertex_output;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(image_texture, image_sampler, input.uv) * input.color;
+    let coverage = clamp(0.5 - rounded_box_distance(input.position.xy, input.box_rect, input.box_radii_x, input.box_radii_y), 0.0, 1.0);
+
+    let alpha = color.a * coverage;
+
+    return vec4<f32>(color.rgb * alpha, alpha);
+}
@@ -0,0 +1,341 @@
+use bevy::{
+    asset::{
+        io::Reader, Asset, AssetApp, AssetEvent, AssetLoader, Assets, AsyncReadExt, Handle,
+        LoadContext,
+    },
+    log::warn,
+    prelude::{
+        Component, DetectChanges, EventReader, Plugin, PostUpdate, Query, Ref, ReflectComponent,
+        Res, Resource,
+    },
+    reflect::{Reflect, TypePath},
+    utils::{BoxedFuture, HashMap},
+};
+
+use crate::element::text::UiText;
+
+pub(crate) struct UiLocalizationPlugin;
+
+impl Plugin for UiLocalizationPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_asset::<UiTranslation>()
+            .init_asset_loader::<UiTranslationLoader>()
+            .init_resource::<UiLocale>()
+            .add_systems(PostUpdate, update_localized_texts);
+    }
+}
+
+// Messages of one locale, loaded from a Fluent (.ftl) file
+// Only the subset without selectors is understood: messages, terms, comments, multiline values & placeables
+// referencing variables ({ $name }), terms ({ -brand }), other messages ({ other-message }) or literals ({ "{" })
+// Attributes are skipped & other placeables (selectors, functions) are left out of the text with a warning
+#[derive(Asset, TypePath, Debug, Default, Clone)]
+pub struct UiTranslation {
+    messages: HashMap<String, String>,
+}
+
+impl UiTranslation {
+    pub fn parse(source: &str) -> UiTranslation {
+        let mut messages = HashMap::new();
+        let mut current_message: Option<(String, String)> = None;
+        let mut in_attribute = false;
+
+        for line in source.lines() {
+            // Indented lines continue the value of the message above them, or of its attribute,
+            // like the line closing a multiline placeable
+            if line.starts_with([' ', '\t', '}']) && !line.trim().is_empty() {
+                in_attribute |= line.trim_start().starts_with('.');
+
+                if in_attribute {
+                    continue;
+                }
+
+                if let Some((_, value)) = &mut current_message {
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+
+                    value.push_str(line.trim());
+                    continue;
+                }
+            }
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            in_attribute = false;
+
+            if let Some((id, value)) = current_message.take() {
+                insert_message(&mut messages, id, value);
+            }
+
+            let Some((id, value)) = line.split_once('=') else {
+                warn!("Skipped a translation line without a message: {line}");
+                continue;
+            };
+
+            current_message = Some((id.trim().to_string(), value.trim().to_string()));
+        }
+
+        if let Some((id, value)) = current_message {
+            insert_message(&mut messages, id, value);
+        }
+
+        UiTranslation { messages }
+    }
+
+    pub fn format(&self, key: &str, args: &[(String, String)]) -> Option<String> {
+        self.format_message(key, args, 0)
+    }
+
+    fn format_message(&self, key: &str, args: &[(String, String)], depth: u32) -> Option<String> {
+        // Guards against messages referencing each other in a loop
+        const MAX_DEPTH: u32 = 8;
+
+        let value = self.messages.get(key)?;
+        let mut formatted = String::with_capacity(value.len());
+        let mut rest = value.as_str();
+
+        while let Some(start) = rest.find('{') {
+            formatted.push_str(&rest[..start]);
+
+            let Some(end) = placeable_end(&rest[start..]) else {
+                formatted.push_str(&rest[start..]);
+                rest = "";
+                break;
+            };
+
+            let placeable = rest[start + 1..start + end].trim();
+            rest = &rest[start + end + 1..];
+
+            if let Some(name) = placeable
+                .strip_prefix('$')
+                .filter(|name| is_identifier(name))
+            {
+                // Arguments are isolated so a right-to-left value doesn't reorder the text around it
+                match args.iter().find(|(arg_name, _)| arg_name == name) {
+                    Some((_, arg_value)) => {
+                        formatted.push('\u{2068}');
+                        formatted.push_str(arg_value);
+                        formatted.push('\u{2069}');
+                    }
+                    None => formatted.push_str(&format!("{{${name}}}")),
+                }
+            } else if let Some(literal) = placeable
+                .strip_prefix('"')
+                .and_then(|literal| literal.strip_suffix('"'))
+            {
+                formatted.push_str(literal);
+            } else if !is_identifier(placeable.strip_prefix('-').unwrap_or(placeable)) {
+                warn!("Left out the unsupported placeable {{ {placeable} }} of the message {key}");
+            } else {
+                let reference = (depth < MAX_DEPTH)
+                    .then(|| self.format_message(placeable, args, depth + 1))
+                    .flatten();
+
+                formatted.push_str(reference.as_deref().unwrap_or(placeable));
+            }
+        }
+
+        formatted.push_str(rest);
+        Some(formatted)
+    }
+}
+
+// Messages with only attributes have no value to show
+fn insert_message(messages: &mut HashMap<String, String>, id: String, value: String) {
+    if !value.is_empty() {
+        messages.insert(id, value);
+    }
+}
+
+// Index of the brace closing the placeable the text starts with, placeables can be nested in selectors
+// & string literals (which end with their line) can hold braces
+fn placeable_end(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_literal = false;
+    let mut escaped = false;
+
+    for (index, character) in text.char_indices() {
+        if in_literal {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' | '\n' => in_literal = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match character {
+            '"' => in_literal = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut characters = name.chars();
+
+    characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic())
+        && characters.all(|character| {
+            character.is_ascii_alphanumeric() || character == '-' || character == '_'
+        })
+}
+
+#[derive(Default)]
+pub(crate) struct UiTranslationLoader;
+
+impl AssetLoader for UiTranslationLoader {
+    type Asset = UiTranslation;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut source = String::new();
+            reader.read_to_string(&mut source).await?;
+
+            Ok(UiTranslation::parse(&source))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
+
+// Changing the current locale re-resolves every LocalizedText, which reshapes their text
+#[derive(Resource, Debug, Clone)]
+pub struct UiLocale {
+    pub current: String,
+    // Used for the messages missing from the current locale's translation
+    pub fallback: String,
+    pub translations: HashMap<String, Handle<UiTranslation>>,
+}
+
+impl UiLocale {
+    pub fn new(current: impl Into<String>) -> UiLocale {
+        let current = current.into();
+
+        UiLocale {
+            fallback: current.clone(),
+            current,
+            translations: HashMap::new(),
+        }
+    }
+
+    pub fn with_fallback(mut self, fallback: impl Into<String>) -> UiLocale {
+        self.fallback = fallback.into();
+        self
+    }
+
+    pub fn with_translation(
+        mut self,
+        locale: impl Into<String>,
+        translation: Handle<UiTranslation>,
+    ) -> UiLocale {
+        self.translations.insert(locale.into(), translation);
+        self
+    }
+
+    pub fn format(
+        &self,
+        translations: &Assets<UiTranslation>,
+        key: &str,
+        args: &[(String, String)],
+    ) -> Option<String> {
+        [&self.current, &self.fallback]
+            .into_iter()
+            .filter_map(|locale| self.translations.get(locale))
+            .filter_map(|translation| translations.get(translation))
+            .find_map(|translation| translation.format(key, args))
+    }
+}
+
+impl Default for UiLocale {
+    fn default() -> Self {
+        UiLocale::new("en-US")
+    }
+}
+
+// Keeps the UiText of the element set to the message in the current locale
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct LocalizedText {
+    pub key: String,
+    pub args: Vec<(String, String)>,
+}
+
+impl LocalizedText {
+    pub fn new(key: impl Into<String>) -> LocalizedText {
+        LocalizedText {
+            key: key.into(),
+            args: Vec::new(),
+        }
+    }
+
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl ToString) -> LocalizedText {
+        let name = name.into();
+        let value = value.to_string();
+
+        match self.args.iter_mut().find(|(arg_name, _)| *arg_name == name) {
+            Some((_, arg_value)) => *arg_value = value,
+            None => self.args.push((name, value)),
+        }
+
+        self
+    }
+}
+
+fn update_localized_texts(
+    locale: Res<UiLocale>,
+    translations: Res<Assets<UiTranslation>>,
+    mut translation_events: EventReader<AssetEvent<UiTranslation>>,
+    mut texts: Query<(Ref<LocalizedText>, &mut UiText)>,
+) {
+    let translations_changed = translation_events.read().any(|translation_event| {
+        matches!(
+            translation_event,
+            AssetEvent::LoadedWithDependencies { .. }
+                | AssetEvent::Modified { .. }
+                | AssetEvent::Removed { .. }
+        )
+    });
+
+    let resolve_all = locale.is_changed() || translations_changed;
+
+    for (localized_text, mut text) in texts.iter_mut() {
+        if !resolve_all && !localized_text.is_changed() {
+            continue;
+        }
+
+        // Missing messages show their key so they're easy to spot
+        let resolved_text = locale
+            .format(&translations, &localized_text.key, &localized_text.args)
+            .unwrap_or_else(|| localized_text.key.clone());
+
+        if text.0 != resolved_text {
+            text.0 = resolved_text;
+        }
+    }
+}
@@ -1,10 +1,34 @@
 pub mod r#box;
+pub mod font;
+pub mod image;
+pub mod localization;
+pub mod rich_text;
+pub mod shadow;
 pub mod text;
+pub mod text_selection;
 
+pub use font::{FontFamily, FontStyle, FontWeight, UiFont, UiFontSettings};
+pub use image::{
+    ImageFit, NineSlice, SliceScaleMode, TextureAtlas, TextureAtlasLayout, UiImage, UiImageBundle,
+};
+pub use localization::{LocalizedText, UiLocale, UiTranslation};
 pub use r#box::{UiBox, UiBoxBundle};
-pub use text::{FontSize, UiText, UiTextBundle};
+pub use rich_text::{TextLinkClickedEvent, TextSpan, UiRichText, UiRichTextBundle};
+pub use shadow::{BoxShadow, BoxShadows};
+pub use text::{
+    FontSize, LineHeight, TextAlign, TextDirection, TextGlyph, TextLayoutInfo, TextLine,
+    TextOverflow, TextWrap, UiText, UiTextBundle, VerticalAlign,
+};
+pub use text_selection::{SelectableText, TextCopyEvent, TextSelection};
 
 #[doc(hidden)]
 pub mod prelude {
-    pub use crate::element::{FontSize, UiBox, UiBoxBundle, UiText, UiTextBundle};
+    pub use crate::element::{
+        BoxShadow, BoxShadows, FontFamily, FontSize, FontStyle, FontWeight, ImageFit, LineHeight,
+        LocalizedText, NineSlice, SelectableText, SliceScaleMode, TextAlign, TextCopyEvent,
+        TextDirection, TextLayoutInfo, TextLinkClickedEvent, TextOverflow, TextSelection, TextSpan,
+        TextWrap, TextureAtlas, TextureAtlasLayout, UiBox, UiBoxBundle, UiFont, UiFontSettings,
+        UiImage, UiImageBundle, UiLocale, UiRichText, UiRichTextBundle, UiText, UiTextBundle,
+        UiTranslation, VerticalAlign,
+    };
 }
@@ -0,0 +1,308 @@
+use bevy::{
+    log::warn,
+    prelude::{
+        BuildChildren, Bundle, Changed, Children, Color, Commands, Component, DespawnRecursiveExt,
+        Entity, Event, EventReader, EventWriter, Or, Plugin, Query, ReflectComponent, Update, Vec2,
+        With,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    element::{
+        font::{FontFamily, FontStyle, FontWeight},
+        r#box::UiBoxBundle,
+        text::{FontSize, TextLayoutInfo},
+    },
+    event::ClickEvent,
+    prelude::AutoZUpdate,
+    property::{
+        update::AutoVisibleRegionUpdate, ColoredElement, Position, Size, VisibleRegion, ZLevel,
+    },
+};
+
+pub(crate) struct UiRichTextPlugin;
+
+impl Plugin for UiRichTextPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<TextLinkClickedEvent>()
+            .add_systems(Update, (on_text_link_click, update_text_decorations));
+    }
+}
+
+// A paragraph made of spans, every style left as None falls back to the element's one
+// Font sizes apply to whole lines, a line mixing sizes takes the largest one of its spans
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UiRichText(pub Vec<TextSpan>);
+
+impl UiRichText {
+    pub fn new(spans: impl IntoIterator<Item = TextSpan>) -> UiRichText {
+        UiRichText(spans.into_iter().collect())
+    }
+
+    pub fn text(&self) -> String {
+        self.0.iter().map(|span| span.text.as_str()).collect()
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TextSpan {
+    pub text: String,
+
+    pub color: Option<Color>,
+    pub font_size: Option<FontSize>,
+    pub font_family: Option<FontFamily>,
+    pub font_weight: Option<FontWeight>,
+    pub font_style: Option<FontStyle>,
+
+    pub underline: bool,
+    pub strikethrough: bool,
+
+    // Clicking the span sends a TextLinkClickedEvent with it
+    pub link: Option<String>,
+}
+
+impl TextSpan {
+    pub fn new(text: impl Into<String>) -> TextSpan {
+        TextSpan {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> TextSpan {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_size(mut self, font_size: FontSize) -> TextSpan {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    pub fn with_family(mut self, font_family: FontFamily) -> TextSpan {
+        self.font_family = Some(font_family);
+        self
+    }
+
+    pub fn with_weight(mut self, font_weight: FontWeight) -> TextSpan {
+        self.font_weight = Some(font_weight);
+        self
+    }
+
+    pub fn with_style(mut self, font_style: FontStyle) -> TextSpan {
+        self.font_style = Some(font_style);
+        self
+    }
+
+    pub fn bold(self) -> TextSpan {
+        self.with_weight(FontWeight::BOLD)
+    }
+
+    pub fn italic(self) -> TextSpan {
+        self.with_style(FontStyle::Italic)
+    }
+
+    pub fn underlined(mut self) -> TextSpan {
+        self.underline = true;
+        self
+    }
+
+    pub fn struck_through(mut self) -> TextSpan {
+        self.strikethrough = true;
+        self
+    }
+
+    pub fn with_link(mut self, link: impl Into<String>) -> TextSpan {
+        self.link = Some(link.into());
+        self
+    }
+}
+
+#[derive(Bundle)]
+pub struct UiRichTextBundle {
+    pub text: UiRichText,
+    pub font_size: FontSize,
+    pub font_family: FontFamily,
+    pub font_weight: FontWeight,
+    pub font_style: FontStyle,
+    pub color: ColoredElement,
+
+    pub position: Position,
+    pub size: Size,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl Default for UiRichTextBundle {
+    fn default() -> Self {
+        Self {
+            text: Default::default(),
+            font_size: Default::default(),
+            font_family: Default::default(),
+            font_weight: Default::default(),
+            font_style: Default::default(),
+            color: ColoredElement::new(Color::BLACK),
+
+            position: Default::default(),
+            size: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct TextLinkClickedEvent {
+    pub element: Entity,
+    pub span: usize,
+    pub link: String,
+}
+
+// Rich texts need a collision to receive the clicks, the clicked glyph then picks the span
+fn on_text_link_click(
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    rich_texts: Query<(&UiRichText, &Position, &TextLayoutInfo)>,
+    mut click_events: EventReader<ClickEvent>,
+    mut link_clicked_events: EventWriter<TextLinkClickedEvent>,
+) {
+    if click_events.is_empty() {
+        return;
+    }
+
+    let Ok(primary_window) = primary_window.get_single() else {
+        warn!("Couldn't get primary window");
+
+        click_events.clear();
+        return;
+    };
+
+    let Some(cursor_position) = primary_window.cursor_position() else {
+        click_events.clear();
+        return;
+    };
+
+    for click_event in click_events.read() {
+        let Ok((rich_text, position, layout_info)) = rich_texts.get(click_event.element) else {
+            continue;
+        };
+
+        let Some(glyph) =
+            layout_info.glyph_at(cursor_position.round() - Vec2::from(position.clone()))
+        else {
+            continue;
+        };
+
+        let Some(link) = rich_text
+            .0
+            .get(glyph.span)
+            .and_then(|span| span.link.as_ref())
+        else {
+            continue;
+        };
+
+        link_clicked_events.send(TextLinkClickedEvent {
+            element: click_event.element,
+            span: glyph.span,
+            link: link.clone(),
+        });
+    }
+}
+
+// Underlines & strikethroughs are drawn as thin boxes parented to the text, rebuilt whenever its layout changes
+#[derive(Component)]
+struct TextDecoration;
+
+fn update_text_decorations(
+    mut commands: Commands,
+    rich_texts: Query<
+        (
+            Entity,
+            &UiRichText,
+            &Position,
+            &ColoredElement,
+            &TextLayoutInfo,
+            Option<&Children>,
+        ),
+        Or<(
+            Changed<UiRichText>,
+            Changed<Position>,
+            Changed<ColoredElement>,
+            Changed<TextLayoutInfo>,
+        )>,
+    >,
+    decorations: Query<(), With<TextDecoration>>,
+) {
+    for (entity, rich_text, position, colored_element, layout_info, children) in rich_texts.iter() {
+        for child in children.into_iter().flatten() {
+            if decorations.contains(*child) {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+
+        let origin = Vec2::from(position.clone());
+
+        for line in layout_info.lines.iter() {
+            let thickness = (line.font_size / 14.0).max(1.0);
+
+            for (span_index, span) in rich_text.0.iter().enumerate() {
+                if !span.underline && !span.strikethrough {
+                    continue;
+                }
+
+                let mut span_glyphs = layout_info
+                    .glyphs
+                    .iter()
+                    .filter(|glyph| glyph.line == line.index && glyph.span == span_index)
+                    .peekable();
+
+                if span_glyphs.peek().is_none() {
+                    continue;
+                }
+
+                let (left, right) =
+                    span_glyphs.fold((f32::MAX, f32::MIN), |(left, right), glyph| {
+                        (left.min(glyph.rect.min.x), right.max(glyph.rect.max.x))
+                    });
+
+                let color = span.color.unwrap_or(colored_element.color);
+
+                let offsets = [
+                    (span.underline, line.baseline + thickness),
+                    (span.strikethrough, line.baseline - line.font_size * 0.3),
+                ];
+
+                for (_, y) in offsets.into_iter().filter(|(enabled, _)| *enabled) {
+                    let decoration_position = origin + Vec2::new(left, y);
+
+                    let decoration = commands
+                        .spawn((
+                            UiBoxBundle {
+                                position: Position::from(decoration_position.max(Vec2::ZERO)),
+                                size: Size::new(
+                                    (right - left).max(1.0) as u32,
+                                    thickness.round() as u32,
+                                ),
+                                color: ColoredElement::new(color),
+                                ..Default::default()
+                            },
+                            TextDecoration,
+                        ))
+                        .id();
+
+                    commands.entity(entity).add_child(decoration);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+#define_import_path epui::rounded_box
+
+// Picks the radii of the corner whose quadrant contains the point (radii = { Left-Top, Right-Top, Left-Bottom, Right-Bottom })
+fn corner_radii(point: vec2<f32>, radii_x: vec4<f32>, radii_y: vec4<f32>) -> vec2<f32> {
+    if point.x < 0.0 {
+        if point.y < 0.0 {
+            return vec2<f32>(radii_x.x, radii_y.x);
+        }
+
+        return vec2<f32>(radii_x.z, radii_y.z);
+    }
+
+    if point.y < 0.0 {
+        return vec2<f32>(radii_x.y, radii_y.y);
+    }
+
+    return vec2<f32>(radii_x.w, radii_y.w);
+}
+
+// Signed distance (in pixels) to the edge of a box with elliptical corners, negative inside
+fn rounded_box_distance(point: vec2<f32>, rect: vec4<f32>, radii_x: vec4<f32>, radii_y: vec4<f32>) -> f32 {
+    let center = (rect.xy + rect.zw) * 0.5;
+    let half_size = (rect.zw - rect.xy) * 0.5;
+    let relative_point = point - center;
+
+    let radii = min(corner_radii(relative_point, radii_x, radii_y), half_size);
+    let edge_distance = abs(relative_point) - half_size;
+
+    // Position relative to the center of the corner ellipse
+    let corner_point = abs(relative_point) - half_size + radii;
+
+    if corner_point.x <= 0.0 || corner_point.y <= 0.0 || min(radii.x, radii.y) <= 0.0 {
+        return max(edge_distance.x, edge_distance.y);
+    }
+
+    // First order approximation of the distance to an ellipse
+    let k0 = length(corner_point / radii);
+    let k1 = length(corner_point / (radii * radii));
+
+    return k0 * (k0 - 1.0) / k1;
+}
@@ -0,0 +1,530 @@
+use std::ops::Deref;
+
+use bevy::{
+    asset::embedded_asset,
+    ecs::system::lifetimeless::SRes,
+    log::error,
+    prelude::{
+        AssetServer, Color, Component, Entity, Handle, IntoSystemConfigs, Msaa, Plugin, Query,
+        Rect, ReflectComponent, Res, ResMut, Resource, Shader, Vec2, Vec4, With,
+    },
+    reflect::Reflect,
+    render::{
+        render_phase::{
+            AddRenderCommand, DrawFunctions, PhaseItem, RenderCommand, RenderCommandResult,
+            RenderPhase, SetItemPipeline,
+        },
+        render_resource::{
+            BlendState, BufferUsages, BufferVec, CachedRenderPipelineId, ColorTargetState,
+            ColorWrites, FragmentState, FrontFace, MultisampleState, PipelineCache, PolygonMode,
+            PrimitiveState, PrimitiveTopology, RenderPipelineDescriptor, TextureFormat,
+            VertexAttribute, VertexBufferLayout, VertexFormat, VertexState, VertexStepMode,
+        },
+        renderer::{RenderDevice, RenderQueue},
+        texture::BevyDefault,
+        Extract, ExtractSchedule, Render, RenderApp, RenderSet,
+    },
+};
+use bytemuck_derive::{Pod, Zeroable};
+
+use crate::{
+    camera::{PhysicalViewportSize, UiMsaa, UiPhaseItem, UiPhaseLayer},
+    element::{r#box::corner_radii, UiBox},
+    property::{
+        state::CurrentlyActive, update::TreeOrder, CornersRoundness, CornersShape, Position, Size,
+        VisibleRegion, ZLevel,
+    },
+};
+
+pub(crate) struct UiBoxShadowPlugin;
+
+impl Plugin for UiBoxShadowPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        embedded_asset!(app, "src/", "shadow.wgsl");
+    }
+
+    fn finish(&self, app: &mut bevy::prelude::App) {
+        let shadow_shader = app
+            .world
+            .resource::<AssetServer>()
+            .load("embedded://epui/element/shadow.wgsl");
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .insert_resource(ShadowShader(shadow_shader))
+            .init_resource::<ShadowPipeline>()
+            .init_resource::<ShadowBuffers>()
+            .init_resource::<ExtractedShadows>()
+            .add_render_command::<UiPhaseItem, RenderShadowCommand>()
+            .add_systems(ExtractSchedule, extract_shadows)
+            .add_systems(Render, queue_shadows.in_set(RenderSet::Queue));
+    }
+}
+
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct BoxShadow {
+    pub offset: Vec2,
+    pub blur_radius: f32,
+    pub spread: f32,
+    pub color: Color,
+    pub inset: bool,
+}
+
+impl BoxShadow {
+    #[inline]
+    pub fn new(offset: Vec2, blur_radius: f32, spread: f32, color: Color) -> BoxShadow {
+        BoxShadow {
+            offset,
+            blur_radius,
+            spread,
+            color,
+            inset: false,
+        }
+    }
+
+    #[inline]
+    pub fn new_inset(offset: Vec2, blur_radius: f32, spread: f32, color: Color) -> BoxShadow {
+        BoxShadow {
+            inset: true,
+            ..BoxShadow::new(offset, blur_radius, spread, color)
+        }
+    }
+}
+
+impl Default for BoxShadow {
+    fn default() -> Self {
+        BoxShadow::new(
+            Vec2::new(0.0, 4.0),
+            8.0,
+            0.0,
+            Color::rgba(0.0, 0.0, 0.0, 0.25),
+        )
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct BoxShadows(pub Vec<BoxShadow>);
+
+#[derive(Resource, Default)]
+struct ShadowPipeline(Option<(CachedRenderPipelineId, u32)>);
+
+#[derive(Clone, Copy, Zeroable, Pod)]
+#[repr(C)]
+struct ShadowInstanceData {
+    vertices: [[f32; 2]; 4], // Vertices (Vec2[4])
+    color: [f32; 4],         // Color (RGBA)
+
+    shadow_rect: [f32; 4], // Shadow Rect { Min X, Min Y, Max X, Max Y } (Vec4)
+    shadow_radii_x: [f32; 4], // Shadow Corners Horizontal Radii { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
+
+    box_rect: [f32; 4],    // Box Rect { Min X, Min Y, Max X, Max Y } (Vec4)
+    box_radii_x: [f32; 4], // Box Corners Horizontal Radii { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
+
+    sigma_and_inset: [f32; 2], // Vec2: Blur Sigma (f32) + Inset (f32, 0 or 1)
+
+    shadow_radii_y: [f32; 4], // Shadow Corners Vertical Radii { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
+    box_radii_y: [f32; 4], // Box Corners Vertical Radii { Left-Top, Right-Top, Left-Bottom, Right-Bottom } (Vec4)
+}
+
+#[derive(Resource)]
+struct ShadowBuffers {
+    instances: BufferVec<ShadowInstanceData>,
+}
+
+impl Default for ShadowBuffers {
+    fn default() -> Self {
+        ShadowBuffers {
+            instances: BufferVec::new(BufferUsages::VERTEX),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct ShadowShader(Handle<Shader>);
+
+impl Deref for ShadowShader {
+    type Target = Handle<Shader>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug)]
+struct ShadowInstance {
+    box_rect: Rect,
+    box_radii_x: Vec4,
+    box_radii_y: Vec4,
+
+    visible_region: Rect,
+    shadow: BoxShadow,
+
+    z_level: ZLevel,
+    tree_order: TreeOrder,
+}
+
+#[derive(Debug, Default, Resource)]
+struct ExtractedShadows(Vec<ShadowInstance>);
+
+fn extract_shadows(
+    boxes: Extract<
+        Query<
+            (
+                &CurrentlyActive<Position>,
+                &CurrentlyActive<Size>,
+                &CurrentlyActive<VisibleRegion>,
+                Option<&CurrentlyActive<BoxShadow>>,
+                Option<&CurrentlyActive<BoxShadows>>,
+                Option<&CurrentlyActive<CornersRoundness>>,
+                Option<&CornersShape>,
+                Option<&CurrentlyActive<ZLevel>>,
+                Option<&TreeOrder>,
+            ),
+            With<UiBox>,
+        >,
+    >,
+    mut extracted_shadows: ResMut<ExtractedShadows>,
+) {
+    extracted_shadows.0.clear();
+
+    for (
+        position,
+        size,
+        visible_region,
+        box_shadow,
+        box_shadows,
+        corners_roundness,
+        corners_shape,
+        z_level,
+        tree_order,
+    ) in boxes.iter()
+    {
+        if box_shadow.is_none() && box_shadows.is_none() {
+            continue;
+        }
+
+        let box_rect = Rect::from_corners(
+            Vec2::from(position.clone()),
+            Vec2::from(position.clone()) + Vec2::from(size.clone()),
+        );
+
+        let (box_radii_x, box_radii_y) = corner_radii(
+            &corners_roundness.cloned().unwrap_or_default(),
+            corners_shape.copied().unwrap_or_default(),
+            box_rect.half_size(),
+        );
+
+        let shadows = box_shadow.into_iter().chain(
+            box_shadows
+                .into_iter()
+                .flat_map(|box_shadows| box_shadows.0.iter()),
+        );
+
+        for shadow in shadows {
+            extracted_shadows.0.push(ShadowInstance {
+                box_rect,
+                box_radii_x,
+                box_radii_y,
+
+                visible_region: Rect::from(visible_region.clone()),
+                shadow: shadow.clone(),
+
+                z_level: z_level.cloned().unwrap_or_default(),
+                tree_order: tree_order.copied().unwrap_or_default(),
+            });
+        }
+    }
+}
+
+pub(crate) fn queue_shadows(
+    mut shadow_pipeline: ResMut<ShadowPipeline>,
+    pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    ui_msaa: Res<UiMsaa>,
+
+    mut view_query: Query<(Entity, &PhysicalViewportSize, &mut RenderPhase<UiPhaseItem>)>,
+    mut shadow_buffers: ResMut<ShadowBuffers>,
+    extracted_shadows: Res<ExtractedShadows>,
+    draw_functions: Res<DrawFunctions<UiPhaseItem>>,
+
+    shadow_shader: Res<ShadowShader>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+) {
+    let samples = ui_msaa.samples(&msaa);
+
+    if shadow_pipeline
+        .0
+        .is_some_and(|(_, pipeline_samples)| pipeline_samples != samples)
+    {
+        shadow_pipeline.0 = None;
+    }
+
+    let (pipeline, _) = shadow_pipeline.0.get_or_insert_with(|| {
+        let vec4_attribute = |index: u64, shader_location: u32| VertexAttribute {
+            format: VertexFormat::Float32x4,
+            offset: std::mem::size_of::<[[f32; 2]; 4]>() as u64
+                + std::mem::size_of::<[f32; 4]>() as u64 * index,
+            shader_location,
+        };
+
+        (
+            pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+                label: Some("shadow_pipeline_desc".into()),
+                layout: vec![],
+                vertex: VertexState {
+                    entry_point: "vs_main".into(),
+                    shader: shadow_shader.0.clone(),
+                    shader_defs: vec![],
+                    buffers: vec![VertexBufferLayout {
+                        step_mode: VertexStepMode::Instance,
+                        attributes: vec![
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: 0,
+                                shader_location: 0,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[f32; 2]>() as u64,
+                                shader_location: 1,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[[f32; 2]; 2]>() as u64,
+                                shader_location: 2,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[[f32; 2]; 3]>() as u64,
+                                shader_location: 3,
+                            },
+                            vec4_attribute(0, 4), // Color
+                            vec4_attribute(1, 5), // Shadow Rect
+                            vec4_attribute(2, 6), // Shadow Horizontal Radii
+                            vec4_attribute(3, 7), // Box Rect
+                            vec4_attribute(4, 8), // Box Horizontal Radii
+                            VertexAttribute {
+                                format: VertexFormat::Float32x2,
+                                offset: std::mem::size_of::<[[f32; 2]; 4]>() as u64
+                                    + std::mem::size_of::<[[f32; 4]; 5]>() as u64,
+                                shader_location: 9,
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 5]>() as u64
+                                    + std::mem::size_of::<[[f32; 4]; 5]>() as u64,
+                                shader_location: 10, // Shadow Vertical Radii
+                            },
+                            VertexAttribute {
+                                format: VertexFormat::Float32x4,
+                                offset: std::mem::size_of::<[[f32; 2]; 5]>() as u64
+                                    + std::mem::size_of::<[[f32; 4]; 6]>() as u64,
+                                shader_location: 11, // Box Vertical Radii
+                            },
+                        ],
+                        array_stride: std::mem::size_of::<ShadowInstanceData>() as u64,
+                    }],
+                },
+                fragment: Some(FragmentState {
+                    entry_point: "fs_main".into(),
+                    shader: shadow_shader.0.clone(),
+                    shader_defs: vec![],
+                    targets: vec![Some(ColorTargetState {
+                        format: TextureFormat::bevy_default(),
+                        blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                        write_mask: ColorWrites::ALL,
+                    })],
+                }),
+                push_constant_ranges: vec![],
+                primitive: PrimitiveState {
+                    topology: PrimitiveTopology::TriangleList,
+                    polygon_mode: PolygonMode::Fill,
+                    conservative: false,
+                    cull_mode: None,
+                    front_face: FrontFace::Ccw,
+                    strip_index_format: None,
+                    unclipped_depth: false,
+                },
+                multisample: MultisampleState {
+                    count: samples,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+            }),
+            samples,
+        )
+    });
+
+    let draw_function_id = draw_functions.read().id::<RenderShadowCommand>();
+    let mut instances = Vec::new();
+
+    // Every view gets its own instances in the same buffer, the index keeps running across views
+    let mut instance = 0;
+    shadow_buffers.instances.clear();
+
+    let shadows_count = extracted_shadows.0.len();
+    instances.reserve(shadows_count * view_query.iter().len());
+
+    for (camera_entity, viewport_size, mut ui_phase) in view_query.iter_mut() {
+        let Some(viewport_size) = viewport_size.0 else {
+            continue;
+        };
+
+        let x_pixel_unit = 2.0 / viewport_size.x as f32;
+        let y_pixel_unit = 2.0 / viewport_size.y as f32;
+
+        ui_phase.items.reserve(shadows_count);
+
+        for ShadowInstance {
+            box_rect,
+            box_radii_x,
+            box_radii_y,
+            visible_region,
+            shadow,
+            z_level,
+            tree_order,
+        } in extracted_shadows.0.iter()
+        {
+            let sigma = shadow.blur_radius.max(0.0) / 2.0;
+            let spread = if shadow.inset {
+                -shadow.spread
+            } else {
+                shadow.spread
+            };
+
+            let shadow_rect = Rect::from_center_half_size(
+                box_rect.center() + shadow.offset,
+                (box_rect.half_size() + spread).max(Vec2::ZERO),
+            );
+
+            let shadow_radii_x = (*box_radii_x + spread).max(Vec4::ZERO);
+            let shadow_radii_y = (*box_radii_y + spread).max(Vec4::ZERO);
+
+            let covered_region = if shadow.inset {
+                *box_rect
+            } else {
+                shadow_rect.inflate(sigma * 3.0)
+            };
+
+            let actual_visible_region = visible_region.intersect(covered_region);
+
+            if actual_visible_region.is_empty() {
+                continue;
+            }
+
+            let left_top_corner = Vec2::new(
+                (x_pixel_unit * actual_visible_region.min.x) - 1.0,
+                1.0 - (y_pixel_unit * actual_visible_region.min.y),
+            );
+
+            let right_top_corner = Vec2::new(
+                left_top_corner.x + (x_pixel_unit * actual_visible_region.width()),
+                left_top_corner.y,
+            );
+
+            let left_bottom_corner = Vec2::new(
+                left_top_corner.x,
+                left_top_corner.y - (y_pixel_unit * actual_visible_region.height()),
+            );
+
+            let right_bottom_corner = Vec2::new(right_top_corner.x, left_bottom_corner.y);
+
+            instances.push(ShadowInstanceData {
+                vertices: [
+                    left_top_corner.into(),
+                    right_top_corner.into(),
+                    left_bottom_corner.into(),
+                    right_bottom_corner.into(),
+                ],
+                color: shadow.color.rgba_to_vec4().into(),
+
+                shadow_rect: [
+                    shadow_rect.min.x,
+                    shadow_rect.min.y,
+                    shadow_rect.max.x,
+                    shadow_rect.max.y,
+                ],
+                shadow_radii_x: shadow_radii_x.into(),
+
+                box_rect: [
+                    box_rect.min.x,
+                    box_rect.min.y,
+                    box_rect.max.x,
+                    box_rect.max.y,
+                ],
+                box_radii_x: (*box_radii_x).into(),
+
+                sigma_and_inset: [sigma, if shadow.inset { 1.0 } else { 0.0 }],
+
+                shadow_radii_y: shadow_radii_y.into(),
+                box_radii_y: (*box_radii_y).into(),
+            });
+
+            let ui_phase_item = UiPhaseItem {
+                entity: camera_entity,
+                z_index: z_level.0,
+                tree_order: tree_order.0,
+                layer: if shadow.inset {
+                    UiPhaseLayer::InnerShadow
+                } else {
+                    UiPhaseLayer::OuterShadow
+                },
+
+                draw_function: draw_function_id,
+                cached_render_pipeline_id: *pipeline,
+
+                batch_range: instance..instance + 1,
+                dynamic_offset: None,
+                batchable: true,
+            };
+
+            ui_phase.add(ui_phase_item);
+            instance += 1;
+        }
+    }
+
+    shadow_buffers.instances.extend(instances);
+
+    shadow_buffers
+        .instances
+        .write_buffer(&render_device, &render_queue);
+}
+
+type RenderShadowCommand = (SetItemPipeline, DrawShadow);
+
+struct DrawShadow;
+impl RenderCommand<UiPhaseItem> for DrawShadow {
+    type Param = SRes<ShadowBuffers>;
+    type ViewWorldQuery = ();
+    type ItemWorldQuery = ();
+
+    fn render<'w>(
+        item: &UiPhaseItem,
+        _view: bevy::ecs::query::ROQueryItem<'w, Self::ViewWorldQuery>,
+        _entity: bevy::ecs::query::ROQueryItem<'w, Self::ItemWorldQuery>,
+        shadow_buffers: bevy::ecs::system::SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut bevy::render::render_phase::TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        {
+            let vertex_buffer = match shadow_buffers.into_inner().instances.buffer() {
+                Some(buffer) => buffer,
+                None => {
+                    error!("Couldn't set vertex buffer because it's not present");
+                    return RenderCommandResult::Failure;
+                }
+            };
+
+            pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+        }
+
+        pass.draw(0..6, item.batch_range().clone());
+
+        RenderCommandResult::Success
+    }
+}
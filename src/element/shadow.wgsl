@@ -0,0 +1,136 @@
+#import epui::rounded_box::{corner_radii, rounded_box_distance}
+
+struct InstanceInput {
+    @location(0) top_left_position: vec2<f32>,
+    @location(1) top_right_position: vec2<f32>,
+    @location(2) bottom_left_position: vec2<f32>,
+    @location(3) bottom_right_position: vec2<f32>,
+
+    @location(4) color: vec4<f32>,
+
+    @location(5) shadow_rect: vec4<f32>,
+    @location(6) shadow_radii_x: vec4<f32>,
+    @location(7) box_rect: vec4<f32>,
+    @location(8) box_radii_x: vec4<f32>,
+    @location(9) sigma_and_inset: vec2<f32>,
+
+    @location(10) shadow_radii_y: vec4<f32>,
+    @location(11) box_radii_y: vec4<f32>
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+
+    @location(0) color: vec4<f32>,
+    @location(1) shadow_rect: vec4<f32>,
+    @location(2) shadow_radii_x: vec4<f32>,
+    @location(3) shadow_radii_y: vec4<f32>,
+    @location(4) box_rect: vec4<f32>,
+    @location(5) box_radii_x: vec4<f32>,
+    @location(6) box_radii_y: vec4<f32>,
+    @location(7) sigma_and_inset: vec2<f32>
+}
+
+const PI: f32 = 3.141592653589793;
+
+@vertex
+fn vs_main(@builtin(vertex_index) in_vertex_index: u32, in_instance: InstanceInput) -> VertexOutput {
+    var vertex_output: VertexOutput;
+
+    var position = in_instance.top_left_position;
+
+    if in_vertex_index == 1u || in_vertex_index == 3u {
+        position = in_instance.top_right_position;
+    }
+
+    if in_vertex_index == 2u || in_vertex_index == 4u {
+        position = in_instance.bottom_left_position;
+    }
+
+    if in_vertex_index == 5u {
+        position = in_instance.bottom_right_position;
+    }
+
+    vertex_output.position = vec4<f32>(position, 0.0, 1.0);
+    vertex_output.color = in_instance.color;
+
+    vertex_output.shadow_rect = in_instance.shadow_rect;
+    vertex_output.shadow_radii_x = in_instance.shadow_radii_x;
+    vertex_output.shadow_radii_y = in_instance.shadow_radii_y;
+    vertex_output.box_rect = in_instance.box_rect;
+    vertex_output.box_radii_x = in_instance.box_radii_x;
+    vertex_output.box_radii_y = in_instance.box_radii_y;
+    vertex_output.sigma_and_inset = in_instance.sigma_and_inset;
+
+    return vertex_output;
+}
+
+fn gaussian(x: f32, sigma: f32) -> f32 {
+    return exp(-(x * x) / (2.0 * sigma * sigma)) / (sqrt(2.0 * PI) * sigma);
+}
+
+// Approximation of the error function (Abramowitz & Stegun 7.1.27)
+fn erf(x: vec2<f32>) -> vec2<f32> {
+    let s = sign(x);
+    let a = abs(x);
+
+    var result = 1.0 + (0.278393 + (0.230389 + 0.078108 * (a * a)) * a) * a;
+    result *= result;
+
+    return s - s / (result * result);
+}
+
+// Integral of the blurred rounded box along the x axis for a single row
+fn rounded_box_shadow_x(x: f32, y: f32, sigma: f32, corner: vec2<f32>, half_size: vec2<f32>) -> f32 {
+    let delta = min(half_size.y - corner.y - abs(y), 0.0);
+    let curved = half_size.x - corner.x + corner.x * sqrt(max(0.0, 1.0 - (delta * delta) / max(corner.y * corner.y, 0.0001)));
+    let integral = 0.5 + 0.5 * erf((x + vec2<f32>(-curved, curved)) * (sqrt(0.5) / sigma));
+
+    return integral.y - integral.x;
+}
+
+// Analytic blurred rounded box, the y axis is sampled since it has no closed form
+fn rounded_box_shadow(point: vec2<f32>, rect: vec4<f32>, radii_x: vec4<f32>, radii_y: vec4<f32>, sigma: f32) -> f32 {
+    let center = (rect.xy + rect.zw) * 0.5;
+    let half_size = (rect.zw - rect.xy) * 0.5;
+    let relative_point = point - center;
+
+    let corner = min(corner_radii(relative_point, radii_x, radii_y), half_size);
+
+    let low = relative_point.y - half_size.y;
+    let high = relative_point.y + half_size.y;
+    let start = clamp(-3.0 * sigma, low, high);
+    let end = clamp(3.0 * sigma, low, high);
+
+    let step = (end - start) / 4.0;
+    var y = start + step * 0.5;
+    var value = 0.0;
+
+    for (var i = 0; i < 4; i++) {
+        value += rounded_box_shadow_x(relative_point.x, relative_point.y - y, sigma, corner, half_size) * gaussian(y, sigma) * step;
+        y += step;
+    }
+
+    return value;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let point = input.position.xy;
+    let sigma = max(input.sigma_and_inset.x, 0.01);
+
+    let box_coverage = clamp(0.5 - rounded_box_distance(point, input.box_rect, input.box_radii_x, input.box_radii_y), 0.0, 1.0);
+    let shadow = rounded_box_shadow(point, input.shadow_rect, input.shadow_radii_x, input.shadow_radii_y, sigma);
+
+    var alpha: f32;
+
+    if input.sigma_and_inset.y > 0.5 {
+        alpha = (1.0 - shadow) * box_coverage;
+    } else {
+        alpha = shadow * (1.0 - box_coverage);
+    }
+
+    alpha *= input.color.a;
+
+    return vec4<f32>(input.color.rgb * alpha, alpha);
+}
@@ -1,14 +1,20 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    borrow::Cow,
+    ops::{Deref, DerefMut, Range},
+    sync::{Arc, Mutex},
+};
 
 use bevy::{
     ecs::system::lifetimeless::SRes,
     log::error,
     prelude::{
-        Bundle, Color, Component, Entity, IntoSystemConfigs, Plugin, Query, ReflectComponent, Res,
-        ResMut, Resource,
+        AssetApp, AssetEvent, Bundle, Color, Commands, Component, Entity, EventReader,
+        IntoSystemConfigs, Msaa, Or, Plugin, PreUpdate, Query, Rect, ReflectComponent, Res, ResMut,
+        Resource, Vec2, With,
     },
     reflect::Reflect,
     render::{
+        render_asset::{prepare_assets, RenderAssetPlugin, RenderAssets},
         render_phase::{
             AddRenderCommand, DrawFunctions, RenderCommand, RenderCommandResult, RenderPhase,
         },
@@ -17,14 +23,27 @@ use bevy::{
         texture::BevyDefault,
         Extract, ExtractSchedule, Render, RenderApp, RenderSet,
     },
-    utils::HashMap,
+    utils::{EntityHashMap, HashMap, HashSet},
+};
+use glyphon::{
+    cosmic_text::{Align, Wrap},
+    Attrs, Buffer, FontSystem, Metrics, SwashCache, TextArea, TextAtlas, TextBounds, TextRenderer,
 };
-use glyphon::{FontSystem, Metrics, SwashCache, TextArea, TextAtlas, TextBounds, TextRenderer};
 
 use crate::{
-    camera::{PhysicalViewportSize, UiPhaseItem},
+    camera::{PhysicalViewportSize, UiMsaa, UiPhaseItem, UiPhaseLayer},
+    element::font::{
+        resolve_family, FontFamily, FontStyle, FontWeight, UiFont, UiFontLoader, UiFontSettings,
+        UiFontSystem,
+    },
+    element::rich_text::{TextSpan, UiRichText},
+    element::{image::queue_images, r#box::queue_boxes, shadow::queue_shadows},
     prelude::{AutoZUpdate, ColoredElement, Position, Size},
-    property::{state::CurrentlyActive, update::AutoVisibleRegionUpdate, VisibleRegion, ZLevel},
+    property::{
+        state::CurrentlyActive,
+        update::{AutoVisibleRegionUpdate, TreeOrder},
+        VisibleRegion, ZLevel,
+    },
 };
 
 #[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
@@ -41,10 +60,132 @@ impl Default for FontSize {
     }
 }
 
+// Start & End follow the direction of each paragraph, so right-to-left paragraphs are right aligned by default
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum TextAlign {
+    #[default]
+    Start,
+    End,
+    Left,
+    Center,
+    Right,
+    Justify,
+}
+
+impl TextAlign {
+    fn resolve(&self, rtl: bool) -> Align {
+        match (self, rtl) {
+            (TextAlign::Start, false) | (TextAlign::End, true) | (TextAlign::Left, _) => {
+                Align::Left
+            }
+            (TextAlign::Start, true) | (TextAlign::End, false) | (TextAlign::Right, _) => {
+                Align::Right
+            }
+            (TextAlign::Center, _) => Align::Center,
+            (TextAlign::Justify, _) => Align::Justified,
+        }
+    }
+}
+
+// Auto takes the direction of each paragraph from its first strong character, as cosmic-text's bidi shaping does
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum TextDirection {
+    #[default]
+    Auto,
+    LeftToRight,
+    RightToLeft,
+}
+
+impl TextDirection {
+    fn is_rtl(&self, paragraph_rtl: bool) -> bool {
+        match self {
+            TextDirection::Auto => paragraph_rtl,
+            TextDirection::LeftToRight => false,
+            TextDirection::RightToLeft => true,
+        }
+    }
+}
+
+// Where the lines are placed vertically when they don't fill the element's height
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum VerticalAlign {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+}
+
+impl VerticalAlign {
+    fn offset(&self, free_space: f32) -> f32 {
+        match self {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Center => free_space.max(0.0) / 2.0,
+            VerticalAlign::Bottom => free_space.max(0.0),
+        }
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum TextWrap {
+    #[default]
+    Word,
+    Glyph,
+    None,
+}
+
+impl From<TextWrap> for Wrap {
+    fn from(text_wrap: TextWrap) -> Self {
+        match text_wrap {
+            TextWrap::Word => Wrap::Word,
+            TextWrap::Glyph => Wrap::Glyph,
+            TextWrap::None => Wrap::None,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum LineHeight {
+    // Multiple of the font size
+    Relative(f32),
+    Pixels(f32),
+}
+
+impl LineHeight {
+    fn pixels(&self, font_size: &FontSize) -> f32 {
+        match *self {
+            LineHeight::Relative(factor) => font_size.0 as f32 * factor,
+            LineHeight::Pixels(pixels) => pixels,
+        }
+    }
+}
+
+impl Default for LineHeight {
+    fn default() -> Self {
+        LineHeight::Relative(1.2)
+    }
+}
+
+// Both modes clip the text to the element, Ellipsis also ends the last visible line with "…" when some text is cut
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum TextOverflow {
+    #[default]
+    Clip,
+    Ellipsis,
+}
+
 #[derive(Bundle)]
 pub struct UiTextBundle {
     pub text: UiText,
     pub font_size: FontSize,
+    pub font_family: FontFamily,
+    pub font_weight: FontWeight,
+    pub font_style: FontStyle,
     pub color: ColoredElement,
 
     pub position: Position,
@@ -62,6 +203,9 @@ impl Default for UiTextBundle {
         Self {
             text: Default::default(),
             font_size: Default::default(),
+            font_family: Default::default(),
+            font_weight: Default::default(),
+            font_style: Default::default(),
             color: ColoredElement::new(Color::BLACK),
 
             position: Default::default(),
@@ -76,6 +220,84 @@ impl Default for UiTextBundle {
     }
 }
 
+// Where the glyphs of a text ended up once shaped, relative to the element's position
+// It's computed in the render world so it lags a frame behind the text's changes
+#[derive(Component, Debug, Default, Clone)]
+pub struct TextLayoutInfo {
+    // The displayed text, which ends with "…" when the text is ellipsized
+    pub text: String,
+    pub glyphs: Vec<TextGlyph>,
+    pub lines: Vec<TextLine>,
+    pub font_size: f32,
+}
+
+impl TextLayoutInfo {
+    pub fn glyph_at(&self, point: Vec2) -> Option<&TextGlyph> {
+        self.glyphs.iter().find(|glyph| glyph.rect.contains(point))
+    }
+
+    // Byte offset of the glyph boundary closest to the point, points above or below the text snap to the first or last line
+    pub fn offset_at(&self, point: Vec2) -> Option<usize> {
+        let line = self
+            .lines
+            .iter()
+            .find(|line| point.y < line.top + line.height)
+            .or(self.lines.last())?;
+
+        let mut line_glyphs = self.glyphs.iter().filter(|glyph| glyph.line == line.index);
+        let mut offset = None;
+
+        for glyph in line_glyphs.by_ref() {
+            if point.x < glyph.rect.center().x {
+                return Some(offset.unwrap_or(glyph.byte_range.start));
+            }
+
+            offset = Some(glyph.byte_range.end);
+        }
+
+        offset
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TextGlyph {
+    pub rect: Rect,
+    // Bytes of the displayed text making the glyph
+    pub byte_range: Range<usize>,
+    pub span: usize,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct TextLine {
+    pub index: usize,
+    pub top: f32,
+    pub baseline: f32,
+    pub height: f32,
+    pub font_size: f32,
+}
+
+// Shared between both worlds, the render world fills it when shaping & the main world drains it into TextLayoutInfo
+#[derive(Resource, Default, Clone)]
+struct SharedTextLayouts(Arc<Mutex<EntityHashMap<Entity, TextLayoutInfo>>>);
+
+fn sync_text_layouts(
+    mut commands: Commands,
+    shared_layouts: Res<SharedTextLayouts>,
+    texts: Query<(), Or<(With<UiText>, With<UiRichText>)>>,
+) {
+    let Ok(mut shared_layouts) = shared_layouts.0.lock() else {
+        error!("Couldn't lock the shared text layouts");
+        return;
+    };
+
+    for (entity, layout_info) in shared_layouts.drain() {
+        if texts.contains(entity) {
+            commands.entity(entity).insert(layout_info);
+        }
+    }
+}
+
 pub struct UiTextPlugin;
 
 #[derive(Component)]
@@ -83,23 +305,20 @@ struct UiTextBuffer(glyphon::Buffer);
 
 #[derive(Resource)]
 struct TextRenderData {
-    font_system: FontSystem,
     swash_cache: SwashCache,
     text_atlas: TextAtlas,
-    text_renderers: HashMap<u32, TextRenderer>,
+    // One per run of texts painted one after the other, keyed by the paint order (z level, tree order) of its first text
+    text_renderers: HashMap<(u32, u32), TextRenderer>,
+    text_renderers_samples: u32,
 }
 
 impl TextRenderData {
-    fn new(
-        font_system: FontSystem,
-        swash_cache: SwashCache,
-        text_atlas: TextAtlas,
-    ) -> TextRenderData {
+    fn new(swash_cache: SwashCache, text_atlas: TextAtlas) -> TextRenderData {
         TextRenderData {
-            font_system,
             swash_cache,
             text_atlas,
             text_renderers: HashMap::new(),
+            text_renderers_samples: 0,
         }
     }
 }
@@ -108,14 +327,23 @@ impl TextRenderData {
 struct TextPipeline(CachedRenderPipelineId);
 
 impl Plugin for UiTextPlugin {
-    fn build(&self, _app: &mut bevy::prelude::App) {}
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_asset::<UiFont>()
+            .init_asset_loader::<UiFontLoader>()
+            .init_resource::<UiFontSettings>()
+            .init_resource::<SharedTextLayouts>()
+            .add_plugins(RenderAssetPlugin::<UiFont>::default())
+            .add_systems(PreUpdate, sync_text_layouts);
+    }
 
     fn finish(&self, app: &mut bevy::prelude::App) {
+        let font_settings = app.world.resource::<UiFontSettings>().clone();
+        let shared_layouts = app.world.resource::<SharedTextLayouts>().clone();
+
         let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
         };
 
-        let font_system = FontSystem::new();
         let swash_cache = SwashCache::new();
 
         let text_atlas = TextAtlas::new(
@@ -125,243 +353,847 @@ impl Plugin for UiTextPlugin {
         );
 
         render_app
-            .insert_resource(TextRenderData::new(font_system, swash_cache, text_atlas))
+            .insert_resource(UiFontSystem::new(&font_settings))
+            .insert_resource(TextRenderData::new(swash_cache, text_atlas))
+            .insert_resource(shared_layouts)
             .init_resource::<ExtractedTexts>()
             .add_render_command::<UiPhaseItem, RenderTextCommand>()
             .add_systems(ExtractSchedule, extract_texts)
             .add_systems(
                 Render,
                 (
-                    prepare_texts.in_set(RenderSet::PrepareAssets),
-                    queue_texts.in_set(RenderSet::Queue),
+                    prepare_texts
+                        .in_set(RenderSet::PrepareAssets)
+                        .after(prepare_assets::<UiFont>),
+                    // Texts are grouped around the other elements' items, so those get queued first
+                    queue_texts
+                        .in_set(RenderSet::Queue)
+                        .after(queue_boxes)
+                        .after(queue_images)
+                        .after(queue_shadows),
                 ),
             );
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+struct TextFont {
+    family: FontFamily,
+    weight: FontWeight,
+    style: FontStyle,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TextLayout {
+    align: TextAlign,
+    direction: TextDirection,
+    vertical_align: VerticalAlign,
+    wrap: TextWrap,
+    line_height: LineHeight,
+    overflow: TextOverflow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TextContent {
+    Plain(UiText),
+    Rich(UiRichText),
+}
+
+impl TextContent {
+    fn spans(&self) -> Cow<[TextSpan]> {
+        match self {
+            TextContent::Plain(text) => Cow::Owned(vec![TextSpan::new(text.0.clone())]),
+            TextContent::Rich(rich_text) => Cow::Borrowed(&rich_text.0),
+        }
+    }
+}
+
+// Borrowed from the main world so unchanged texts aren't cloned every frame
+enum TextContentRef<'a> {
+    Plain(&'a UiText),
+    Rich(&'a UiRichText),
+}
+
+impl TextContentRef<'_> {
+    fn matches(&self, content: &TextContent) -> bool {
+        match (self, content) {
+            (TextContentRef::Plain(text), TextContent::Plain(current)) => *text == current,
+            (TextContentRef::Rich(rich_text), TextContent::Rich(current)) => *rich_text == current,
+            _ => false,
+        }
+    }
+
+    fn to_owned(&self) -> TextContent {
+        match self {
+            TextContentRef::Plain(text) => TextContent::Plain((*text).clone()),
+            TextContentRef::Rich(rich_text) => TextContent::Rich((*rich_text).clone()),
+        }
+    }
+}
+
 struct TextInstance {
-    text: UiText,
+    content: TextContent,
     font_size: FontSize,
+    font: TextFont,
+    layout: TextLayout,
 
     position: Position,
     size: Size,
 
     z_level: ZLevel,
+    tree_order: TreeOrder,
     visible_region: VisibleRegion,
 
     color: ColoredElement,
-    text_buffer: Option<UiTextBuffer>,
+    // One per run of lines sharing their font size, with the top of its lines below the previous buffers
+    text_buffers: Vec<(UiTextBuffer, f32)>,
+    // Offset of the shaped lines from the top of the element, from the vertical alignment
+    vertical_offset: f32,
+
+    // Set when the text, font or size changed since the buffer was last shaped
+    needs_reshape: bool,
+    // Set when the buffer was shaped with the fallback font, it's reshaped once a font asset gets loaded
+    waiting_for_fonts: bool,
+    last_seen: u32,
 }
 
+// Kept across frames so the glyphon buffers are only reshaped when their layout inputs change
 #[derive(Resource, Default)]
-struct ExtractedTexts(Vec<TextInstance>);
+struct ExtractedTexts {
+    texts: EntityHashMap<Entity, TextInstance>,
+    frame: u32,
+}
 
 impl Deref for ExtractedTexts {
-    type Target = Vec<TextInstance>;
+    type Target = EntityHashMap<Entity, TextInstance>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.texts
     }
 }
 
 impl DerefMut for ExtractedTexts {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.texts
     }
 }
 
 fn extract_texts(
     texts: Extract<
-        Query<(
-            &CurrentlyActive<UiText>,
-            &CurrentlyActive<FontSize>,
-            &CurrentlyActive<Position>,
-            &CurrentlyActive<Size>,
-            &CurrentlyActive<VisibleRegion>,
-            &CurrentlyActive<ColoredElement>,
-            Option<&CurrentlyActive<ZLevel>>,
-        )>,
+        Query<
+            (
+                Entity,
+                (
+                    Option<&CurrentlyActive<UiText>>,
+                    Option<&CurrentlyActive<UiRichText>>,
+                ),
+                &CurrentlyActive<FontSize>,
+                &CurrentlyActive<Position>,
+                &CurrentlyActive<Size>,
+                &CurrentlyActive<VisibleRegion>,
+                &CurrentlyActive<ColoredElement>,
+                Option<&CurrentlyActive<ZLevel>>,
+                Option<&TreeOrder>,
+                (Option<&FontFamily>, Option<&FontWeight>, Option<&FontStyle>),
+                (
+                    Option<&TextAlign>,
+                    Option<&TextDirection>,
+                    Option<&VerticalAlign>,
+                    Option<&TextWrap>,
+                    Option<&LineHeight>,
+                    Option<&TextOverflow>,
+                ),
+            ),
+            Or<(With<UiText>, With<UiRichText>)>,
+        >,
     >,
+    mut font_events: Extract<EventReader<AssetEvent<UiFont>>>,
     mut extracted_texts: ResMut<ExtractedTexts>,
 ) {
-    extracted_texts.clear();
+    extracted_texts.frame = extracted_texts.frame.wrapping_add(1);
+    let frame = extracted_texts.frame;
+
+    let fonts_loaded = font_events.read().any(|event| {
+        matches!(
+            event,
+            AssetEvent::Added { .. } | AssetEvent::Modified { .. }
+        )
+    });
+
+    for (
+        entity,
+        (text, rich_text),
+        font_size,
+        position,
+        size,
+        visible_region,
+        colored_element,
+        z_level,
+        tree_order,
+        (font_family, font_weight, font_style),
+        (text_align, text_direction, vertical_align, text_wrap, line_height, text_overflow),
+    ) in texts.iter()
+    {
+        let font = TextFont {
+            family: font_family.cloned().unwrap_or_default(),
+            weight: font_weight.copied().unwrap_or_default(),
+            style: font_style.copied().unwrap_or_default(),
+        };
 
-    let texts_count = texts.iter().len();
-    if texts_count > extracted_texts.capacity() {
-        let additional_slots = texts_count - extracted_texts.len() + 2;
+        let layout = TextLayout {
+            align: text_align.copied().unwrap_or_default(),
+            direction: text_direction.copied().unwrap_or_default(),
+            vertical_align: vertical_align.copied().unwrap_or_default(),
+            wrap: text_wrap.copied().unwrap_or_default(),
+            line_height: line_height.copied().unwrap_or_default(),
+            overflow: text_overflow.copied().unwrap_or_default(),
+        };
 
-        extracted_texts.reserve_exact(additional_slots);
-    }
+        // Rich text takes over the plain one when an element has both
+        let content = match (rich_text, text) {
+            (Some(rich_text), _) => TextContentRef::Rich(rich_text),
+            (None, Some(text)) => TextContentRef::Plain(text),
+            (None, None) => continue,
+        };
 
-    if extracted_texts.capacity().abs_diff(extracted_texts.len()) >= 5 {
-        extracted_texts.shrink_to_fit();
-    }
+        let Some(text_instance) = extracted_texts.get_mut(&entity) else {
+            extracted_texts.insert(
+                entity,
+                TextInstance {
+                    content: content.to_owned(),
+                    font_size: font_size.clone(),
+                    font,
+                    layout,
+
+                    position: position.clone(),
+                    size: size.clone(),
+
+                    z_level: z_level.cloned().unwrap_or_default(),
+                    tree_order: tree_order.copied().unwrap_or_default(),
+                    visible_region: visible_region.clone(),
+
+                    color: colored_element.clone(),
+                    text_buffers: Vec::new(),
+                    vertical_offset: 0.0,
+
+                    needs_reshape: true,
+                    waiting_for_fonts: false,
+                    last_seen: frame,
+                },
+            );
 
-    for (text, font_size, position, size, visible_region, colored_element, z_level) in texts.iter()
-    {
-        extracted_texts.push(TextInstance {
-            text: text.clone(),
-            font_size: font_size.clone(),
+            continue;
+        };
 
-            position: position.clone(),
-            size: size.clone(),
+        if !content.matches(&text_instance.content) {
+            text_instance.content = content.to_owned();
+            text_instance.needs_reshape = true;
+        }
 
-            z_level: z_level.cloned().unwrap_or_default(),
-            visible_region: visible_region.clone(),
+        if text_instance.font_size != *font_size {
+            text_instance.font_size = font_size.clone();
+            text_instance.needs_reshape = true;
+        }
 
-            color: colored_element.clone(),
-            text_buffer: None,
-        });
+        if text_instance.font != font {
+            text_instance.font = font;
+            text_instance.needs_reshape = true;
+        }
+
+        if text_instance.layout != layout {
+            text_instance.layout = layout;
+            text_instance.needs_reshape = true;
+        }
+
+        if text_instance.size != *size {
+            text_instance.size = size.clone();
+            text_instance.needs_reshape = true;
+        }
+
+        text_instance.position = position.clone();
+        text_instance.z_level = z_level.cloned().unwrap_or_default();
+        text_instance.tree_order = tree_order.copied().unwrap_or_default();
+        text_instance.visible_region = visible_region.clone();
+        text_instance.color = colored_element.clone();
+        text_instance.last_seen = frame;
+
+        if fonts_loaded && text_instance.waiting_for_fonts {
+            text_instance.needs_reshape = true;
+        }
     }
+
+    // Texts that weren't extracted this frame were despawned or lost their components
+    extracted_texts.retain(|_, text_instance| text_instance.last_seen == frame);
 }
 
 fn prepare_texts(
-    mut text_render_data: ResMut<TextRenderData>,
+    mut font_system: ResMut<UiFontSystem>,
     mut extracted_texts: ResMut<ExtractedTexts>,
+    loaded_fonts: Res<RenderAssets<UiFont>>,
+    shared_layouts: Res<SharedTextLayouts>,
 ) {
-    for TextInstance {
-        size,
-        text,
-        font_size,
-        text_buffer,
-        ..
-    } in extracted_texts.iter_mut()
+    let font_system = &mut font_system.0;
+
+    let Ok(mut shared_layouts) = shared_layouts.0.lock() else {
+        error!("Couldn't lock the shared text layouts");
+        return;
+    };
+
+    for (
+        entity,
+        TextInstance {
+            size,
+            content,
+            font_size,
+            font,
+            layout,
+            text_buffers,
+            vertical_offset,
+            needs_reshape,
+            waiting_for_fonts,
+            ..
+        },
+    ) in extracted_texts.iter_mut()
     {
-        if let Some(buffer) = text_buffer.as_mut() {
-            buffer.0.set_text(
-                &mut text_render_data.font_system,
-                &text.0,
-                glyphon::Attrs::new(),
-                glyphon::Shaping::Advanced,
+        if !*needs_reshape && !text_buffers.is_empty() {
+            continue;
+        }
+
+        // Until its font is loaded the text is shaped with the fallback font & gets reshaped when a font gets loaded
+        let family = resolve_family(&font.family, &loaded_fonts);
+        let attrs = Attrs::new()
+            .family(family.unwrap_or(glyphon::cosmic_text::Family::SansSerif))
+            .weight(font.weight.into())
+            .style(font.style.into());
+
+        let segments = text_segments(&content.spans(), font_size);
+        text_buffers.truncate(segments.len());
+
+        let mut layout_info = TextLayoutInfo {
+            font_size: font_size.0 as f32,
+            ..Default::default()
+        };
+        let mut fonts_loaded = true;
+        let mut segments_height = 0.0;
+
+        for (index, segment) in segments.iter().enumerate() {
+            let metrics = Metrics::new(
+                segment.font_size.0 as f32,
+                layout.line_height.pixels(&segment.font_size),
             );
 
-            for line in &mut buffer.0.lines {
-                line.set_align(Some(glyphon::cosmic_text::Align::Left));
+            if text_buffers.len() == index {
+                text_buffers.push((UiTextBuffer(Buffer::new(font_system, metrics)), 0.0));
             }
 
-            buffer.0.set_size(
-                &mut text_render_data.font_system,
+            let (UiTextBuffer(buffer), buffer_top) = &mut text_buffers[index];
+            *buffer_top = segments_height;
+
+            // A buffer only gets the height left below the previous ones
+            buffer.set_metrics(font_system, metrics);
+            buffer.set_wrap(font_system, layout.wrap.into());
+            buffer.set_size(
+                font_system,
                 size.width as f32,
-                size.height as f32,
+                (size.height as f32 - segments_height).max(0.0),
             );
 
-            buffer.0.set_metrics(
-                &mut text_render_data.font_system,
-                Metrics::new(font_size.0 as f32, font_size.0 as f32 + 4.0f32),
+            let mut shaped_spans = Cow::Borrowed(segment.spans.as_slice());
+            fonts_loaded &= shape_text(
+                buffer,
+                font_system,
+                &shaped_spans,
+                &segment.span_indices,
+                attrs,
+                &loaded_fonts,
+                layout,
             );
 
-            buffer
-                .0
-                .shape_until_scroll(&mut text_render_data.font_system);
-        } else {
-            let mut buffer = glyphon::Buffer::new(
-                &mut text_render_data.font_system,
-                Metrics::new(font_size.0 as f32, font_size.0 as f32 + 4.0f32),
-            );
+            let ellipsized =
+                layout.overflow == TextOverflow::Ellipsis && text_overflows(buffer, font_system);
+
+            if ellipsized {
+                let text = spans_text(&segment.spans);
+                let mut visible_end = visible_text_end(buffer, &line_offsets(&text, buffer));
+
+                // Drops characters until the text with the ellipsis fits (the ellipsis may not fit after the visible part)
+                loop {
+                    shaped_spans = Cow::Owned(ellipsize_spans(&segment.spans, visible_end));
+                    fonts_loaded &= shape_text(
+                        buffer,
+                        font_system,
+                        &shaped_spans,
+                        &segment.span_indices,
+                        attrs,
+                        &loaded_fonts,
+                        layout,
+                    );
+
+                    if visible_end == 0 || !text_overflows(buffer, font_system) {
+                        break;
+                    }
+
+                    visible_end = text[..visible_end]
+                        .char_indices()
+                        .next_back()
+                        .map_or(0, |(index, _)| index);
+                }
+            }
 
-            buffer.set_text(
-                &mut text_render_data.font_system,
-                &text.0,
-                glyphon::Attrs::new(),
-                glyphon::Shaping::Advanced,
+            add_layout_info(
+                &mut layout_info,
+                buffer,
+                spans_text(&shaped_spans),
+                segments_height,
             );
+            segments_height += shaped_lines_count(buffer, font_system) as f32 * metrics.line_height;
 
-            for line in &mut buffer.lines {
-                line.set_align(Some(glyphon::cosmic_text::Align::Left));
+            // The text past the ellipsis isn't displayed
+            if ellipsized {
+                text_buffers.truncate(index + 1);
+                break;
             }
 
-            buffer.set_size(
-                &mut text_render_data.font_system,
-                size.width as f32,
-                size.height as f32,
-            );
+            if let Some((_, line_break)) = segment.line_break {
+                layout_info.text.push_str(line_break);
+            }
+        }
+
+        *vertical_offset = layout
+            .vertical_align
+            .offset(size.height as f32 - segments_height);
+
+        for line in layout_info.lines.iter_mut() {
+            line.top += *vertical_offset;
+            line.baseline += *vertical_offset;
+        }
+
+        for glyph in layout_info.glyphs.iter_mut() {
+            glyph.rect.min.y += *vertical_offset;
+            glyph.rect.max.y += *vertical_offset;
+        }
+
+        shared_layouts.insert(*entity, layout_info);
+
+        *needs_reshape = false;
+        *waiting_for_fonts = family.is_none() || !fonts_loaded;
+    }
+}
+
+// Lines sharing their font size, shaped in a single buffer since glyphon's buffers use the same metrics for all their lines
+struct TextSegment {
+    spans: Vec<TextSpan>,
+    // Index of every span in the element's spans, pieces of a span split on line breaks share it
+    span_indices: Vec<usize>,
+    font_size: FontSize,
+    // The line break between the segment & the next one, with the span it's part of
+    line_break: Option<(usize, &'static str)>,
+}
+
+impl TextSegment {
+    fn push(&mut self, span_index: usize, span: &TextSpan, text: &str) {
+        match self.spans.last_mut() {
+            Some(last_span) if self.span_indices.last() == Some(&span_index) => {
+                last_span.text.push_str(text)
+            }
+            _ => {
+                self.spans.push(TextSpan {
+                    text: text.to_owned(),
+                    ..span.clone()
+                });
+                self.span_indices.push(span_index);
+            }
+        }
+    }
+}
+
+// Splits the spans on the line breaks where the font size changes, a line takes the largest size of its spans
+fn text_segments(spans: &[TextSpan], font_size: &FontSize) -> Vec<TextSegment> {
+    let mut segments: Vec<TextSegment> = Vec::new();
+
+    // Pieces of the current line with the span they're from, the line is added to the segments at its line break
+    let mut line: Vec<(usize, &str)> = Vec::new();
+    let mut line_font_size: Option<u32> = None;
+
+    let mut add_line = |line: &mut Vec<(usize, &str)>,
+                        line_font_size: u32,
+                        line_break: Option<(usize, &'static str)>| {
+        if !segments
+            .last()
+            .is_some_and(|segment| segment.font_size.0 == line_font_size)
+        {
+            segments.push(TextSegment {
+                spans: Vec::new(),
+                span_indices: Vec::new(),
+                font_size: FontSize(line_font_size),
+                line_break: None,
+            });
+        }
+
+        let segment = segments
+            .last_mut()
+            .expect("The line's segment was just found or added");
+
+        // The line break before the line stays in the text of the segment when both lines share it
+        if let Some((span_index, previous_line_break)) = segment.line_break.take() {
+            segment.push(span_index, &spans[span_index], previous_line_break);
+        }
+
+        for (span_index, text) in line.drain(..) {
+            segment.push(span_index, &spans[span_index], text);
+        }
+
+        // Empty lines still take their line height
+        if segment.spans.is_empty() {
+            segment.spans.push(TextSpan::default());
+            segment.span_indices.push(0);
+        }
+
+        segment.line_break = line_break;
+    };
+
+    for (span_index, span) in spans.iter().enumerate() {
+        let span_font_size = span.font_size.as_ref().unwrap_or(font_size).0;
+        let mut pieces = span.text.split('\n').peekable();
+
+        while let Some(piece) = pieces.next() {
+            let ends_line = pieces.peek().is_some();
+            let (piece, line_break) = match piece.strip_suffix('\r') {
+                Some(piece) if ends_line => (piece, "\r\n"),
+                _ => (piece, "\n"),
+            };
+
+            if !piece.is_empty() {
+                line_font_size = line_font_size.max(Some(span_font_size));
+                line.push((span_index, piece));
+            }
 
-            buffer.shape_until_scroll(&mut text_render_data.font_system);
-            *text_buffer = Some(UiTextBuffer(buffer));
+            // An empty line takes the size of the span breaking it
+            if ends_line {
+                add_line(
+                    &mut line,
+                    line_font_size.take().unwrap_or(span_font_size),
+                    Some((span_index, line_break)),
+                );
+            }
         }
     }
+
+    add_line(&mut line, line_font_size.unwrap_or(font_size.0), None);
+
+    segments
+}
+
+// Returns false when the font of a span isn't loaded yet
+fn shape_text(
+    buffer: &mut Buffer,
+    font_system: &mut FontSystem,
+    spans: &[TextSpan],
+    span_indices: &[usize],
+    attrs: Attrs,
+    loaded_fonts: &RenderAssets<UiFont>,
+    layout: &TextLayout,
+) -> bool {
+    let mut fonts_loaded = true;
+
+    // The span index is kept in the glyphs' metadata to find the span of a glyph back
+    let spans_attrs = spans
+        .iter()
+        .zip(span_indices)
+        .map(|(span, index)| {
+            let mut span_attrs = attrs.metadata(*index);
+
+            if let Some(font_family) = &span.font_family {
+                match resolve_family(font_family, loaded_fonts) {
+                    Some(family) => span_attrs = span_attrs.family(family),
+                    None => fonts_loaded = false,
+                }
+            }
+
+            if let Some(font_weight) = span.font_weight {
+                span_attrs = span_attrs.weight(font_weight.into());
+            }
+
+            if let Some(font_style) = span.font_style {
+                span_attrs = span_attrs.style(font_style.into());
+            }
+
+            if let Some(color) = span.color {
+                let [r, g, b, a] = color.as_rgba_f32().map(|x| (x * 255.0f32) as u8);
+                span_attrs = span_attrs.color(glyphon::Color::rgba(r, g, b, a));
+            }
+
+            (span.text.as_str(), span_attrs)
+        })
+        .collect::<Vec<_>>();
+
+    buffer.set_rich_text(font_system, spans_attrs, glyphon::Shaping::Advanced);
+
+    // A paragraph's direction is only known once shaped, aligning it afterwards only redoes its layout
+    for line in &mut buffer.lines {
+        let rtl = layout.direction.is_rtl(line.shape(font_system).rtl);
+        line.set_align(Some(layout.align.resolve(rtl)));
+    }
+
+    buffer.shape_until_scroll(font_system);
+    fonts_loaded
+}
+
+fn spans_text(spans: &[TextSpan]) -> String {
+    spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+// Keeps the text of the spans up to the end (in bytes of the whole text) & appends the ellipsis to the last one
+fn ellipsize_spans(spans: &[TextSpan], end: usize) -> Vec<TextSpan> {
+    let mut ellipsized_spans = Vec::new();
+    let mut span_start = 0;
+
+    for span in spans {
+        if span_start >= end && !ellipsized_spans.is_empty() {
+            break;
+        }
+
+        let mut ellipsized_span = span.clone();
+        ellipsized_span
+            .text
+            .truncate(end.saturating_sub(span_start).min(span.text.len()));
+
+        span_start += span.text.len();
+        ellipsized_spans.push(ellipsized_span);
+    }
+
+    if let Some(last_span) = ellipsized_spans.last_mut() {
+        last_span.text.truncate(last_span.text.trim_end().len());
+        last_span.text.push('…');
+    }
+
+    ellipsized_spans
+}
+
+// Lays out every line, including the ones past the buffer's height which aren't shaped until scrolled to
+fn shaped_lines_count(buffer: &mut Buffer, font_system: &mut FontSystem) -> usize {
+    (0..buffer.lines.len())
+        .filter_map(|line_index| buffer.line_layout(font_system, line_index))
+        .map(|layout_lines| layout_lines.len())
+        .sum()
+}
+
+fn text_overflows(buffer: &mut Buffer, font_system: &mut FontSystem) -> bool {
+    let (width, height) = buffer.size();
+    let lines_height =
+        shaped_lines_count(buffer, font_system) as f32 * buffer.metrics().line_height;
+
+    lines_height > height || buffer.layout_runs().any(|run| run.line_w > width)
+}
+
+// Byte offset of every buffer line in the whole text, the buffer splits the text on line breaks
+fn line_offsets(text: &str, buffer: &Buffer) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(buffer.lines.len());
+    let mut offset = 0;
+
+    for line in buffer.lines.iter() {
+        offsets.push(offset);
+        offset = (offset + line.text().len()).min(text.len());
+
+        if text[offset..].starts_with("\r\n") {
+            offset += 2;
+        } else if let Some(line_break) = text[offset..].chars().next() {
+            offset += line_break.len_utf8();
+        }
+    }
+
+    offsets
+}
+
+// End (in bytes of the whole text) of the part of the text that fits in the buffer's size
+fn visible_text_end(buffer: &Buffer, line_offsets: &[usize]) -> usize {
+    let (width, height) = buffer.size();
+    let line_height = buffer.metrics().line_height;
+
+    buffer
+        .layout_runs()
+        .take_while(|run| run.line_top + line_height <= height)
+        .flat_map(|run| {
+            let line_offset = line_offsets.get(run.line_i).copied().unwrap_or_default();
+
+            run.glyphs
+                .iter()
+                .filter(move |glyph| glyph.x + glyph.w <= width)
+                .map(move |glyph| line_offset + glyph.end)
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+// Appends the lines of a buffer placed at the top, with the displayed text it was shaped from
+fn add_layout_info(layout_info: &mut TextLayoutInfo, buffer: &Buffer, text: String, top: f32) {
+    let line_offsets = line_offsets(&text, buffer);
+    let metrics = buffer.metrics();
+    let text_offset = layout_info.text.len();
+
+    for run in buffer.layout_runs() {
+        let index = layout_info.lines.len();
+        let line_top = run.line_top + top;
+        let line_offset = text_offset + line_offsets.get(run.line_i).copied().unwrap_or_default();
+
+        layout_info.lines.push(TextLine {
+            index,
+            top: line_top,
+            baseline: run.line_y + top,
+            height: metrics.line_height,
+            font_size: metrics.font_size,
+        });
+
+        layout_info
+            .glyphs
+            .extend(run.glyphs.iter().map(|glyph| TextGlyph {
+                rect: Rect::new(
+                    glyph.x,
+                    line_top,
+                    glyph.x + glyph.w,
+                    line_top + metrics.line_height,
+                ),
+                byte_range: line_offset + glyph.start..line_offset + glyph.end,
+                span: glyph.metadata,
+                line: index,
+            }));
+    }
+
+    layout_info.text.push_str(&text);
 }
 
 fn queue_texts(
     mut view_query: Query<(Entity, &PhysicalViewportSize, &mut RenderPhase<UiPhaseItem>)>,
     mut text_render_data: ResMut<TextRenderData>,
+    mut font_system: ResMut<UiFontSystem>,
     extracted_texts: Res<ExtractedTexts>,
     device: Res<RenderDevice>,
     queue: Res<RenderQueue>,
     draw_functions: Res<DrawFunctions<UiPhaseItem>>,
+    msaa: Res<Msaa>,
+    ui_msaa: Res<UiMsaa>,
 ) {
+    let mut used_text_renderers = HashSet::new();
+
     for (view_entity, viewport_size, mut ui_phase) in view_query.iter_mut() {
         let Some(viewport_size) = viewport_size.0 else {
             continue;
         };
 
-        let mut text_areas_map = HashMap::new();
+        let mut texts = Vec::new();
 
         for TextInstance {
-            text_buffer,
+            text_buffers,
             position,
+            size,
+            vertical_offset,
             z_level,
+            tree_order,
             visible_region,
             color,
             ..
-        } in extracted_texts.iter()
+        } in extracted_texts.values()
         {
-            let Some(text_buffer) = text_buffer else {
-                continue;
-            };
-
-            let text_areas_vec = if let Some(text_areas_vec) = text_areas_map.get_mut(&z_level.0) {
-                text_areas_vec
-            } else {
-                text_areas_map.insert(&z_level.0, Vec::new());
+            let [r, g, b, a] = color.color.as_rgba_f32().map(|x| (x * 255.0f32) as u8);
 
-                let Some(text_areas_vec) = text_areas_map.get_mut(&z_level.0) else {
-                    error!("Getting the value of the inserted z {} failed", z_level.0);
-                    continue;
+            // Texts are clipped to their element, on top of the region their parents leave visible
+            let bounds = Rect::from(visible_region.clone()).intersect(Rect::from_corners(
+                Vec2::from(position.clone()),
+                Vec2::from(position.clone()) + Vec2::from(size.clone()),
+            ));
+
+            for (text_buffer, buffer_top) in text_buffers.iter() {
+                let text_area = TextArea {
+                    buffer: &text_buffer.0,
+                    left: position.x as f32,
+                    top: position.y as f32 + *vertical_offset + *buffer_top,
+                    scale: 1.0f32,
+                    bounds: TextBounds {
+                        left: bounds.min.x as i32,
+                        top: bounds.min.y as i32,
+
+                        right: bounds.max.x.min(i32::MAX as f32) as i32,
+                        bottom: bounds.max.y.min(i32::MAX as f32) as i32,
+                    },
+                    default_color: glyphon::Color::rgba(r, g, b, a),
                 };
 
-                text_areas_vec
+                texts.push(((z_level.0, tree_order.0), text_area));
+            }
+        }
+
+        texts.sort_by_key(|(paint_order, _)| *paint_order);
+
+        // Paint order of the items already queued by the other elements
+        let mut other_items = ui_phase
+            .items
+            .iter()
+            .map(|item| (item.z_index, item.tree_order, item.layer))
+            .collect::<Vec<_>>();
+        other_items.sort_unstable();
+
+        // Consecutive texts share a renderer, unless something else is painted between them
+        let mut text_runs: Vec<((u32, u32), Vec<TextArea>)> = Vec::new();
+        let mut other_index = 0;
+        let mut last_paint_order = None;
+
+        for (paint_order, text_area) in texts {
+            let next_key = (paint_order.0, paint_order.1, UiPhaseLayer::Text);
+
+            let painted_between = match last_paint_order {
+                Some((z_level, tree_order)) => {
+                    let last_key = (z_level, tree_order, UiPhaseLayer::Text);
+
+                    while other_items
+                        .get(other_index)
+                        .is_some_and(|other_key| *other_key <= last_key)
+                    {
+                        other_index += 1;
+                    }
+
+                    other_items
+                        .get(other_index)
+                        .is_some_and(|other_key| *other_key < next_key)
+                }
+                None => true,
             };
 
-            let [r, g, b, a] = color.color.as_rgba_f32().map(|x| (x * 255.0f32) as u8);
+            match text_runs.last_mut() {
+                Some((_, text_areas)) if !painted_between => text_areas.push(text_area),
+                _ => text_runs.push((paint_order, vec![text_area])),
+            }
 
-            text_areas_vec.push(TextArea {
-                buffer: &text_buffer.0,
-                left: position.x as f32,
-                top: position.y as f32,
-                scale: 1.0f32,
-                bounds: TextBounds {
-                    left: visible_region.x as i32,
-                    top: visible_region.y as i32,
-
-                    right: visible_region.x as i32
-                        + visible_region.width.min(i32::MAX as u32) as i32,
-                    bottom: visible_region.y as i32
-                        + visible_region.height.min(i32::MAX as u32) as i32,
-                },
-                default_color: glyphon::Color::rgba(r, g, b, a),
-            });
+            last_paint_order = Some(paint_order);
         }
 
         let TextRenderData {
-            font_system,
             swash_cache,
             text_atlas,
             text_renderers,
+            text_renderers_samples,
         } = text_render_data.as_mut();
 
-        for (z_index, text_areas_vec) in text_areas_map {
-            let text_renderer = if let Some(text_renderer) = text_renderers.get_mut(z_index) {
+        let samples = ui_msaa.samples(&msaa);
+
+        if *text_renderers_samples != samples {
+            text_renderers.clear();
+            *text_renderers_samples = samples;
+        }
+
+        for (paint_order, text_areas_vec) in text_runs {
+            used_text_renderers.insert(paint_order);
+
+            let text_renderer = if let Some(text_renderer) = text_renderers.get_mut(&paint_order) {
                 text_renderer
             } else {
                 text_renderers
                     .insert_unique_unchecked(
-                        *z_index,
+                        paint_order,
                         TextRenderer::new(
                             text_atlas,
                             device.wgpu_device(),
                             MultisampleState {
-                                count: 4,
+                                count: samples,
                                 ..Default::default()
                             },
                             None,
@@ -373,7 +1205,7 @@ fn queue_texts(
             if let Err(err) = text_renderer.prepare(
                 device.wgpu_device(),
                 &queue,
-                font_system,
+                &mut font_system.0,
                 text_atlas,
                 glyphon::Resolution {
                     width: viewport_size.x,
@@ -392,18 +1224,26 @@ fn queue_texts(
 
             let phase = UiPhaseItem {
                 entity: view_entity,
-                z_index: *z_index,
+                z_index: paint_order.0,
+                tree_order: paint_order.1,
+                layer: UiPhaseLayer::Text,
 
                 draw_function: draw_function_id,
                 cached_render_pipeline_id: CachedRenderPipelineId::INVALID,
 
                 batch_range: 0..1,
                 dynamic_offset: None,
+                batchable: false,
             };
 
             ui_phase.add(phase);
         }
     }
+
+    // Renderers of runs that are gone or now start at another paint order
+    text_render_data
+        .text_renderers
+        .retain(|paint_order, _| used_text_renderers.contains(paint_order));
 }
 
 struct RenderTextCommand;
@@ -425,10 +1265,11 @@ impl RenderCommand<UiPhaseItem> for RenderTextCommand {
         span.in_scope(|| {
             let param = param.into_inner();
 
-            let Some(text_renderer) = param.text_renderers.get(&item.z_index) else {
+            let Some(text_renderer) = param.text_renderers.get(&(item.z_index, item.tree_order))
+            else {
                 error!(
-                    "Couldn't find a text renderer for z level: {}",
-                    item.z_index
+                    "Couldn't find a text renderer for z level: {}, tree order: {}",
+                    item.z_index, item.tree_order
                 );
 
                 return RenderCommandResult::Failure;
@@ -0,0 +1,279 @@
+use bevy::{
+    log::warn,
+    prelude::{
+        BuildChildren, Changed, Children, Color, Commands, Component, DespawnRecursiveExt, Entity,
+        Event, EventWriter, Input, KeyCode, MouseButton, Or, Plugin, Query, Rect, ReflectComponent,
+        RemovedComponents, Res, Update, Vec2, With,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    element::{r#box::UiBoxBundle, text::TextLayoutInfo},
+    property::{ColoredElement, Position, Size, VisibleRegion, ZIndex, ZLevel},
+};
+
+pub(crate) struct UiTextSelectionPlugin;
+
+impl Plugin for UiTextSelectionPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<TextCopyEvent>().add_systems(
+            Update,
+            (
+                update_text_selections,
+                copy_text_selections,
+                update_selection_highlights,
+            ),
+        );
+    }
+}
+
+// Opts a UiText or UiRichText into being selected by dragging the cursor over it
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct SelectableText {
+    pub highlight_color: Color,
+}
+
+impl Default for SelectableText {
+    fn default() -> Self {
+        Self {
+            highlight_color: Color::rgba(0.2, 0.4, 1.0, 0.4),
+        }
+    }
+}
+
+// Byte offsets in the displayed text (see TextLayoutInfo), the focus follows the cursor while dragging
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct TextSelection {
+    pub anchor: usize,
+    pub focus: usize,
+    pub text: String,
+}
+
+impl TextSelection {
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.anchor.min(self.focus)..self.anchor.max(self.focus)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.focus
+    }
+}
+
+// Sent on Ctrl+C (Cmd+C on macOS) with the selected text, for the app to put in its clipboard
+#[derive(Event)]
+pub struct TextCopyEvent {
+    pub element: Entity,
+    pub text: String,
+}
+
+#[derive(Component)]
+struct SelectionDragged;
+
+fn update_text_selections(
+    mut commands: Commands,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut texts: Query<
+        (
+            Entity,
+            &Position,
+            &Size,
+            Option<&VisibleRegion>,
+            Option<&ZLevel>,
+            &TextLayoutInfo,
+            Option<&mut TextSelection>,
+            Option<&SelectionDragged>,
+        ),
+        With<SelectableText>,
+    >,
+) {
+    let Ok(primary_window) = primary_window.get_single() else {
+        warn!("Couldn't get primary window");
+
+        return;
+    };
+
+    let Some(cursor_position) = primary_window.cursor_position() else {
+        return;
+    };
+
+    let cursor_position = cursor_position.round();
+
+    if mouse_buttons.just_pressed(MouseButton::Left) {
+        // Only the topmost text under the cursor starts a selection, pressing anywhere clears the others
+        let pressed_text = texts
+            .iter()
+            .filter(|(_, position, size, visible_region, ..)| {
+                let visible_region = match visible_region {
+                    Some(visible_region) => (*visible_region).clone(),
+                    None => VisibleRegion::new(position.x, position.y, size.width, size.height),
+                };
+
+                Rect::from(visible_region).contains(cursor_position)
+            })
+            .max_by_key(|(_, _, _, _, z_level, ..)| z_level.map_or(0, |z_level| z_level.0))
+            .map(|(entity, ..)| entity);
+
+        for (entity, position, _, _, _, layout_info, selection, _) in texts.iter_mut() {
+            if Some(entity) != pressed_text {
+                if selection.is_some() {
+                    commands.entity(entity).remove::<TextSelection>();
+                }
+
+                continue;
+            }
+
+            let offset = layout_info
+                .offset_at(cursor_position - Vec2::from(position.clone()))
+                .unwrap_or_default();
+
+            commands.entity(entity).insert((
+                TextSelection {
+                    anchor: offset,
+                    focus: offset,
+                    text: String::new(),
+                },
+                SelectionDragged,
+            ));
+        }
+
+        return;
+    }
+
+    for (entity, position, _, _, _, layout_info, selection, dragged) in texts.iter_mut() {
+        let (Some(mut selection), Some(_)) = (selection, dragged) else {
+            continue;
+        };
+
+        if let Some(focus) = layout_info.offset_at(cursor_position - Vec2::from(position.clone())) {
+            if focus != selection.focus {
+                selection.focus = focus;
+                selection.text = layout_info
+                    .text
+                    .get(selection.range())
+                    .unwrap_or_default()
+                    .to_string();
+            }
+        }
+
+        if !mouse_buttons.pressed(MouseButton::Left) {
+            commands.entity(entity).remove::<SelectionDragged>();
+        }
+    }
+}
+
+fn copy_text_selections(
+    keys: Res<Input<KeyCode>>,
+    selections: Query<(Entity, &TextSelection)>,
+    mut copy_events: EventWriter<TextCopyEvent>,
+) {
+    let modifier_pressed = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    if !modifier_pressed || !keys.just_pressed(KeyCode::C) {
+        return;
+    }
+
+    for (entity, selection) in selections.iter() {
+        if !selection.is_empty() {
+            copy_events.send(TextCopyEvent {
+                element: entity,
+                text: selection.text.clone(),
+            });
+        }
+    }
+}
+
+// The highlight is made of a box per selected line, parented to the text one level below it
+#[derive(Component)]
+struct SelectionHighlight;
+
+fn update_selection_highlights(
+    mut commands: Commands,
+    texts: Query<
+        (
+            Entity,
+            &SelectableText,
+            &Position,
+            &TextLayoutInfo,
+            Option<&TextSelection>,
+            Option<&Children>,
+        ),
+        Or<(
+            Changed<TextSelection>,
+            Changed<Position>,
+            Changed<TextLayoutInfo>,
+            Changed<SelectableText>,
+        )>,
+    >,
+    highlights: Query<(), With<SelectionHighlight>>,
+    mut removed_selections: RemovedComponents<TextSelection>,
+    children: Query<&Children, With<SelectableText>>,
+) {
+    for entity in removed_selections.read() {
+        for child in children.get(entity).into_iter().flatten() {
+            if highlights.contains(*child) {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+    }
+
+    for (entity, selectable_text, position, layout_info, selection, children) in texts.iter() {
+        for child in children.into_iter().flatten() {
+            if highlights.contains(*child) {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+
+        let Some(selection) = selection.filter(|selection| !selection.is_empty()) else {
+            continue;
+        };
+
+        let range = selection.range();
+        let origin = Vec2::from(position.clone());
+
+        for line in layout_info.lines.iter() {
+            let line_rect = layout_info
+                .glyphs
+                .iter()
+                .filter(|glyph| {
+                    glyph.line == line.index
+                        && glyph.byte_range.start >= range.start
+                        && glyph.byte_range.end <= range.end
+                })
+                .fold(None, |line_rect: Option<Rect>, glyph| {
+                    Some(line_rect.map_or(glyph.rect, |line_rect| line_rect.union(glyph.rect)))
+                });
+
+            let Some(line_rect) = line_rect else {
+                continue;
+            };
+
+            let highlight = commands
+                .spawn((
+                    UiBoxBundle {
+                        position: Position::from((origin + line_rect.min).max(Vec2::ZERO)),
+                        size: Size::new(
+                            line_rect.width().round() as u32,
+                            line_rect.height().round() as u32,
+                        ),
+                        color: ColoredElement::new(selectable_text.highlight_color),
+                        ..Default::default()
+                    },
+                    ZIndex::Local(-2),
+                    SelectionHighlight,
+                ))
+                .id();
+
+            commands.entity(entity).add_child(highlight);
+        }
+    }
+}
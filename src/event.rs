@@ -13,7 +13,7 @@ use bevy::{
 
 use crate::{
     prelude::{Position, Size},
-    property::{collision::Collision, VisibleRegion},
+    property::{collision::Collision, modal::ModalScope, state::disabled::Disabled, VisibleRegion},
 };
 
 pub struct UiEventPlugin;
@@ -25,6 +25,7 @@ impl Plugin for UiEventPlugin {
             .add_event::<PressEvent>()
             .add_event::<ReleaseEvent>()
             .add_event::<ClickEvent>()
+            .add_event::<SecondaryPressEvent>()
             .add_systems(
                 Update,
                 (on_mouse_move, on_mouse_click_start, on_mouse_click_end),
@@ -50,12 +51,13 @@ fn on_mouse_move(
     primary_window: Query<&Window, With<PrimaryWindow>>,
     elements_not_hovered: Query<
         (Entity, &Position, &Size, &Collision, Option<&VisibleRegion>),
-        Without<ElementHovered>,
+        (Without<ElementHovered>, Without<Disabled>),
     >,
     elements_hovered: Query<
         (Entity, &Position, &Size, &Collision, Option<&VisibleRegion>),
-        With<ElementHovered>,
+        (With<ElementHovered>, Without<Disabled>),
     >,
+    modal_scope: ModalScope,
     mut mouse_motion_events: EventReader<MouseMotion>,
     mut hover_enter_events: EventWriter<HoverEnterEvent>,
     mut hover_exit_events: EventWriter<HoverExitEvent>,
@@ -74,6 +76,11 @@ fn on_mouse_move(
 
     if mouse_motion_events.read().next().is_some() {
         for (entity, position, size, collision, visible_region) in elements_not_hovered.iter() {
+            // Elements behind the topmost modal don't get hovered
+            if !modal_scope.allows(entity) {
+                continue;
+            }
+
             let visible_region = match visible_region {
                 Some(visible_region) => visible_region.clone(),
                 None => VisibleRegion::new(position.x, position.y, size.width, size.height),
@@ -91,6 +98,14 @@ fn on_mouse_move(
         }
 
         for (entity, position, size, collision, visible_region) in elements_hovered.iter() {
+            // A modal opened over a hovered element takes its hover away
+            if !modal_scope.allows(entity) {
+                commands.entity(entity).remove::<ElementHovered>();
+                hover_exit_events.send(HoverExitEvent { element: entity });
+
+                continue;
+            }
+
             let visible_region = match visible_region {
                 Some(visible_region) => visible_region.clone(),
                 None => VisibleRegion::new(position.x, position.y, size.width, size.height),
@@ -126,15 +141,26 @@ pub struct ClickEvent {
     pub element: Entity,
 }
 
+// Right button presses, they don't make the element pressed or clicked
+#[derive(Event)]
+pub struct SecondaryPressEvent {
+    pub element: Entity,
+}
+
 #[derive(Component)]
 pub struct ElementPressed;
 
-fn on_mouse_click_start(
+pub(crate) fn on_mouse_click_start(
     mut commands: Commands,
     primary_window: Query<&Window, With<PrimaryWindow>>,
-    elements: Query<(Entity, &Position, &Size, &Collision, Option<&VisibleRegion>)>,
+    elements: Query<
+        (Entity, &Position, &Size, &Collision, Option<&VisibleRegion>),
+        Without<Disabled>,
+    >,
+    modal_scope: ModalScope,
     mut mouse_click_events: EventReader<MouseButtonInput>,
     mut press_events: EventWriter<PressEvent>,
+    mut secondary_press_events: EventWriter<SecondaryPressEvent>,
 ) {
     let Ok(primary_window) = primary_window.get_single() else {
         warn!("Couldn't get primary window");
@@ -149,8 +175,16 @@ fn on_mouse_click_start(
     let cursor_position = cursor_position.round();
 
     for mouse_click_event in mouse_click_events.read() {
-        if mouse_click_event.button == MouseButton::Left {
+        if matches!(
+            mouse_click_event.button,
+            MouseButton::Left | MouseButton::Right
+        ) {
             for (entity, position, size, collision, visible_region) in elements.iter() {
+                // Elements behind the topmost modal don't get pressed
+                if !modal_scope.allows(entity) {
+                    continue;
+                }
+
                 let visible_region = match visible_region {
                     Some(visible_region) => visible_region.clone(),
                     None => VisibleRegion::new(position.x, position.y, size.width, size.height),
@@ -166,6 +200,10 @@ fn on_mouse_click_start(
                         {
                             commands.entity(entity).insert(ElementPressed);
                             press_events.send(PressEvent { element: entity });
+                        } else if mouse_click_event.button == MouseButton::Right
+                            && mouse_click_event.state == ButtonState::Pressed
+                        {
+                            secondary_press_events.send(SecondaryPressEvent { element: entity });
                         }
                     }
                 }
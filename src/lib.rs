@@ -2,27 +2,39 @@ pub mod camera;
 pub mod element;
 pub mod event;
 pub mod property;
+pub mod widget;
 
 #[doc(hidden)]
 pub mod prelude {
-    pub use crate::camera::{UiCamera, UiCameraBundle};
+    pub use crate::camera::{UiCamera, UiCameraBundle, UiMsaa};
     pub use crate::UiPlugin;
 
     pub use crate::element::prelude::*;
     pub use crate::property::prelude::*;
+    pub use crate::widget::prelude::*;
 }
 
 use bevy::{prelude::*, window::RequestRedraw, winit::WinitSettings};
 
-use element::{r#box::UiBoxPlugin, text::UiTextPlugin};
+use element::{
+    image::UiImagePlugin, localization::UiLocalizationPlugin, r#box::UiBoxPlugin,
+    rich_text::UiRichTextPlugin, shadow::UiBoxShadowPlugin, text::UiTextPlugin,
+    text_selection::UiTextSelectionPlugin,
+};
 use event::UiEventPlugin;
 use property::{
     auto_remove::UiAutoRemovePlugin,
     collision::UiCollisionPlugin,
-    state::{click::UiClickStatePlugin, hover::UiHoverStatePlugin},
+    focus::UiFocusPlugin,
+    modal::UiModalPlugin,
+    state::{
+        checked::UiCheckedStatePlugin, click::UiClickStatePlugin, disabled::UiDisabledStatePlugin,
+        hover::UiHoverStatePlugin,
+    },
     transition::UiTransitionPlugin,
     update::UiUpdatePropertiesPlugin,
 };
+use widget::UiWidgetPlugin;
 
 use crate::camera::UiCameraPlugin;
 
@@ -33,15 +45,29 @@ impl Plugin for UiPlugin {
         app.add_systems(Startup, redraw)
             .add_plugins((
                 UiCameraPlugin,
-                UiBoxPlugin,
-                UiTextPlugin,
-                UiTransitionPlugin,
-                UiAutoRemovePlugin,
-                UiCollisionPlugin,
-                UiUpdatePropertiesPlugin,
-                UiEventPlugin,
-                UiHoverStatePlugin,
-                UiClickStatePlugin,
+                (
+                    UiBoxPlugin,
+                    UiBoxShadowPlugin,
+                    UiImagePlugin,
+                    UiTextPlugin,
+                    UiRichTextPlugin,
+                    UiTextSelectionPlugin,
+                    UiLocalizationPlugin,
+                ),
+                (
+                    UiTransitionPlugin,
+                    UiAutoRemovePlugin,
+                    UiCollisionPlugin,
+                    UiUpdatePropertiesPlugin,
+                    UiEventPlugin,
+                    UiFocusPlugin,
+                    UiModalPlugin,
+                    UiHoverStatePlugin,
+                    UiClickStatePlugin,
+                    UiDisabledStatePlugin,
+                    UiCheckedStatePlugin,
+                ),
+                UiWidgetPlugin,
             ))
             .insert_resource(WinitSettings::game());
     }
@@ -1,6 +1,12 @@
 use std::{marker::PhantomData, time::Duration};
 
-use crate::{element::text::FontSize, property::*};
+use crate::{
+    element::{
+        shadow::{BoxShadow, BoxShadows},
+        text::FontSize,
+    },
+    property::{fill::Fill, *},
+};
 use bevy::{
     prelude::{Commands, Component, Entity, EventWriter, Plugin, PostUpdate, Query, With, Without},
     utils::Instant,
@@ -17,8 +23,11 @@ impl Plugin for UiAutoRemovePlugin {
                 remove_system::<Position>,
                 remove_system::<Size>,
                 remove_system::<ColoredElement>,
+                remove_system::<Fill>,
                 remove_system::<CornersRoundness>,
                 remove_system::<FontSize>,
+                remove_system::<BoxShadow>,
+                remove_system::<BoxShadows>,
             ),
         );
     }
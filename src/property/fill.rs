@@ -0,0 +1,278 @@
+use bevy::{
+    prelude::{Color, Component, ReflectComponent, Vec2, Vec4},
+    reflect::Reflect,
+};
+
+pub const MAX_GRADIENT_STOPS: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct ColorStop {
+    pub color: Color,
+    pub offset: f32,
+}
+
+impl ColorStop {
+    #[inline]
+    pub fn new(color: Color, offset: f32) -> ColorStop {
+        ColorStop { color, offset }
+    }
+}
+
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum Fill {
+    Solid(Color),
+    LinearGradient {
+        angle: f32, // Radians, clockwise starting from the top like CSS
+        stops: Vec<ColorStop>,
+    },
+    RadialGradient {
+        center: Vec2, // Relative to the element size, (0.5, 0.5) is the center
+        radius: f32,  // Relative to the element half size
+        stops: Vec<ColorStop>,
+    },
+    ConicGradient {
+        center: Vec2, // Relative to the element size, (0.5, 0.5) is the center
+        angle: f32,   // Radians, clockwise starting from the top like CSS
+        stops: Vec<ColorStop>,
+    },
+}
+
+impl Default for Fill {
+    fn default() -> Self {
+        Fill::Solid(Color::default())
+    }
+}
+
+impl Fill {
+    pub fn linear(angle: f32, stops: Vec<ColorStop>) -> Fill {
+        Fill::LinearGradient { angle, stops }
+    }
+
+    pub fn radial(center: Vec2, radius: f32, stops: Vec<ColorStop>) -> Fill {
+        Fill::RadialGradient {
+            center,
+            radius,
+            stops,
+        }
+    }
+
+    pub fn conic(center: Vec2, angle: f32, stops: Vec<ColorStop>) -> Fill {
+        Fill::ConicGradient {
+            center,
+            angle,
+            stops,
+        }
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        match self {
+            Fill::Solid(_) => &[],
+            Fill::LinearGradient { stops, .. }
+            | Fill::RadialGradient { stops, .. }
+            | Fill::ConicGradient { stops, .. } => stops,
+        }
+    }
+
+    pub fn is_same_kind(&self, other: &Fill) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    pub fn sample(&self, offset: f32) -> Color {
+        match self {
+            Fill::Solid(color) => *color,
+            _ => {
+                let mut stops = self.stops().to_vec();
+                stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+                sample_stops(&stops, offset)
+            }
+        }
+    }
+
+    pub fn average_color(&self) -> Color {
+        match self {
+            Fill::Solid(color) => *color,
+            _ => {
+                let samples = MAX_GRADIENT_STOPS * 2;
+                let sum = (0..samples)
+                    .map(|index| {
+                        self.sample((index as f32 + 0.5) / samples as f32)
+                            .rgba_to_vec4()
+                    })
+                    .sum::<Vec4>();
+
+                Color::rgba_from_array(sum / samples as f32)
+            }
+        }
+    }
+
+    // Sorted by offset & resampled evenly when they don't fit into MAX_GRADIENT_STOPS
+    pub fn normalized_stops(&self) -> Vec<ColorStop> {
+        let mut stops = match self {
+            Fill::Solid(color) => vec![ColorStop::new(*color, 0.0)],
+            _ => self.stops().to_vec(),
+        };
+
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+        if stops.len() > MAX_GRADIENT_STOPS {
+            stops = (0..MAX_GRADIENT_STOPS)
+                .map(|index| {
+                    let offset = index as f32 / (MAX_GRADIENT_STOPS - 1) as f32;
+                    ColorStop::new(self.sample(offset), offset)
+                })
+                .collect();
+        }
+
+        stops
+    }
+
+    fn with_stops(&self, stops: Vec<ColorStop>) -> Fill {
+        match self {
+            Fill::Solid(_) => Fill::Solid(stops.first().map(|stop| stop.color).unwrap_or_default()),
+            Fill::LinearGradient { angle, .. } => Fill::linear(*angle, stops),
+            Fill::RadialGradient { center, radius, .. } => Fill::radial(*center, *radius, stops),
+            Fill::ConicGradient { center, angle, .. } => Fill::conic(*center, *angle, stops),
+        }
+    }
+
+    fn promote_to_kind_of(&self, other: &Fill) -> Fill {
+        match self {
+            Fill::Solid(color) => other.with_stops(vec![
+                ColorStop::new(*color, 0.0),
+                ColorStop::new(*color, 1.0),
+            ]),
+            _ => self.clone(),
+        }
+    }
+
+    pub(crate) fn interpolate(progress: f32, from: &Fill, to: &Fill) -> Fill {
+        if let (Fill::Solid(from), Fill::Solid(to)) = (from, to) {
+            return Fill::Solid(lerp_color(*from, *to, progress));
+        }
+
+        let from = from.promote_to_kind_of(to);
+        let to = to.promote_to_kind_of(&from);
+
+        if !from.is_same_kind(&to) {
+            // Gradients of different kinds can't be morphed, fade through their averaged color instead
+            let middle = Fill::Solid(lerp_color(from.average_color(), to.average_color(), 0.5));
+
+            return if progress < 0.5 {
+                Fill::interpolate(progress * 2.0, &from, &middle)
+            } else {
+                Fill::interpolate(progress * 2.0 - 1.0, &middle, &to)
+            };
+        }
+
+        let mut offsets = from
+            .stops()
+            .iter()
+            .chain(to.stops())
+            .map(|stop| stop.offset)
+            .collect::<Vec<_>>();
+
+        offsets.sort_by(f32::total_cmp);
+        offsets.dedup();
+
+        if offsets.len() > MAX_GRADIENT_STOPS {
+            offsets = (0..MAX_GRADIENT_STOPS)
+                .map(|index| index as f32 / (MAX_GRADIENT_STOPS - 1) as f32)
+                .collect();
+        }
+
+        let stops = offsets
+            .into_iter()
+            .map(|offset| {
+                ColorStop::new(
+                    lerp_color(from.sample(offset), to.sample(offset), progress),
+                    offset,
+                )
+            })
+            .collect();
+
+        match (&from, &to) {
+            (
+                Fill::LinearGradient {
+                    angle: from_angle, ..
+                },
+                Fill::LinearGradient {
+                    angle: to_angle, ..
+                },
+            ) => Fill::linear(lerp(*from_angle, *to_angle, progress), stops),
+            (
+                Fill::RadialGradient {
+                    center: from_center,
+                    radius: from_radius,
+                    ..
+                },
+                Fill::RadialGradient {
+                    center: to_center,
+                    radius: to_radius,
+                    ..
+                },
+            ) => Fill::radial(
+                from_center.lerp(*to_center, progress),
+                lerp(*from_radius, *to_radius, progress),
+                stops,
+            ),
+            (
+                Fill::ConicGradient {
+                    center: from_center,
+                    angle: from_angle,
+                    ..
+                },
+                Fill::ConicGradient {
+                    center: to_center,
+                    angle: to_angle,
+                    ..
+                },
+            ) => Fill::conic(
+                from_center.lerp(*to_center, progress),
+                lerp(*from_angle, *to_angle, progress),
+                stops,
+            ),
+            _ => to.clone(),
+        }
+    }
+}
+
+impl From<Color> for Fill {
+    fn from(color: Color) -> Self {
+        Fill::Solid(color)
+    }
+}
+
+fn sample_stops(stops: &[ColorStop], offset: f32) -> Color {
+    let Some(first) = stops.first() else {
+        return Color::NONE;
+    };
+
+    if offset <= first.offset {
+        return first.color;
+    }
+
+    for (previous, next) in stops.iter().zip(stops.iter().skip(1)) {
+        if offset <= next.offset {
+            let range = (next.offset - previous.offset).max(f32::EPSILON);
+            return lerp_color(
+                previous.color,
+                next.color,
+                (offset - previous.offset) / range,
+            );
+        }
+    }
+
+    stops.last().map(|stop| stop.color).unwrap_or(first.color)
+}
+
+#[inline]
+fn lerp(from: f32, to: f32, progress: f32) -> f32 {
+    from + (to - from) * progress
+}
+
+#[inline]
+fn lerp_color(from: Color, to: Color, progress: f32) -> Color {
+    Color::rgba_from_array(from.rgba_to_vec4().lerp(to.rgba_to_vec4(), progress))
+}
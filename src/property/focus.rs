@@ -0,0 +1,133 @@
+use bevy::{
+    prelude::{
+        Commands, Component, Entity, EventReader, Input, IntoSystemConfigs, KeyCode, MouseButton,
+        Plugin, Query, ReflectComponent, Res, Update, With, Without,
+    },
+    reflect::Reflect,
+};
+
+use crate::{
+    event::{on_mouse_click_start, PressEvent},
+    property::{modal::ModalScope, state::disabled::Disabled, update::TreeOrder, ZLevel},
+};
+
+pub(crate) struct UiFocusPlugin;
+
+impl Plugin for UiFocusPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(
+            Update,
+            (
+                focus_on_press.after(on_mouse_click_start),
+                cycle_focus,
+                blur_disabled_elements,
+            ),
+        );
+    }
+}
+
+// Lets the element take the keyboard focus by pressing it or cycling to it with Tab / Shift+Tab
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Focusable;
+
+// At most one element has it at a time
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Focused;
+
+pub(crate) fn set_focus(
+    commands: &mut Commands,
+    focused: &Query<Entity, With<Focused>>,
+    new_focus: Option<Entity>,
+) {
+    for entity in focused.iter() {
+        if Some(entity) != new_focus {
+            commands.entity(entity).remove::<Focused>();
+        }
+    }
+
+    if let Some(new_focus) = new_focus {
+        if !focused.contains(new_focus) {
+            commands.entity(new_focus).insert(Focused);
+        }
+    }
+}
+
+// Pressing outside of any focusable element clears the focus
+fn focus_on_press(
+    mut commands: Commands,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut press_events: EventReader<PressEvent>,
+    focusables: Query<Option<&ZLevel>, (With<Focusable>, Without<Disabled>)>,
+    focused: Query<Entity, With<Focused>>,
+) {
+    if !mouse_buttons.just_pressed(MouseButton::Left) {
+        press_events.clear();
+        return;
+    }
+
+    // Overlapping elements are all pressed, the topmost one takes the focus
+    let pressed_focusable = press_events
+        .read()
+        .filter_map(|press_event| {
+            focusables
+                .get(press_event.element)
+                .ok()
+                .map(|z_level| (press_event.element, z_level.map_or(0, |z_level| z_level.0)))
+        })
+        .max_by_key(|(_, z_level)| *z_level)
+        .map(|(entity, _)| entity);
+
+    set_focus(&mut commands, &focused, pressed_focusable);
+}
+
+fn cycle_focus(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    focusables: Query<(Entity, Option<&TreeOrder>), (With<Focusable>, Without<Disabled>)>,
+    focused: Query<Entity, With<Focused>>,
+    modal_scope: ModalScope,
+) {
+    if !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+
+    // The focus stays trapped inside the topmost modal
+    let mut focusables = focusables
+        .iter()
+        .filter(|(entity, _)| modal_scope.allows(*entity))
+        .map(|(entity, tree_order)| (tree_order.copied().unwrap_or_default(), entity))
+        .collect::<Vec<_>>();
+
+    if focusables.is_empty() {
+        return;
+    }
+
+    focusables.sort_unstable_by_key(|(tree_order, entity)| (tree_order.0, entity.index()));
+
+    let current = focused
+        .iter()
+        .next()
+        .and_then(|focused| focusables.iter().position(|(_, entity)| *entity == focused));
+
+    let backwards = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let next = match (current, backwards) {
+        (None, false) => 0,
+        (None, true) => focusables.len() - 1,
+        (Some(current), false) => (current + 1) % focusables.len(),
+        (Some(current), true) => (current + focusables.len() - 1) % focusables.len(),
+    };
+
+    set_focus(&mut commands, &focused, Some(focusables[next].1));
+}
+
+fn blur_disabled_elements(
+    mut commands: Commands,
+    elements: Query<Entity, (With<Focused>, With<Disabled>)>,
+) {
+    for entity in elements.iter() {
+        commands.entity(entity).remove::<Focused>();
+    }
+}
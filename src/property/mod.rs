@@ -1,5 +1,8 @@
 pub mod auto_remove;
 pub mod collision;
+pub mod fill;
+pub mod focus;
+pub mod modal;
 pub mod state;
 pub mod transition;
 pub mod update;
@@ -8,9 +11,16 @@ pub mod update;
 pub mod prelude {
     pub use crate::property::{
         collision::{AABBCollisionBundle, BoxCollisionBundle},
+        fill::{ColorStop, Fill},
+        focus::{Focusable, Focused},
+        modal::{Modal, ModalClosedEvent, UiModalBundle},
+        state::{
+            checked::{Checked, CheckedEffect, CheckedEffectTransition},
+            disabled::{Disabled, DisabledEffect},
+        },
         transition::Transition,
-        update::AutoZUpdate,
-        ColoredElement, CornersRoundness, Position, Size,
+        update::{AutoZUpdate, Overlay},
+        ColoredElement, CornersRoundness, CornersShape, Position, Size, ZIndex,
     };
 }
 
@@ -49,6 +59,33 @@ impl From<Vec2> for Position {
 #[reflect(Component)]
 pub struct ZLevel(pub u32);
 
+// Overrides how an element with AutoZUpdate gets its ZLevel, the element's descendants stay stacked on top of it
+// Local offsets the level from the parent's one (parent + 1 + offset)
+// Global places the element on an absolute level regardless of its ancestors (e.g. popups & tooltips)
+#[derive(Component, Debug, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum ZIndex {
+    Local(i32),
+    Global(i32),
+}
+
+impl Default for ZIndex {
+    fn default() -> Self {
+        ZIndex::Local(0)
+    }
+}
+
+impl ZIndex {
+    pub fn z_level(&self, parent_z_level: Option<u32>) -> Option<u32> {
+        match *self {
+            ZIndex::Local(offset) => parent_z_level.map(|parent_z_level| {
+                (parent_z_level as i64 + 1 + offset as i64).clamp(0, u32::MAX as i64) as u32
+            }),
+            ZIndex::Global(z_level) => Some(z_level.max(0) as u32),
+        }
+    }
+}
+
 #[derive(Component, Debug, Clone, Hash, PartialEq, Eq, Reflect)]
 #[reflect(Component)]
 pub struct Size {
@@ -136,6 +173,15 @@ impl From<Vec4> for CornersRoundness {
     }
 }
 
+// Elliptical corners scale each axis of the corner radius by that axis of the element instead of the smallest one
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum CornersShape {
+    #[default]
+    Circular,
+    Elliptical,
+}
+
 #[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
 #[reflect(Component)]
 pub struct VisibleRegion {
@@ -0,0 +1,280 @@
+use bevy::{
+    ecs::system::SystemParam,
+    hierarchy::HierarchyQueryExt,
+    log::warn,
+    prelude::{
+        Added, Bundle, Color, Commands, Component, DespawnRecursiveExt, Entity, Event, EventWriter,
+        Has, Input, IntoSystemConfigs, KeyCode, Or, Parent, Plugin, Query, ReflectComponent,
+        RemovedComponents, Res, ResMut, Resource, Update, With, Without,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    element::r#box::UiBox,
+    event::{ElementHovered, ElementPressed, HoverExitEvent, ReleaseEvent},
+    property::{
+        collision::BoxCollisionBundle,
+        focus::{set_focus, Focusable, Focused},
+        state::disabled::Disabled,
+        update::{AutoVisibleRegionUpdate, AutoZUpdate, Overlay, TreeOrder},
+        ColoredElement, Position, Size, VisibleRegion, ZIndex, ZLevel,
+    },
+};
+
+pub(crate) struct UiModalPlugin;
+
+impl Plugin for UiModalPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_resource::<ModalStack>()
+            .add_event::<ModalClosedEvent>()
+            .add_systems(
+                Update,
+                (
+                    (update_modal_stack, release_blocked_elements).chain(),
+                    fit_modal_backdrops,
+                    close_modals_on_escape,
+                ),
+            );
+    }
+}
+
+// Each modal gets its own range of z levels above the previous one, for its dialog's descendants to stack in
+const MODAL_Z_LEVEL: i32 = 10_000;
+const MODAL_Z_LEVEL_STEP: i32 = 1_000;
+
+// The root of a dialog blocking the rest of the UI, drawn as a backdrop covering the whole window
+// Only the topmost modal & its descendants get hovered, pressed & focused, the dialog itself goes in its children
+#[derive(Component, Debug, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Modal {
+    pub close_on_escape: bool,
+}
+
+impl Default for Modal {
+    fn default() -> Self {
+        Self {
+            close_on_escape: true,
+        }
+    }
+}
+
+// Sent when Escape despawns the topmost modal
+#[derive(Event)]
+pub struct ModalClosedEvent {
+    pub modal: Entity,
+}
+
+#[derive(Bundle)]
+pub struct UiModalBundle {
+    pub modal: Modal,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    // The backdrop's color, dimming the UI behind the modal
+    pub color: ColoredElement,
+
+    // Presses on the backdrop are caught by the modal instead of falling through
+    pub collision: BoxCollisionBundle,
+    pub overlay: Overlay,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl Default for UiModalBundle {
+    fn default() -> Self {
+        Self {
+            modal: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Default::default(),
+            color: ColoredElement::new(Color::rgba(0.0, 0.0, 0.0, 0.5)),
+
+            collision: Default::default(),
+            overlay: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+// Open modals from the bottom one to the topmost one, along with the element focused before each was opened
+#[derive(Resource, Default)]
+pub(crate) struct ModalStack(Vec<(Entity, Option<Entity>)>);
+
+impl ModalStack {
+    fn top(&self) -> Option<Entity> {
+        self.0.last().map(|(modal, _)| *modal)
+    }
+}
+
+// Tells whether an element can get input while modals are open
+#[derive(SystemParam)]
+pub(crate) struct ModalScope<'w, 's> {
+    modal_stack: Res<'w, ModalStack>,
+    parents: Query<'w, 's, &'static Parent>,
+}
+
+impl<'w, 's> ModalScope<'w, 's> {
+    pub(crate) fn allows(&self, entity: Entity) -> bool {
+        let Some(top_modal) = self.modal_stack.top() else {
+            return true;
+        };
+
+        entity == top_modal
+            || self
+                .parents
+                .iter_ancestors(entity)
+                .any(|ancestor| ancestor == top_modal)
+    }
+}
+
+// Opening a modal moves the focus to its first focusable element, closing it gives the focus back
+fn update_modal_stack(
+    mut commands: Commands,
+    mut modal_stack: ResMut<ModalStack>,
+    added_modals: Query<Entity, Added<Modal>>,
+    mut removed_modals: RemovedComponents<Modal>,
+    focusables: Query<(Entity, Option<&TreeOrder>), (With<Focusable>, Without<Disabled>)>,
+    parents: Query<&Parent>,
+    focused: Query<Entity, With<Focused>>,
+) {
+    for removed_modal in removed_modals.read() {
+        let Some(index) = modal_stack
+            .0
+            .iter()
+            .position(|(modal, _)| *modal == removed_modal)
+        else {
+            continue;
+        };
+
+        let (_, previous_focus) = modal_stack.0.remove(index);
+
+        match modal_stack.0.get_mut(index) {
+            // Closing a modal below the topmost one hands its previous focus to the one above it
+            Some((_, above_previous_focus)) => *above_previous_focus = previous_focus,
+            None => {
+                let previous_focus = previous_focus.filter(|entity| focusables.contains(*entity));
+                set_focus(&mut commands, &focused, previous_focus);
+            }
+        }
+    }
+
+    for added_modal in added_modals.iter() {
+        let z_level = MODAL_Z_LEVEL + MODAL_Z_LEVEL_STEP * modal_stack.0.len() as i32;
+        commands.entity(added_modal).insert(ZIndex::Global(z_level));
+
+        let first_focusable = focusables
+            .iter()
+            .filter(|(entity, _)| {
+                parents
+                    .iter_ancestors(*entity)
+                    .any(|ancestor| ancestor == added_modal)
+            })
+            .min_by_key(|(entity, tree_order)| {
+                (tree_order.copied().unwrap_or_default().0, entity.index())
+            })
+            .map(|(entity, _)| entity);
+
+        modal_stack.0.push((added_modal, focused.iter().next()));
+        set_focus(&mut commands, &focused, first_focusable);
+    }
+}
+
+// Elements covered by a newly opened modal stop being hovered & pressed
+fn release_blocked_elements(
+    mut commands: Commands,
+    added_modals: Query<(), Added<Modal>>,
+    elements: Query<
+        (Entity, Has<ElementHovered>, Has<ElementPressed>),
+        Or<(With<ElementHovered>, With<ElementPressed>)>,
+    >,
+    modal_scope: ModalScope,
+    mut hover_exit_events: EventWriter<HoverExitEvent>,
+    mut release_events: EventWriter<ReleaseEvent>,
+) {
+    if added_modals.is_empty() {
+        return;
+    }
+
+    for (entity, hovered, pressed) in elements.iter() {
+        if modal_scope.allows(entity) {
+            continue;
+        }
+
+        if hovered {
+            commands.entity(entity).remove::<ElementHovered>();
+            hover_exit_events.send(HoverExitEvent { element: entity });
+        }
+
+        if pressed {
+            commands.entity(entity).remove::<ElementPressed>();
+            release_events.send(ReleaseEvent { element: entity });
+        }
+    }
+}
+
+fn fit_modal_backdrops(
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mut modals: Query<(&mut Position, &mut Size), With<Modal>>,
+) {
+    if modals.is_empty() {
+        return;
+    }
+
+    let Ok(primary_window) = primary_window.get_single() else {
+        warn!("Couldn't get primary window");
+
+        return;
+    };
+
+    let window_size = Size::new(
+        primary_window.width() as u32,
+        primary_window.height() as u32,
+    );
+
+    for (mut position, mut size) in modals.iter_mut() {
+        if *position != Position::new(0, 0) {
+            *position = Position::new(0, 0);
+        }
+
+        if *size != window_size {
+            *size = window_size.clone();
+        }
+    }
+}
+
+fn close_modals_on_escape(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    modal_stack: Res<ModalStack>,
+    modals: Query<&Modal>,
+    mut modal_closed_events: EventWriter<ModalClosedEvent>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    let Some(top_modal) = modal_stack.top() else {
+        return;
+    };
+
+    if modals
+        .get(top_modal)
+        .is_ok_and(|modal| modal.close_on_escape)
+    {
+        commands.entity(top_modal).despawn_recursive();
+        modal_closed_events.send(ModalClosedEvent { modal: top_modal });
+    }
+}
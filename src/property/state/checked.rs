@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{
+        Changed, Commands, Component, Entity, Has, Plugin, Query, Ref, ReflectComponent, Update,
+        World,
+    },
+    reflect::Reflect,
+};
+
+use crate::{
+    prelude::*,
+    property::{
+        auto_remove::{remove_system, AutoRemove},
+        transition::{transition_system, PropertyTransition},
+    },
+};
+
+use super::{Active, ActiveOptionExt, AppComponentStateExt, ComponentState, CurrentlyActive};
+
+pub struct UiCheckedStatePlugin;
+
+impl Plugin for UiCheckedStatePlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_component_state::<CheckedState, Position>(())
+            .add_component_state::<CheckedState, Size>(())
+            .add_component_state::<CheckedState, ColoredElement>(())
+            .add_component_state::<CheckedState, Fill>(())
+            .add_component_state::<CheckedState, CornersRoundness>(())
+            .add_component_state::<CheckedState, FontSize>(())
+            .add_component_state::<CheckedState, BoxShadow>(())
+            .add_component_state::<CheckedState, BoxShadows>(())
+            .add_systems(
+                Update,
+                (
+                    transition_system::<CheckedValue<Position>>,
+                    transition_system::<CheckedValue<Size>>,
+                    transition_system::<CheckedValue<ColoredElement>>,
+                    transition_system::<CheckedValue<Fill>>,
+                    transition_system::<CheckedValue<CornersRoundness>>,
+                    transition_system::<CheckedValue<FontSize>>,
+                    transition_system::<CheckedValue<BoxShadow>>,
+                    transition_system::<CheckedValue<BoxShadows>>,
+                    remove_system::<CheckedValue<Position>>,
+                    remove_system::<CheckedValue<Size>>,
+                    remove_system::<CheckedValue<ColoredElement>>,
+                    remove_system::<CheckedValue<Fill>>,
+                    remove_system::<CheckedValue<CornersRoundness>>,
+                    remove_system::<CheckedValue<FontSize>>,
+                    remove_system::<CheckedValue<BoxShadow>>,
+                    remove_system::<CheckedValue<BoxShadows>>,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    checked_effect_system::<Position>,
+                    checked_effect_system::<Size>,
+                    checked_effect_system::<ColoredElement>,
+                    checked_effect_system::<Fill>,
+                    checked_effect_system::<CornersRoundness>,
+                    checked_effect_system::<FontSize>,
+                    checked_effect_system::<BoxShadow>,
+                    checked_effect_system::<BoxShadows>,
+                    checked_effect_transition_system::<Position>,
+                    checked_effect_transition_system::<Size>,
+                    checked_effect_transition_system::<ColoredElement>,
+                    checked_effect_transition_system::<Fill>,
+                    checked_effect_transition_system::<CornersRoundness>,
+                    checked_effect_transition_system::<FontSize>,
+                    checked_effect_transition_system::<BoxShadow>,
+                    checked_effect_transition_system::<BoxShadows>,
+                ),
+            );
+    }
+}
+
+// The on/off value of checkboxes, toggle switches & radio buttons, its effects apply while it's true
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Checked(pub bool);
+
+#[derive(Clone)]
+pub struct CheckedState;
+
+pub type CheckedValue<T> = ComponentState<CheckedState, T>;
+
+#[derive(Component)]
+pub struct CheckedEffect<T: Component + Clone> {
+    pub value: T,
+}
+
+impl<T: Component + Clone> CheckedEffect<T> {
+    pub fn new(value: T) -> CheckedEffect<T> {
+        CheckedEffect { value }
+    }
+}
+
+#[derive(Component)]
+pub struct CheckedEffectTransition<T: PropertyTransition<T> + Component + Clone> {
+    // Public so widgets can move the checked value along with their layout
+    pub value: T,
+
+    in_duration: Duration,
+    out_duration: Duration,
+}
+
+impl<T: PropertyTransition<T> + Component + Clone> CheckedEffectTransition<T> {
+    pub fn new(
+        value: T,
+        in_duration: Duration,
+        out_duration: Duration,
+    ) -> CheckedEffectTransition<T> {
+        CheckedEffectTransition {
+            value,
+
+            in_duration,
+            out_duration,
+        }
+    }
+}
+
+pub fn checked_effect_system<T: Component + Clone>(
+    mut commands: Commands,
+    effects: Query<(Entity, &Checked, &CheckedEffect<T>, Has<CheckedValue<T>>), Changed<Checked>>,
+) {
+    for (entity, checked, effect, has_checked_value) in effects.iter() {
+        if checked.0 {
+            commands
+                .entity(entity)
+                .insert(CheckedValue::new(effect.value.clone()));
+        } else if has_checked_value {
+            commands.entity(entity).remove::<CheckedValue<T>>();
+        }
+    }
+}
+
+// Elements spawned checked start with the checked value instead of transitioning to it
+pub fn checked_effect_transition_system<T: PropertyTransition<T> + Component + Clone>(
+    world: &World,
+    mut commands: Commands,
+    effects: Query<
+        (
+            Entity,
+            Ref<Checked>,
+            &CheckedEffectTransition<T>,
+            &T,
+            &CurrentlyActive<T>,
+            Option<&Active<T>>,
+        ),
+        Changed<Checked>,
+    >,
+) {
+    for (entity, checked, effect, base_value, active_value, active) in effects.iter() {
+        let is_checked_active = active.is_active_state::<CheckedValue<T>>(world.components());
+
+        if checked.is_added() {
+            if checked.0 {
+                commands
+                    .entity(entity)
+                    .insert(CheckedValue::new(effect.value.clone()));
+            }
+        } else if checked.0 {
+            commands
+                .entity(entity)
+                .insert((
+                    CheckedValue::new(active_value.clone()),
+                    Transition::new(CheckedValue::new(effect.value.clone()), effect.in_duration),
+                ))
+                .remove::<AutoRemove<CheckedValue<T>>>();
+        } else if is_checked_active {
+            let entity_ref = world.entity(entity);
+
+            commands.entity(entity).insert((
+                Transition::new(
+                    CheckedValue::new(
+                        active
+                            .second_active_or_base(world, &entity_ref, base_value)
+                            .clone(),
+                    ),
+                    effect.out_duration,
+                ),
+                AutoRemove::<CheckedValue<T>>::new(effect.out_duration),
+            ));
+        } else {
+            commands.entity(entity).remove::<CheckedValue<T>>();
+        }
+    }
+}
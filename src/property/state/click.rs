@@ -20,21 +20,30 @@ impl Plugin for UiClickStatePlugin {
         app.add_component_state::<ClickState, Position>(())
             .add_component_state::<ClickState, Size>(())
             .add_component_state::<ClickState, ColoredElement>(())
+            .add_component_state::<ClickState, Fill>(())
             .add_component_state::<ClickState, CornersRoundness>(())
             .add_component_state::<ClickState, FontSize>(())
+            .add_component_state::<ClickState, BoxShadow>(())
+            .add_component_state::<ClickState, BoxShadows>(())
             .add_systems(
                 Update,
                 (
                     transition_system::<Clicked<Position>>,
                     transition_system::<Clicked<Size>>,
                     transition_system::<Clicked<ColoredElement>>,
+                    transition_system::<Clicked<Fill>>,
                     transition_system::<Clicked<CornersRoundness>>,
                     transition_system::<Clicked<FontSize>>,
+                    transition_system::<Clicked<BoxShadow>>,
+                    transition_system::<Clicked<BoxShadows>>,
                     remove_system::<Clicked<Position>>,
                     remove_system::<Clicked<Size>>,
                     remove_system::<Clicked<ColoredElement>>,
+                    remove_system::<Clicked<Fill>>,
                     remove_system::<Clicked<CornersRoundness>>,
                     remove_system::<Clicked<FontSize>>,
+                    remove_system::<Clicked<BoxShadow>>,
+                    remove_system::<Clicked<BoxShadows>>,
                 ),
             )
             .add_systems(
@@ -43,13 +52,19 @@ impl Plugin for UiClickStatePlugin {
                     click_effect_system::<Position>,
                     click_effect_system::<Size>,
                     click_effect_system::<ColoredElement>,
+                    click_effect_system::<Fill>,
                     click_effect_system::<CornersRoundness>,
                     click_effect_system::<FontSize>,
+                    click_effect_system::<BoxShadow>,
+                    click_effect_system::<BoxShadows>,
                     click_effect_clear_system::<Position>,
                     click_effect_clear_system::<Size>,
                     click_effect_clear_system::<ColoredElement>,
+                    click_effect_clear_system::<Fill>,
                     click_effect_clear_system::<CornersRoundness>,
                     click_effect_clear_system::<FontSize>,
+                    click_effect_clear_system::<BoxShadow>,
+                    click_effect_clear_system::<BoxShadows>,
                 ),
             )
             .add_systems(
@@ -58,13 +73,19 @@ impl Plugin for UiClickStatePlugin {
                     click_effect_transition_in_system::<Position>,
                     click_effect_transition_in_system::<Size>,
                     click_effect_transition_in_system::<ColoredElement>,
+                    click_effect_transition_in_system::<Fill>,
                     click_effect_transition_in_system::<CornersRoundness>,
                     click_effect_transition_in_system::<FontSize>,
+                    click_effect_transition_in_system::<BoxShadow>,
+                    click_effect_transition_in_system::<BoxShadows>,
                     click_effect_transition_out_system::<Position>,
                     click_effect_transition_out_system::<Size>,
                     click_effect_transition_out_system::<ColoredElement>,
+                    click_effect_transition_out_system::<Fill>,
                     click_effect_transition_out_system::<CornersRoundness>,
                     click_effect_transition_out_system::<FontSize>,
+                    click_effect_transition_out_system::<BoxShadow>,
+                    click_effect_transition_out_system::<BoxShadows>,
                 ),
             );
     }
@@ -0,0 +1,115 @@
+use bevy::{
+    prelude::{
+        Added, Commands, Component, Entity, EventWriter, Plugin, Query, ReflectComponent,
+        RemovedComponents, Update, With,
+    },
+    reflect::Reflect,
+};
+
+use crate::{
+    event::{ElementHovered, ElementPressed, HoverExitEvent, ReleaseEvent},
+    prelude::*,
+};
+
+use super::{AppComponentStateExt, ComponentState};
+
+pub struct UiDisabledStatePlugin;
+
+impl Plugin for UiDisabledStatePlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_component_state::<DisabledState, Position>(())
+            .add_component_state::<DisabledState, Size>(())
+            .add_component_state::<DisabledState, ColoredElement>(())
+            .add_component_state::<DisabledState, Fill>(())
+            .add_component_state::<DisabledState, CornersRoundness>(())
+            .add_component_state::<DisabledState, FontSize>(())
+            .add_component_state::<DisabledState, BoxShadow>(())
+            .add_component_state::<DisabledState, BoxShadows>(())
+            .add_systems(Update, release_disabled_elements)
+            .add_systems(
+                Update,
+                (
+                    disabled_effect_system::<Position>,
+                    disabled_effect_system::<Size>,
+                    disabled_effect_system::<ColoredElement>,
+                    disabled_effect_system::<Fill>,
+                    disabled_effect_system::<CornersRoundness>,
+                    disabled_effect_system::<FontSize>,
+                    disabled_effect_system::<BoxShadow>,
+                    disabled_effect_system::<BoxShadows>,
+                    disabled_effect_clear_system::<Position>,
+                    disabled_effect_clear_system::<Size>,
+                    disabled_effect_clear_system::<ColoredElement>,
+                    disabled_effect_clear_system::<Fill>,
+                    disabled_effect_clear_system::<CornersRoundness>,
+                    disabled_effect_clear_system::<FontSize>,
+                    disabled_effect_clear_system::<BoxShadow>,
+                    disabled_effect_clear_system::<BoxShadows>,
+                ),
+            );
+    }
+}
+
+// Disabled elements don't get hovered, pressed or clicked
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Disabled;
+
+#[derive(Clone)]
+pub struct DisabledState;
+
+pub type DisabledValue<T> = ComponentState<DisabledState, T>;
+
+#[derive(Component)]
+pub struct DisabledEffect<T: Component + Clone> {
+    pub value: T,
+}
+
+impl<T: Component + Clone> DisabledEffect<T> {
+    pub fn new(value: T) -> DisabledEffect<T> {
+        DisabledEffect { value }
+    }
+}
+
+pub fn disabled_effect_system<T: Component + Clone>(
+    mut commands: Commands,
+    effects: Query<(Entity, &DisabledEffect<T>), Added<Disabled>>,
+) {
+    for (entity, effect) in effects.iter() {
+        commands
+            .entity(entity)
+            .insert(DisabledValue::new(effect.value.clone()));
+    }
+}
+
+pub fn disabled_effect_clear_system<T: Component + Clone>(
+    mut commands: Commands,
+    mut enabled_elements: RemovedComponents<Disabled>,
+    effects: Query<(), (With<DisabledEffect<T>>, With<DisabledValue<T>>)>,
+) {
+    for entity in enabled_elements.read() {
+        if effects.contains(entity) {
+            commands.entity(entity).remove::<DisabledValue<T>>();
+        }
+    }
+}
+
+// Elements disabled while hovered or pressed leave these states as if the cursor left them
+fn release_disabled_elements(
+    mut commands: Commands,
+    elements: Query<(Entity, Option<&ElementHovered>, Option<&ElementPressed>), Added<Disabled>>,
+    mut hover_exit_events: EventWriter<HoverExitEvent>,
+    mut release_events: EventWriter<ReleaseEvent>,
+) {
+    for (entity, hovered, pressed) in elements.iter() {
+        if hovered.is_some() {
+            commands.entity(entity).remove::<ElementHovered>();
+            hover_exit_events.send(HoverExitEvent { element: entity });
+        }
+
+        if pressed.is_some() {
+            commands.entity(entity).remove::<ElementPressed>();
+            release_events.send(ReleaseEvent { element: entity });
+        }
+    }
+}
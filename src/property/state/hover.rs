@@ -20,21 +20,30 @@ impl Plugin for UiHoverStatePlugin {
         app.add_component_state::<HoverState, Position>(())
             .add_component_state::<HoverState, Size>(())
             .add_component_state::<HoverState, ColoredElement>(())
+            .add_component_state::<HoverState, Fill>(())
             .add_component_state::<HoverState, CornersRoundness>(())
             .add_component_state::<HoverState, FontSize>(())
+            .add_component_state::<HoverState, BoxShadow>(())
+            .add_component_state::<HoverState, BoxShadows>(())
             .add_systems(
                 Update,
                 (
                     transition_system::<Hovered<Position>>,
                     transition_system::<Hovered<Size>>,
                     transition_system::<Hovered<ColoredElement>>,
+                    transition_system::<Hovered<Fill>>,
                     transition_system::<Hovered<CornersRoundness>>,
                     transition_system::<Hovered<FontSize>>,
+                    transition_system::<Hovered<BoxShadow>>,
+                    transition_system::<Hovered<BoxShadows>>,
                     remove_system::<Hovered<Position>>,
                     remove_system::<Hovered<Size>>,
                     remove_system::<Hovered<ColoredElement>>,
+                    remove_system::<Hovered<Fill>>,
                     remove_system::<Hovered<CornersRoundness>>,
                     remove_system::<Hovered<FontSize>>,
+                    remove_system::<Hovered<BoxShadow>>,
+                    remove_system::<Hovered<BoxShadows>>,
                 ),
             )
             .add_systems(
@@ -43,13 +52,19 @@ impl Plugin for UiHoverStatePlugin {
                     hover_effect_system::<Position>,
                     hover_effect_system::<Size>,
                     hover_effect_system::<ColoredElement>,
+                    hover_effect_system::<Fill>,
                     hover_effect_system::<CornersRoundness>,
                     hover_effect_system::<FontSize>,
+                    hover_effect_system::<BoxShadow>,
+                    hover_effect_system::<BoxShadows>,
                     hover_effect_clear_system::<Position>,
                     hover_effect_clear_system::<Size>,
                     hover_effect_clear_system::<ColoredElement>,
+                    hover_effect_clear_system::<Fill>,
                     hover_effect_clear_system::<CornersRoundness>,
                     hover_effect_clear_system::<FontSize>,
+                    hover_effect_clear_system::<BoxShadow>,
+                    hover_effect_clear_system::<BoxShadows>,
                 ),
             )
             .add_systems(
@@ -58,13 +73,19 @@ impl Plugin for UiHoverStatePlugin {
                     hover_effect_transition_in_system::<Position>,
                     hover_effect_transition_in_system::<Size>,
                     hover_effect_transition_in_system::<ColoredElement>,
+                    hover_effect_transition_in_system::<Fill>,
                     hover_effect_transition_in_system::<CornersRoundness>,
                     hover_effect_transition_in_system::<FontSize>,
+                    hover_effect_transition_in_system::<BoxShadow>,
+                    hover_effect_transition_in_system::<BoxShadows>,
                     hover_effect_transition_out_system::<Position>,
                     hover_effect_transition_out_system::<Size>,
                     hover_effect_transition_out_system::<ColoredElement>,
+                    hover_effect_transition_out_system::<Fill>,
                     hover_effect_transition_out_system::<CornersRoundness>,
                     hover_effect_transition_out_system::<FontSize>,
+                    hover_effect_transition_out_system::<BoxShadow>,
+                    hover_effect_transition_out_system::<BoxShadows>,
                 ),
             );
     }
@@ -1,4 +1,6 @@
+pub mod checked;
 pub mod click;
+pub mod disabled;
 pub mod hover;
 
 use std::{
@@ -276,6 +278,123 @@ unsafe impl<T: Component + Clone> WorldQuery for &CurrentlyActive<T> {
     }
 }
 
+// Whether the value CurrentlyActive<T> returns changed since the system last ran: the base value, the active state's value or which state is active
+pub struct ActiveChanged<T: Component + Clone>(PhantomData<T>);
+
+unsafe impl<T: Component + Clone> ReadOnlyWorldQuery for ActiveChanged<T> {}
+
+unsafe impl<T: Component + Clone> WorldQuery for ActiveChanged<T> {
+    type Fetch<'w> = (
+        &'w World,
+        ComponentId,
+        bevy::ecs::component::Tick,
+        bevy::ecs::component::Tick,
+    );
+    type Item<'a> = bool;
+    type ReadOnly = Self;
+    type State = ComponentId;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    const IS_DENSE: bool = true;
+    const IS_ARCHETYPAL: bool = false;
+
+    unsafe fn init_fetch<'w>(
+        world: bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: bevy::ecs::component::Tick,
+        this_run: bevy::ecs::component::Tick,
+    ) -> Self::Fetch<'w> {
+        (world.world(), *state, last_run, this_run)
+    }
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _state: &Self::State,
+        _archetype: &'w bevy::ecs::archetype::Archetype,
+        _table: &'w bevy::ecs::storage::Table,
+    ) {
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _state: &Self::State,
+        _table: &'w bevy::ecs::storage::Table,
+    ) {
+    }
+
+    #[inline]
+    fn update_archetype_component_access(
+        _state: &Self::State,
+        _archetype: &bevy::ecs::archetype::Archetype,
+        access: &mut bevy::ecs::query::Access<bevy::ecs::archetype::ArchetypeComponentId>,
+    ) {
+        access.read_all();
+    }
+
+    unsafe fn fetch<'w>(
+        (world, base_component_id, last_run, this_run): &mut Self::Fetch<'w>,
+        entity: Entity,
+        _table_row: bevy::ecs::storage::TableRow,
+    ) -> Self::Item<'w> {
+        let entity_ref = world.entity(entity);
+        let changed = |component_id| {
+            entity_ref
+                .get_change_ticks_by_id(component_id)
+                .is_some_and(|ticks| ticks.is_changed(*last_run, *this_run))
+        };
+
+        if changed(*base_component_id) {
+            return true;
+        }
+
+        // Active<T> is changed whenever a state gets activated or deactivated
+        let Some(active_component_id) = world.component_id::<Active<T>>() else {
+            return false;
+        };
+
+        if changed(active_component_id) {
+            return true;
+        }
+
+        entity_ref
+            .get::<Active<T>>()
+            .and_then(|active| active.component_ids.last().copied())
+            .is_some_and(changed)
+    }
+
+    #[inline]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: bevy::ecs::storage::TableRow,
+    ) -> bool {
+        Self::fetch(fetch, entity, table_row)
+    }
+
+    fn update_component_access(
+        _state: &Self::State,
+        access: &mut bevy::ecs::query::FilteredAccess<ComponentId>,
+    ) {
+        access.read_all();
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        world.init_component::<T>()
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        set_contains_id(*state)
+    }
+}
+
 pub trait ActiveOptionExt<T: Component + Clone> {
     fn is_active_state<C: Component>(&self, components: &Components) -> bool;
     fn active_or_base<'a>(&self, world: &World, entity_ref: &'a EntityRef, base: &'a T) -> &'a T;
@@ -1,6 +1,12 @@
 use std::time::Duration;
 
-use crate::{element::text::FontSize, property::*};
+use crate::{
+    element::{
+        shadow::{BoxShadow, BoxShadows},
+        text::FontSize,
+    },
+    property::{fill::Fill, *},
+};
 use bevy::{
     prelude::{
         Commands, Component, Entity, EventWriter, Plugin, PostUpdate, Query, Res, Vec2, Vec4,
@@ -21,8 +27,11 @@ impl Plugin for UiTransitionPlugin {
                 transition_system::<Position>,
                 transition_system::<Size>,
                 transition_system::<ColoredElement>,
+                transition_system::<Fill>,
                 transition_system::<CornersRoundness>,
                 transition_system::<FontSize>,
+                transition_system::<BoxShadow>,
+                transition_system::<BoxShadows>,
             ),
         );
     }
@@ -119,6 +128,12 @@ impl PropertyTransition<ColoredElement> for ColoredElement {
     }
 }
 
+impl PropertyTransition<Fill> for Fill {
+    fn transition<'a>(progress: f32, from: &'a Fill, to: &'a Fill) -> Fill {
+        Fill::interpolate(progress, from, to)
+    }
+}
+
 impl PropertyTransition<CornersRoundness> for CornersRoundness {
     fn transition<'a>(
         progress: f32,
@@ -142,6 +157,38 @@ impl PropertyTransition<FontSize> for FontSize {
     }
 }
 
+impl PropertyTransition<BoxShadow> for BoxShadow {
+    fn transition<'a>(progress: f32, from: &'a BoxShadow, to: &'a BoxShadow) -> BoxShadow {
+        BoxShadow {
+            offset: from.offset.lerp(to.offset, progress),
+            blur_radius: from.blur_radius + (to.blur_radius - from.blur_radius) * progress,
+            spread: from.spread + (to.spread - from.spread) * progress,
+            color: Color::rgba_from_array(
+                from.color
+                    .rgba_to_vec4()
+                    .lerp(to.color.rgba_to_vec4(), progress),
+            ),
+            inset: to.inset,
+        }
+    }
+}
+
+impl PropertyTransition<BoxShadows> for BoxShadows {
+    fn transition<'a>(progress: f32, from: &'a BoxShadows, to: &'a BoxShadows) -> BoxShadows {
+        if from.0.len() != to.0.len() {
+            return to.clone();
+        }
+
+        BoxShadows(
+            from.0
+                .iter()
+                .zip(to.0.iter())
+                .map(|(from, to)| BoxShadow::transition(progress, from, to))
+                .collect(),
+        )
+    }
+}
+
 impl<S: Send + Sync + 'static, T: Component + Clone + PropertyTransition<T>>
     PropertyTransition<ComponentState<S, T>> for ComponentState<S, T>
 {
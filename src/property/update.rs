@@ -1,16 +1,19 @@
 use bevy::{
-    prelude::{Component, Entity, Parent, Plugin, Query, ReflectComponent, Update, With},
+    ecs::query::Has,
+    prelude::{
+        Added, Changed, Children, Commands, Component, Entity, Or, ParamSet, Parent, Plugin, Query,
+        ReflectComponent, RemovedComponents, Update, With, Without,
+    },
     reflect::Reflect,
-    utils::HashMap,
 };
 
-use super::{Position, Size, VisibleRegion, ZLevel};
+use super::{Position, Size, VisibleRegion, ZIndex, ZLevel};
 
 pub struct UiUpdatePropertiesPlugin;
 
 impl Plugin for UiUpdatePropertiesPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_systems(Update, (update_z, update_visible_region));
+        app.add_systems(Update, (update_z, update_visible_region, update_tree_order));
     }
 }
 
@@ -18,72 +21,266 @@ impl Plugin for UiUpdatePropertiesPlugin {
 #[reflect(Component)]
 pub struct AutoZUpdate;
 
-fn update_z(mut z_levels: Query<(Entity, &mut ZLevel, Option<(&AutoZUpdate, &Parent)>)>) {
-    // TODO: Use events to update ZLevel instead of updating always
+type ZLevelQueryData = (
+    &'static mut ZLevel,
+    Option<&'static Parent>,
+    Option<&'static ZIndex>,
+    Has<AutoZUpdate>,
+);
 
-    let mut z_levels_map = HashMap::new();
+fn update_z(
+    mut z_levels: ParamSet<(
+        Query<
+            Entity,
+            (
+                With<ZLevel>,
+                Or<(
+                    Changed<ZLevel>,
+                    Changed<Parent>,
+                    Changed<ZIndex>,
+                    Added<AutoZUpdate>,
+                )>,
+            ),
+        >,
+        Query<ZLevelQueryData>,
+    )>,
+    children: Query<&Children>,
+    mut removed_z_indices: RemovedComponents<ZIndex>,
+    mut removed_parents: RemovedComponents<Parent>,
+) {
+    let changed_entities = z_levels
+        .p0()
+        .iter()
+        .chain(removed_z_indices.read())
+        .chain(removed_parents.read())
+        .collect::<Vec<_>>();
 
-    for (entity, z_level, _) in z_levels.iter() {
-        z_levels_map.insert(entity, z_level.0);
+    if changed_entities.is_empty() {
+        return;
     }
 
-    for (_, mut z_level, auto_update_data) in z_levels.iter_mut() {
-        if let Some((_, parent)) = auto_update_data {
-            if let Some(parent_z_level) = z_levels_map.get(&parent.get()) {
-                let new_z_level = *parent_z_level + 1;
+    let mut z_levels = z_levels.p1();
+    let mut pending_entities = Vec::with_capacity(changed_entities.len());
 
+    for entity in changed_entities {
+        if let Some(new_z_level) = auto_z_level(&z_levels, entity) {
+            if let Ok((mut z_level, _, _, _)) = z_levels.get_mut(entity) {
                 if z_level.0 != new_z_level {
                     z_level.0 = new_z_level;
                 }
             }
         }
+
+        pending_entities.push(entity);
+    }
+
+    // Walking down the hierarchy here lets deep descendants settle within the same frame
+    while let Some(entity) = pending_entities.pop() {
+        let Ok(entity_children) = children.get(entity) else {
+            continue;
+        };
+
+        for child in entity_children.iter() {
+            let Some(new_z_level) = auto_z_level(&z_levels, *child) else {
+                continue;
+            };
+
+            if let Ok((mut child_z_level, _, _, _)) = z_levels.get_mut(*child) {
+                if child_z_level.0 != new_z_level {
+                    child_z_level.0 = new_z_level;
+                    pending_entities.push(*child);
+                }
+            }
+        }
     }
 }
 
+// The ZLevel an element with AutoZUpdate should have, None when it's managed manually
+fn auto_z_level(z_levels: &Query<ZLevelQueryData>, entity: Entity) -> Option<u32> {
+    let Ok((_, parent, z_index, true)) = z_levels.get(entity) else {
+        return None;
+    };
+
+    let parent_z_level = parent
+        .and_then(|parent| z_levels.get(parent.get()).ok())
+        .map(|(parent_z_level, _, _, _)| parent_z_level.0);
+
+    z_index.copied().unwrap_or_default().z_level(parent_z_level)
+}
+
 #[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
 #[reflect(Component)]
 pub struct AutoVisibleRegionUpdate;
 
+// Overlays (popups, tooltips, ...) aren't clipped by their parent and can cover the whole window
+// They're usually given a ZIndex::Global so they're also painted over the rest of the UI
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Overlay;
+
+type VisibleRegionQueryData = (
+    &'static Position,
+    &'static Size,
+    Option<&'static mut VisibleRegion>,
+    Option<&'static Parent>,
+    Has<Overlay>,
+    Has<AutoVisibleRegionUpdate>,
+);
+
+// Elements are clipped to their parent's rect, within the region the parent itself is clipped to
 fn update_visible_region(
-    mut visible_regions: Query<
-        (&mut VisibleRegion, Option<&Parent>),
-        With<AutoVisibleRegionUpdate>,
+    mut elements: ParamSet<(
+        Query<&Children, Or<(Changed<Position>, Changed<Size>, Changed<VisibleRegion>)>>,
+        Query<VisibleRegionQueryData>,
+    )>,
+    reparented: Query<
+        Entity,
+        (
+            With<AutoVisibleRegionUpdate>,
+            Or<(
+                Changed<Parent>,
+                Added<AutoVisibleRegionUpdate>,
+                Added<Overlay>,
+            )>,
+        ),
     >,
-    regions: Query<(&Position, &Size)>,
+    children: Query<&Children>,
+    mut removed_parents: RemovedComponents<Parent>,
 ) {
-    // TODO: Use events & filters to update VisibleRegion instead of updating always
+    let mut pending_entities = reparented
+        .iter()
+        .chain(removed_parents.read())
+        .chain(elements.p0().iter().flatten().copied())
+        .collect::<Vec<_>>();
 
-    for (mut visible_region, parent) in visible_regions.iter_mut() {
-        let new_visible_region = if let Some(parent) = parent {
-            if let Ok((parent_position, parent_size)) = regions.get(parent.get()) {
-                VisibleRegion {
-                    x: parent_position.x,
-                    y: parent_position.y,
-
-                    width: parent_size.width,
-                    height: parent_size.height,
-                }
-            } else {
-                VisibleRegion {
-                    x: 0,
-                    y: 0,
+    let mut elements = elements.p1();
 
-                    width: u32::MAX,
-                    height: u32::MAX,
-                }
-            }
-        } else {
-            VisibleRegion {
-                x: 0,
-                y: 0,
+    // Walking down the hierarchy here lets deep descendants settle within the same frame
+    while let Some(entity) = pending_entities.pop() {
+        let Some(new_visible_region) = auto_visible_region(&elements, entity) else {
+            continue;
+        };
 
-                width: u32::MAX,
-                height: u32::MAX,
-            }
+        let Ok((_, _, Some(mut visible_region), _, _, _)) = elements.get_mut(entity) else {
+            continue;
         };
 
         if *visible_region != new_visible_region {
             *visible_region = new_visible_region;
+
+            if let Ok(entity_children) = children.get(entity) {
+                pending_entities.extend(entity_children.iter());
+            }
+        }
+    }
+}
+
+// The VisibleRegion an element with AutoVisibleRegionUpdate should have, None when it's managed manually
+fn auto_visible_region(
+    elements: &Query<VisibleRegionQueryData>,
+    entity: Entity,
+) -> Option<VisibleRegion> {
+    let Ok((_, _, Some(_), parent, is_overlay, true)) = elements.get(entity) else {
+        return None;
+    };
+
+    let parent = parent
+        .filter(|_| !is_overlay)
+        .and_then(|parent| elements.get(parent.get()).ok());
+
+    let Some((parent_position, parent_size, parent_visible_region, _, _, _)) = parent else {
+        return Some(VisibleRegion {
+            x: 0,
+            y: 0,
+
+            width: u32::MAX,
+            height: u32::MAX,
+        });
+    };
+
+    let parent_rect = parent_rect(parent_position, parent_size);
+
+    Some(match parent_visible_region {
+        Some(parent_visible_region) => intersect_regions(&parent_rect, parent_visible_region),
+        None => parent_rect,
+    })
+}
+
+fn parent_rect(parent_position: &Position, parent_size: &Size) -> VisibleRegion {
+    VisibleRegion {
+        x: parent_position.x,
+        y: parent_position.y,
+
+        width: parent_size.width,
+        height: parent_size.height,
+    }
+}
+
+fn intersect_regions(a: &VisibleRegion, b: &VisibleRegion) -> VisibleRegion {
+    let (a_end_x, a_end_y) = (a.x as u64 + a.width as u64, a.y as u64 + a.height as u64);
+    let (b_end_x, b_end_y) = (b.x as u64 + b.width as u64, b.y as u64 + b.height as u64);
+
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+
+    VisibleRegion {
+        x,
+        y,
+
+        width: a_end_x.min(b_end_x).saturating_sub(x as u64) as u32,
+        height: a_end_y.min(b_end_y).saturating_sub(y as u64) as u32,
+    }
+}
+
+// Depth-first position of the element in the hierarchy, elements later in the tree are painted over earlier ones on the same z level
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Reflect)]
+#[reflect(Component)]
+pub struct TreeOrder(pub u32);
+
+fn update_tree_order(
+    mut commands: Commands,
+    changed_hierarchy: Query<(), Or<(Changed<Children>, Changed<Parent>, Added<ZLevel>)>>,
+    mut removed_parents: RemovedComponents<Parent>,
+    mut removed_z_levels: RemovedComponents<ZLevel>,
+    roots: Query<Entity, Without<Parent>>,
+    children: Query<&Children>,
+    mut tree_orders: Query<Option<&mut TreeOrder>, With<ZLevel>>,
+) {
+    let removed_count = removed_parents.read().count() + removed_z_levels.read().count();
+
+    if changed_hierarchy.is_empty() && removed_count == 0 {
+        return;
+    }
+
+    let mut roots = roots.iter().collect::<Vec<_>>();
+    roots.sort_unstable_by_key(|root| root.index());
+
+    let mut next_tree_order = 0;
+    let mut pending_entities = Vec::new();
+
+    for root in roots {
+        pending_entities.push(root);
+
+        while let Some(entity) = pending_entities.pop() {
+            match tree_orders.get_mut(entity) {
+                Ok(Some(mut tree_order)) => {
+                    if tree_order.0 != next_tree_order {
+                        tree_order.0 = next_tree_order;
+                    }
+
+                    next_tree_order += 1;
+                }
+                Ok(None) => {
+                    commands.entity(entity).insert(TreeOrder(next_tree_order));
+                    next_tree_order += 1;
+                }
+                Err(_) => {}
+            }
+
+            // Reversed so the first child is visited first
+            if let Ok(entity_children) = children.get(entity) {
+                pending_entities.extend(entity_children.iter().rev());
+            }
         }
     }
 }
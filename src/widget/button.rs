@@ -0,0 +1,273 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component, Entity, Event,
+        EventReader, EventWriter, Has, Input, KeyCode, Plugin, Query, ReflectComponent,
+        RemovedComponents, Res, Update, With, Without,
+    },
+    reflect::Reflect,
+};
+
+use crate::{
+    element::{
+        r#box::UiBox,
+        text::{FontSize, TextAlign, UiText, UiTextBundle, VerticalAlign},
+    },
+    event::ClickEvent,
+    property::{
+        collision::BoxCollisionBundle,
+        focus::{Focusable, Focused},
+        state::{
+            click::ClickEffectTransition,
+            disabled::{Disabled, DisabledEffect},
+            hover::HoverEffectTransition,
+        },
+        update::{AutoVisibleRegionUpdate, AutoZUpdate},
+        ColoredElement, CornersRoundness, Position, Size, VisibleRegion, ZLevel,
+    },
+    widget::WidgetPart,
+};
+
+pub(crate) struct UiButtonPlugin;
+
+impl Plugin for UiButtonPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<ButtonActivated>().add_systems(
+            Update,
+            (
+                spawn_button_labels,
+                update_button_labels,
+                disable_button_labels,
+                activate_buttons,
+            ),
+        );
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct UiButton;
+
+// Drawn centered on the button by a child text element that follows the button's position & size
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ButtonLabel {
+    pub text: String,
+    pub font_size: FontSize,
+    pub color: Color,
+    pub disabled_color: Color,
+}
+
+impl ButtonLabel {
+    pub fn new(text: impl Into<String>) -> ButtonLabel {
+        ButtonLabel {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for ButtonLabel {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            font_size: FontSize(24),
+            color: Color::BLACK,
+            disabled_color: Color::DARK_GRAY,
+        }
+    }
+}
+
+// Sent when the button gets clicked, or when Enter / Space gets pressed while it's focused
+#[derive(Event)]
+pub struct ButtonActivated {
+    pub button: Entity,
+}
+
+#[derive(Bundle)]
+pub struct UiButtonBundle {
+    pub button: UiButton,
+    pub label: ButtonLabel,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+    pub corners_roundness: CornersRoundness,
+
+    pub hover_effect: HoverEffectTransition<ColoredElement>,
+    pub click_effect: ClickEffectTransition<ColoredElement>,
+    pub disabled_effect: DisabledEffect<ColoredElement>,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl UiButtonBundle {
+    pub fn new(label: impl Into<String>) -> UiButtonBundle {
+        UiButtonBundle {
+            label: ButtonLabel::new(label),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for UiButtonBundle {
+    fn default() -> Self {
+        Self {
+            button: Default::default(),
+            label: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(200, 60),
+            color: ColoredElement::new(Color::GRAY),
+            corners_roundness: CornersRoundness::from_scalar(0.3),
+
+            hover_effect: HoverEffectTransition::new(
+                ColoredElement::new(Color::DARK_GRAY),
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+            ),
+            click_effect: ClickEffectTransition::new(
+                ColoredElement::new(Color::DARK_GREEN),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+            ),
+            disabled_effect: DisabledEffect::new(ColoredElement::new(Color::SILVER)),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+#[derive(Component)]
+struct ButtonLabelText;
+
+fn button_label_layout(position: &Position, size: &Size) -> (Position, Size) {
+    (position.clone(), size.clone())
+}
+
+fn spawn_button_labels(
+    mut commands: Commands,
+    buttons: Query<(Entity, &ButtonLabel, &Position, &Size, Has<Disabled>), Added<UiButton>>,
+) {
+    for (entity, label, position, size, disabled) in buttons.iter() {
+        let (label_position, label_size) = button_label_layout(position, size);
+
+        let mut label_text = commands.spawn((
+            UiTextBundle {
+                text: UiText(label.text.clone()),
+                font_size: label.font_size.clone(),
+                color: ColoredElement::new(label.color),
+                position: label_position,
+                size: label_size,
+                ..Default::default()
+            },
+            TextAlign::Center,
+            VerticalAlign::Center,
+            DisabledEffect::new(ColoredElement::new(label.disabled_color)),
+            WidgetPart(button_label_layout),
+            ButtonLabelText,
+        ));
+
+        if disabled {
+            label_text.insert(Disabled);
+        }
+
+        let label_text = label_text.id();
+        commands.entity(entity).add_child(label_text);
+    }
+}
+
+fn update_button_labels(
+    buttons: Query<(&ButtonLabel, &Children), (With<UiButton>, Changed<ButtonLabel>)>,
+    mut labels: Query<
+        (
+            &mut UiText,
+            &mut FontSize,
+            &mut ColoredElement,
+            &mut DisabledEffect<ColoredElement>,
+        ),
+        With<ButtonLabelText>,
+    >,
+) {
+    for (label, children) in buttons.iter() {
+        let mut label_texts = labels.iter_many_mut(children);
+
+        while let Some((mut text, mut font_size, mut color, mut disabled_effect)) =
+            label_texts.fetch_next()
+        {
+            if text.0 != label.text {
+                text.0 = label.text.clone();
+            }
+
+            if *font_size != label.font_size {
+                *font_size = label.font_size.clone();
+            }
+
+            if color.color != label.color {
+                color.color = label.color;
+            }
+
+            disabled_effect.value = ColoredElement::new(label.disabled_color);
+        }
+    }
+}
+
+// The label is disabled along with its button so it switches to its disabled color
+fn disable_button_labels(
+    mut commands: Commands,
+    disabled_buttons: Query<&Children, (With<UiButton>, Added<Disabled>)>,
+    enabled_buttons: Query<&Children, (With<UiButton>, Without<Disabled>)>,
+    mut removed_disabled: RemovedComponents<Disabled>,
+    labels: Query<Entity, With<ButtonLabelText>>,
+) {
+    for children in disabled_buttons.iter() {
+        for label in labels.iter_many(children) {
+            commands.entity(label).insert(Disabled);
+        }
+    }
+
+    for children in enabled_buttons.iter_many(removed_disabled.read()) {
+        for label in labels.iter_many(children) {
+            commands.entity(label).remove::<Disabled>();
+        }
+    }
+}
+
+fn activate_buttons(
+    keys: Res<Input<KeyCode>>,
+    buttons: Query<(), (With<UiButton>, Without<Disabled>)>,
+    focused_buttons: Query<Entity, (With<UiButton>, With<Focused>, Without<Disabled>)>,
+    mut click_events: EventReader<ClickEvent>,
+    mut activated_events: EventWriter<ButtonActivated>,
+) {
+    for click_event in click_events.read() {
+        if buttons.contains(click_event.element) {
+            activated_events.send(ButtonActivated {
+                button: click_event.element,
+            });
+        }
+    }
+
+    if keys.any_just_pressed([KeyCode::Return, KeyCode::NumpadEnter, KeyCode::Space]) {
+        for button in focused_buttons.iter() {
+            activated_events.send(ButtonActivated { button });
+        }
+    }
+}
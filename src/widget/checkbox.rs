@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{
+        Added, BuildChildren, Bundle, Color, Commands, Component, Entity, Plugin, Query,
+        ReflectComponent, Update,
+    },
+    reflect::Reflect,
+};
+
+use crate::{
+    element::r#box::{UiBox, UiBoxBundle},
+    property::{
+        collision::BoxCollisionBundle,
+        focus::Focusable,
+        state::{
+            checked::{Checked, CheckedEffectTransition},
+            disabled::DisabledEffect,
+        },
+        update::{AutoVisibleRegionUpdate, AutoZUpdate},
+        ColoredElement, CornersRoundness, Position, Size, VisibleRegion, ZLevel,
+    },
+    widget::{CheckedPart, WidgetPart, ACCENT_COLOR},
+};
+
+pub(crate) struct UiCheckboxPlugin;
+
+impl Plugin for UiCheckboxPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(Update, spawn_checkbox_marks);
+    }
+}
+
+pub(crate) const CHECKED_TRANSITION: Duration = Duration::from_millis(150);
+
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Checkbox;
+
+#[derive(Bundle)]
+pub struct UiCheckboxBundle {
+    pub checkbox: Checkbox,
+    pub checked: Checked,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+    pub corners_roundness: CornersRoundness,
+
+    pub checked_effect: CheckedEffectTransition<ColoredElement>,
+    pub disabled_effect: DisabledEffect<ColoredElement>,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl Default for UiCheckboxBundle {
+    fn default() -> Self {
+        Self {
+            checkbox: Default::default(),
+            checked: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(24, 24),
+            color: ColoredElement::new(Color::GRAY),
+            corners_roundness: CornersRoundness::from_scalar(0.2),
+
+            checked_effect: CheckedEffectTransition::new(
+                ColoredElement::new(ACCENT_COLOR),
+                CHECKED_TRANSITION,
+                CHECKED_TRANSITION,
+            ),
+            disabled_effect: DisabledEffect::new(ColoredElement::new(Color::SILVER)),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+fn checkbox_mark_layout(position: &Position, size: &Size) -> (Position, Size) {
+    let inset = size.width.min(size.height) / 4;
+
+    (
+        Position::new(position.x + inset, position.y + inset),
+        Size::new(
+            size.width.saturating_sub(inset * 2),
+            size.height.saturating_sub(inset * 2),
+        ),
+    )
+}
+
+// The mark fades in over the checkbox when it gets checked
+fn spawn_checkbox_marks(
+    mut commands: Commands,
+    checkboxes: Query<(Entity, &Checked, &Position, &Size), Added<Checkbox>>,
+) {
+    for (entity, checked, position, size) in checkboxes.iter() {
+        let (mark_position, mark_size) = checkbox_mark_layout(position, size);
+
+        let mark = commands
+            .spawn((
+                UiBoxBundle {
+                    position: mark_position,
+                    size: mark_size,
+                    color: ColoredElement::new(Color::NONE),
+                    ..Default::default()
+                },
+                CornersRoundness::from_scalar(0.2),
+                *checked,
+                CheckedEffectTransition::new(
+                    ColoredElement::new(Color::WHITE),
+                    CHECKED_TRANSITION,
+                    CHECKED_TRANSITION,
+                ),
+                WidgetPart(checkbox_mark_layout),
+                CheckedPart,
+            ))
+            .id();
+
+        commands.entity(entity).add_child(mark);
+    }
+}
@@ -0,0 +1,1070 @@
+use std::cmp::Ordering;
+
+use bevy::{
+    log::warn,
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component,
+        DespawnRecursiveExt, DetectChanges, Entity, Event, EventReader, EventWriter, Has, Input,
+        IntoSystemConfigs, KeyCode, MouseButton, Mut, Or, Plugin, Query, Ref, ReflectComponent,
+        Res, Update, With, Without,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    element::{
+        font::FontWeight,
+        r#box::{UiBox, UiBoxBundle},
+        text::{FontSize, TextAlign, TextOverflow, UiText, UiTextBundle, VerticalAlign},
+    },
+    event::{on_mouse_click_start, ClickEvent, PressEvent},
+    property::{
+        collision::BoxCollisionBundle,
+        focus::{Focusable, Focused},
+        state::{disabled::Disabled, hover::HoverEffect},
+        update::{AutoVisibleRegionUpdate, AutoZUpdate},
+        ColoredElement, Position, Size, VisibleRegion, ZLevel,
+    },
+    widget::{
+        virtual_list::{RowHeight, UiVirtualListBundle, VirtualList, VirtualListRowBuilder},
+        WidgetPart, ACCENT_COLOR,
+    },
+};
+
+pub(crate) struct UiDataTablePlugin;
+
+impl Plugin for UiDataTablePlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<DataTableSortChangedEvent>()
+            .add_event::<DataTableSelectionChangedEvent>()
+            .add_event::<DataTableColumnResizedEvent>()
+            .add_systems(
+                Update,
+                (
+                    spawn_data_table_parts,
+                    (
+                        interact_with_data_tables.after(on_mouse_click_start),
+                        sort_data_tables,
+                        layout_data_table_headers,
+                        fill_data_table_rows,
+                    )
+                        .chain(),
+                ),
+            );
+    }
+}
+
+const HEADER_HEIGHT: u32 = 32;
+const CELL_PADDING: u32 = 8;
+// Width of the grabbable area around the boundary on the right of each column
+const RESIZE_HANDLE_WIDTH: u32 = 6;
+
+const HEADER_COLOR: Color = Color::rgb(0.93, 0.93, 0.95);
+const ROW_COLOR: Color = Color::WHITE;
+const ALTERNATE_ROW_COLOR: Color = Color::rgb(0.96, 0.96, 0.98);
+const SELECTED_ROW_COLOR: Color = Color::rgb(0.8, 0.86, 1.0);
+
+// Rows of text under a header, only the rows in view get entities (see VirtualList)
+// Clicking a sortable column's header sorts by it & dragging the boundary on its right resizes it
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct DataTable {
+    pub columns: Vec<DataColumn>,
+    // A row's cells in the order of the columns, missing cells are left empty
+    pub rows: Vec<Vec<String>>,
+    pub sort: Option<DataTableSort>,
+    // Indices into rows, which stay the same whatever the sort
+    pub selected: Vec<usize>,
+    pub row_height: u32,
+}
+
+impl DataTable {
+    pub fn new(columns: impl IntoIterator<Item = DataColumn>) -> DataTable {
+        DataTable {
+            columns: columns.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_rows(mut self, rows: Vec<Vec<String>>) -> DataTable {
+        self.rows = rows;
+        self
+    }
+
+    pub fn with_sort(mut self, column: usize, order: SortOrder) -> DataTable {
+        self.sort = Some(DataTableSort { column, order });
+        self
+    }
+}
+
+impl Default for DataTable {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            sort: None,
+            selected: Vec::new(),
+            row_height: 28,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct DataColumn {
+    pub title: String,
+    pub width: u32,
+    // Resizing doesn't go below it
+    pub min_width: u32,
+    pub alignment: TextAlign,
+    pub sortable: bool,
+}
+
+impl DataColumn {
+    pub fn new(title: impl Into<String>, width: u32) -> DataColumn {
+        DataColumn {
+            title: title.into(),
+            width,
+            min_width: 40,
+            alignment: TextAlign::Start,
+            sortable: true,
+        }
+    }
+
+    pub fn with_min_width(mut self, min_width: u32) -> DataColumn {
+        self.min_width = min_width;
+        self
+    }
+
+    pub fn with_alignment(mut self, alignment: TextAlign) -> DataColumn {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn with_sortable(mut self, sortable: bool) -> DataColumn {
+        self.sortable = sortable;
+        self
+    }
+}
+
+// Cells that are both numbers compare as numbers, others as case insensitive text
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+pub struct DataTableSort {
+    pub column: usize,
+    pub order: SortOrder,
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+// Sent when the user clicks a column's header, not when DataTable::sort gets changed by code
+#[derive(Event, Debug, Clone)]
+pub struct DataTableSortChangedEvent {
+    pub table: Entity,
+    pub sort: DataTableSort,
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct DataTableSelectionChangedEvent {
+    pub table: Entity,
+    pub selected: Vec<usize>,
+}
+
+// Sent while the user drags a column's boundary
+#[derive(Event, Debug, Clone)]
+pub struct DataTableColumnResizedEvent {
+    pub table: Entity,
+    pub column: usize,
+    pub width: u32,
+}
+
+#[derive(Bundle)]
+pub struct UiDataTableBundle {
+    pub table: DataTable,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl UiDataTableBundle {
+    pub fn new(table: DataTable) -> UiDataTableBundle {
+        UiDataTableBundle {
+            table,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for UiDataTableBundle {
+    fn default() -> Self {
+        Self {
+            table: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(600, 400),
+            color: ColoredElement::new(Color::WHITE),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+// Inserted along with the header & the body
+#[derive(Component)]
+struct DataTableState {
+    header: Entity,
+    body: Entity,
+    // The rows' indices in the order they're shown
+    order: Vec<usize>,
+    // The row Shift selections start from & the one the arrow keys move from
+    anchor: Option<usize>,
+    cursor: Option<usize>,
+    resize: Option<ColumnResize>,
+}
+
+struct ColumnResize {
+    column: usize,
+    start_x: f32,
+    start_width: u32,
+}
+
+#[derive(Component)]
+struct DataTableHeader;
+
+#[derive(Component)]
+struct DataTableHeaderCell {
+    table: Entity,
+    column: usize,
+    title: Entity,
+    indicator: Entity,
+}
+
+#[derive(Component)]
+struct DataTableResizeHandle {
+    table: Entity,
+    column: usize,
+}
+
+#[derive(Component)]
+struct DataTableBody;
+
+// Inserted by the body's row builder, index being the row's place in the shown order
+#[derive(Component)]
+struct DataTableRow {
+    table: Entity,
+    index: usize,
+}
+
+// Clips its text to the column
+#[derive(Component)]
+struct DataTableCell {
+    column: usize,
+    text: Entity,
+}
+
+fn header_layout(position: &Position, size: &Size) -> (Position, Size) {
+    (position.clone(), Size::new(size.width, HEADER_HEIGHT))
+}
+
+fn body_layout(position: &Position, size: &Size) -> (Position, Size) {
+    (
+        Position::new(position.x, position.y + HEADER_HEIGHT),
+        Size::new(size.width, size.height.saturating_sub(HEADER_HEIGHT)),
+    )
+}
+
+fn spawn_data_table_parts(
+    mut commands: Commands,
+    tables: Query<(Entity, &DataTable, &Position, &Size), Added<DataTable>>,
+) {
+    for (entity, table, position, size) in tables.iter() {
+        let (header_position, header_size) = header_layout(position, size);
+        let (body_position, body_size) = body_layout(position, size);
+
+        let header = commands
+            .spawn((
+                UiBoxBundle {
+                    position: header_position,
+                    size: header_size,
+                    color: ColoredElement::new(HEADER_COLOR),
+                    ..Default::default()
+                },
+                WidgetPart(header_layout),
+                DataTableHeader,
+            ))
+            .id();
+
+        let body = commands
+            .spawn((
+                UiVirtualListBundle {
+                    position: body_position,
+                    size: body_size,
+                    ..UiVirtualListBundle::new(
+                        VirtualList::new(table.rows.len(), RowHeight::Fixed(table.row_height)),
+                        VirtualListRowBuilder::new(move |row_commands, row| {
+                            row_commands.insert((
+                                DataTableRow {
+                                    table: entity,
+                                    index: row.index,
+                                },
+                                BoxCollisionBundle::default(),
+                            ));
+                        }),
+                    )
+                },
+                WidgetPart(body_layout),
+                DataTableBody,
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(DataTableState {
+                header,
+                body,
+                order: Vec::new(),
+                anchor: None,
+                cursor: None,
+                resize: None,
+            })
+            .push_children(&[header, body]);
+    }
+}
+
+// Clicking a sortable header sorts by its column, ascending first then flipping the order on each click
+// Pressing a row selects it (Ctrl toggles it, Shift selects the rows from the last one pressed)
+// While focused: Up / Down / Home / End move the selection (extending it with Shift) & scroll it into view
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn interact_with_data_tables(
+    keys: Res<Input<KeyCode>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mut tables: Query<
+        (Entity, &mut DataTable, &mut DataTableState, Has<Focused>),
+        Without<Disabled>,
+    >,
+    mut bodies: Query<(&mut VirtualList, &Size), With<DataTableBody>>,
+    header_cells: Query<&DataTableHeaderCell>,
+    resize_handles: Query<&DataTableResizeHandle>,
+    rows: Query<&DataTableRow>,
+    mut press_events: EventReader<PressEvent>,
+    mut click_events: EventReader<ClickEvent>,
+    mut sort_changed_events: EventWriter<DataTableSortChangedEvent>,
+    mut selection_changed_events: EventWriter<DataTableSelectionChangedEvent>,
+    mut column_resized_events: EventWriter<DataTableColumnResizedEvent>,
+) {
+    let pressed = press_events
+        .read()
+        .map(|press_event| press_event.element)
+        .collect::<Vec<_>>();
+    let clicked = click_events
+        .read()
+        .map(|click_event| click_event.element)
+        .collect::<Vec<_>>();
+
+    let cursor_x = match primary_window.get_single() {
+        Ok(primary_window) => primary_window
+            .cursor_position()
+            .map(|cursor_position| cursor_position.x),
+        Err(_) => {
+            warn!("Couldn't get primary window");
+
+            None
+        }
+    };
+
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    for (entity, mut table, mut state, focused) in tables.iter_mut() {
+        // Releasing a boundary over a header doesn't sort by its column
+        if state.resize.is_none() {
+            let clicked_column = clicked
+                .iter()
+                .filter_map(|element| header_cells.get(*element).ok())
+                .find(|header_cell| header_cell.table == entity)
+                .map(|header_cell| header_cell.column)
+                .filter(|column| {
+                    table
+                        .columns
+                        .get(*column)
+                        .is_some_and(|column| column.sortable)
+                });
+
+            if let Some(column) = clicked_column {
+                let order = match table.sort {
+                    Some(sort) if sort.column == column && sort.order == SortOrder::Ascending => {
+                        SortOrder::Descending
+                    }
+                    _ => SortOrder::Ascending,
+                };
+
+                let sort = DataTableSort { column, order };
+                table.sort = Some(sort);
+                sort_changed_events.send(DataTableSortChangedEvent {
+                    table: entity,
+                    sort,
+                });
+            }
+        }
+
+        let pressed_handle = pressed
+            .iter()
+            .filter_map(|element| resize_handles.get(*element).ok())
+            .find(|handle| handle.table == entity);
+
+        if let (Some(handle), Some(cursor_x)) = (pressed_handle, cursor_x) {
+            if let Some(column) = table.columns.get(handle.column) {
+                state.resize = Some(ColumnResize {
+                    column: handle.column,
+                    start_x: cursor_x,
+                    start_width: column.width,
+                });
+            }
+        }
+
+        if !mouse_buttons.pressed(MouseButton::Left) {
+            if state.resize.is_some() {
+                state.resize = None;
+            }
+        } else if let (Some(resize), Some(cursor_x)) = (&state.resize, cursor_x) {
+            let column = resize.column;
+            let width = (resize.start_width as f32 + cursor_x - resize.start_x).max(0.0) as u32;
+
+            if let Some(resized_column) = table.columns.get(column) {
+                let width = width.max(resized_column.min_width);
+
+                if resized_column.width != width {
+                    table.columns[column].width = width;
+                    column_resized_events.send(DataTableColumnResizedEvent {
+                        table: entity,
+                        column,
+                        width,
+                    });
+                }
+            }
+        }
+
+        let mut selected = table.selected.clone();
+        let mut anchor = state.anchor;
+        let mut cursor = state.cursor;
+
+        let shown_index = |row: usize| state.order.iter().position(|shown| *shown == row);
+        let select_range = |anchor: Option<usize>, row: usize| {
+            let row_index = shown_index(row);
+            let anchor_index = anchor.and_then(shown_index).or(row_index);
+
+            match (anchor_index, row_index) {
+                (Some(anchor_index), Some(row_index)) => {
+                    state.order[anchor_index.min(row_index)..=anchor_index.max(row_index)].to_vec()
+                }
+                _ => vec![row],
+            }
+        };
+
+        let pressed_row = pressed
+            .iter()
+            .filter_map(|element| rows.get(*element).ok())
+            .find(|row| row.table == entity)
+            .and_then(|row| state.order.get(row.index).copied());
+
+        if let Some(row) = pressed_row {
+            cursor = Some(row);
+
+            if ctrl {
+                match selected.iter().position(|selected| *selected == row) {
+                    Some(index) => {
+                        selected.remove(index);
+                    }
+                    None => selected.push(row),
+                }
+
+                anchor = Some(row);
+            } else if shift {
+                selected = select_range(anchor, row);
+            } else {
+                selected = vec![row];
+                anchor = Some(row);
+            }
+        }
+
+        let last_index = state.order.len().checked_sub(1);
+        let cursor_index = cursor.and_then(shown_index);
+
+        let moved_to = match (focused, last_index) {
+            (true, Some(last_index)) => {
+                if keys.just_pressed(KeyCode::Up) {
+                    Some(cursor_index.map_or(0, |cursor_index| cursor_index.saturating_sub(1)))
+                } else if keys.just_pressed(KeyCode::Down) {
+                    Some(cursor_index.map_or(0, |cursor_index| (cursor_index + 1).min(last_index)))
+                } else if keys.just_pressed(KeyCode::Home) {
+                    Some(0)
+                } else if keys.just_pressed(KeyCode::End) {
+                    Some(last_index)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(moved_to) = moved_to {
+            let row = state.order[moved_to];
+            cursor = Some(row);
+
+            if shift {
+                selected = select_range(anchor, row);
+            } else {
+                selected = vec![row];
+                anchor = Some(row);
+            }
+
+            if let Ok((mut body, body_size)) = bodies.get_mut(state.body) {
+                let row_top = (moved_to as u32 * table.row_height) as f32;
+                let row_bottom = row_top + table.row_height as f32;
+
+                if row_top < body.scroll_offset {
+                    body.scroll_offset = row_top;
+                } else if row_bottom > body.scroll_offset + body_size.height as f32 {
+                    body.scroll_offset = row_bottom - body_size.height as f32;
+                }
+            }
+        }
+
+        if table.selected != selected {
+            table.selected = selected.clone();
+            selection_changed_events.send(DataTableSelectionChangedEvent {
+                table: entity,
+                selected,
+            });
+        }
+
+        if state.anchor != anchor || state.cursor != cursor {
+            state.anchor = anchor;
+            state.cursor = cursor;
+        }
+    }
+}
+
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+fn compare_rows(table: &DataTable, sort: DataTableSort, a: usize, b: usize) -> Ordering {
+    let cell = |row: usize| {
+        table.rows[row]
+            .get(sort.column)
+            .map(String::as_str)
+            .unwrap_or_default()
+    };
+
+    let ordering = compare_cells(cell(a), cell(b));
+
+    match sort.order {
+        SortOrder::Ascending => ordering,
+        SortOrder::Descending => ordering.reverse(),
+    }
+}
+
+// Only sorts again when the shown order isn't sorted anymore, so selecting or resizing doesn't sort long tables
+fn sort_data_tables(
+    mut tables: Query<
+        (&DataTable, &mut DataTableState),
+        Or<(Changed<DataTable>, Added<DataTableState>)>,
+    >,
+    mut bodies: Query<&mut VirtualList, With<DataTableBody>>,
+) {
+    for (table, mut state) in tables.iter_mut() {
+        let sorted = state.order.len() == table.rows.len()
+            && match table.sort {
+                Some(sort) => state
+                    .order
+                    .windows(2)
+                    .all(|rows| compare_rows(table, sort, rows[0], rows[1]) != Ordering::Greater),
+                None => state
+                    .order
+                    .iter()
+                    .enumerate()
+                    .all(|(index, row)| index == *row),
+            };
+
+        if !sorted {
+            let mut order = (0..table.rows.len()).collect::<Vec<_>>();
+
+            if let Some(sort) = table.sort {
+                order.sort_by(|a, b| compare_rows(table, sort, *a, *b));
+            }
+
+            state.order = order;
+        }
+
+        let Ok(mut body) = bodies.get_mut(state.body) else {
+            continue;
+        };
+
+        if body.item_count != table.rows.len() {
+            body.item_count = table.rows.len();
+        }
+
+        if body.row_height != RowHeight::Fixed(table.row_height) {
+            body.row_height = RowHeight::Fixed(table.row_height);
+        }
+    }
+}
+
+// The left edge of each column from the table's left edge
+fn column_offsets(table: &DataTable) -> Vec<u32> {
+    table
+        .columns
+        .iter()
+        .scan(0, |offset, column| {
+            let column_offset = *offset;
+            *offset += column.width;
+
+            Some(column_offset)
+        })
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
+fn layout_data_table_headers(
+    mut commands: Commands,
+    tables: Query<
+        (Entity, &DataTable, &DataTableState, &Position),
+        Or<(Changed<DataTable>, Changed<Position>, Added<DataTableState>)>,
+    >,
+    headers: Query<Option<&Children>, With<DataTableHeader>>,
+    mut header_cells: Query<
+        (&DataTableHeaderCell, &mut Position, &mut Size),
+        (Without<DataTable>, Without<DataTableResizeHandle>),
+    >,
+    mut resize_handles: Query<
+        (&DataTableResizeHandle, &mut Position, &mut Size),
+        (Without<DataTable>, Without<DataTableHeaderCell>),
+    >,
+    mut texts: Query<
+        (&mut UiText, &mut Position, &mut Size, &mut TextAlign),
+        (
+            Without<DataTable>,
+            Without<DataTableHeaderCell>,
+            Without<DataTableResizeHandle>,
+        ),
+    >,
+) {
+    for (entity, table, state, position) in tables.iter() {
+        let Ok(header_children) = headers.get(state.header) else {
+            continue;
+        };
+
+        let header_children = header_children
+            .map(|header_children| header_children.to_vec())
+            .unwrap_or_default();
+        let cell_count = header_children
+            .iter()
+            .filter(|child| header_cells.contains(**child))
+            .count();
+
+        let offsets = column_offsets(table);
+
+        // Columns got added or removed, the header gets rebuilt
+        if cell_count != table.columns.len() {
+            for child in header_children {
+                commands.entity(child).despawn_recursive();
+            }
+
+            for (column, offset) in offsets.iter().enumerate() {
+                spawn_header_cell(
+                    &mut commands,
+                    entity,
+                    state.header,
+                    table,
+                    column,
+                    position.x + offset,
+                    position.y,
+                );
+            }
+
+            continue;
+        }
+
+        for child in header_children.iter() {
+            if let Ok((header_cell, mut cell_position, mut cell_size)) =
+                header_cells.get_mut(*child)
+            {
+                let Some(column) = table.columns.get(header_cell.column) else {
+                    continue;
+                };
+
+                let x = position.x + offsets[header_cell.column];
+                set_rect(
+                    &mut cell_position,
+                    &mut cell_size,
+                    Position::new(x, position.y),
+                    Size::new(column.width, HEADER_HEIGHT),
+                );
+
+                let (title, indicator) = (header_cell.title, header_cell.indicator);
+                let indicator_text = sort_indicator(table, header_cell.column);
+
+                if let Ok((mut text, mut text_position, mut text_size, mut alignment)) =
+                    texts.get_mut(title)
+                {
+                    let (new_position, new_size) = title_rect(x, position.y, column.width);
+                    set_rect(&mut text_position, &mut text_size, new_position, new_size);
+                    set_text(&mut text, &mut alignment, &column.title, column.alignment);
+                }
+
+                if let Ok((mut text, mut text_position, mut text_size, mut alignment)) =
+                    texts.get_mut(indicator)
+                {
+                    let (new_position, new_size) = indicator_rect(x, position.y, column.width);
+                    set_rect(&mut text_position, &mut text_size, new_position, new_size);
+                    set_text(&mut text, &mut alignment, indicator_text, TextAlign::Right);
+                }
+            } else if let Ok((handle, mut handle_position, mut handle_size)) =
+                resize_handles.get_mut(*child)
+            {
+                let Some(column) = table.columns.get(handle.column) else {
+                    continue;
+                };
+
+                let (new_position, new_size) = resize_handle_rect(
+                    position.x + offsets[handle.column],
+                    position.y,
+                    column.width,
+                );
+                set_rect(
+                    &mut handle_position,
+                    &mut handle_size,
+                    new_position,
+                    new_size,
+                );
+            }
+        }
+    }
+}
+
+fn sort_indicator(table: &DataTable, column: usize) -> &'static str {
+    match table.sort {
+        Some(DataTableSort {
+            column: sort_column,
+            order: SortOrder::Ascending,
+        }) if sort_column == column => "▲",
+        Some(DataTableSort {
+            column: sort_column,
+            order: SortOrder::Descending,
+        }) if sort_column == column => "▼",
+        _ => "",
+    }
+}
+
+// Leaves room on the right for the sort indicator
+fn title_rect(x: u32, y: u32, width: u32) -> (Position, Size) {
+    (
+        Position::new(x + CELL_PADDING, y),
+        Size::new(width.saturating_sub(CELL_PADDING * 3 + 10), HEADER_HEIGHT),
+    )
+}
+
+fn indicator_rect(x: u32, y: u32, width: u32) -> (Position, Size) {
+    (
+        Position::new((x + width).saturating_sub(CELL_PADDING + 10), y),
+        Size::new(10, HEADER_HEIGHT),
+    )
+}
+
+fn resize_handle_rect(x: u32, y: u32, width: u32) -> (Position, Size) {
+    (
+        Position::new((x + width).saturating_sub(RESIZE_HANDLE_WIDTH / 2), y),
+        Size::new(RESIZE_HANDLE_WIDTH, HEADER_HEIGHT),
+    )
+}
+
+fn spawn_header_cell(
+    commands: &mut Commands,
+    table_entity: Entity,
+    header: Entity,
+    table: &DataTable,
+    column: usize,
+    x: u32,
+    y: u32,
+) {
+    let data_column = &table.columns[column];
+
+    let (title_position, title_size) = title_rect(x, y, data_column.width);
+    let title = commands
+        .spawn((
+            UiTextBundle {
+                text: UiText(data_column.title.clone()),
+                font_size: FontSize(16),
+                font_weight: FontWeight::BOLD,
+                position: title_position,
+                size: title_size,
+                ..Default::default()
+            },
+            data_column.alignment,
+            VerticalAlign::Center,
+            TextOverflow::Ellipsis,
+        ))
+        .id();
+
+    let (indicator_position, indicator_size) = indicator_rect(x, y, data_column.width);
+    let indicator = commands
+        .spawn((
+            UiTextBundle {
+                text: UiText(sort_indicator(table, column).to_string()),
+                font_size: FontSize(12),
+                position: indicator_position,
+                size: indicator_size,
+                ..Default::default()
+            },
+            TextAlign::Right,
+            VerticalAlign::Center,
+        ))
+        .id();
+
+    let mut cell = commands.spawn((
+        UiBoxBundle {
+            position: Position::new(x, y),
+            size: Size::new(data_column.width, HEADER_HEIGHT),
+            color: ColoredElement::new(Color::NONE),
+            ..Default::default()
+        },
+        BoxCollisionBundle::default(),
+        DataTableHeaderCell {
+            table: table_entity,
+            column,
+            title,
+            indicator,
+        },
+    ));
+
+    if data_column.sortable {
+        cell.insert(HoverEffect::new(ColoredElement::new(
+            Color::BLACK.with_a(0.05),
+        )));
+    }
+
+    let cell = cell.push_children(&[title, indicator]).id();
+
+    let (handle_position, handle_size) = resize_handle_rect(x, y, data_column.width);
+    let handle = commands
+        .spawn((
+            UiBoxBundle {
+                position: handle_position,
+                size: handle_size,
+                color: ColoredElement::new(Color::NONE),
+                ..Default::default()
+            },
+            HoverEffect::new(ColoredElement::new(ACCENT_COLOR)),
+            BoxCollisionBundle::default(),
+            DataTableResizeHandle {
+                table: table_entity,
+                column,
+            },
+        ))
+        .id();
+
+    // Handles go after the cells, so they're above the next column's cell
+    commands.entity(header).push_children(&[cell, handle]);
+}
+
+// Fills the rows the body just built & all the rows of tables that changed
+#[allow(clippy::type_complexity)]
+fn fill_data_table_rows(
+    mut commands: Commands,
+    tables: Query<(Ref<DataTable>, Ref<DataTableState>)>,
+    mut rows: Query<(
+        Entity,
+        Ref<DataTableRow>,
+        &Position,
+        &Size,
+        &mut ColoredElement,
+        Option<&Children>,
+    )>,
+    mut cells: Query<
+        (&DataTableCell, &mut Position, &mut Size),
+        (Without<DataTableRow>, Without<UiText>),
+    >,
+    mut texts: Query<
+        (&mut UiText, &mut Position, &mut Size, &mut TextAlign),
+        (Without<DataTableRow>, Without<DataTableCell>),
+    >,
+) {
+    for (entity, row, position, size, mut color, row_children) in rows.iter_mut() {
+        let Ok((table, state)) = tables.get(row.table) else {
+            continue;
+        };
+
+        if !row.is_changed() && !table.is_changed() && !state.is_changed() {
+            continue;
+        }
+
+        let Some(&data_index) = state.order.get(row.index) else {
+            continue;
+        };
+
+        let Some(data_row) = table.rows.get(data_index) else {
+            continue;
+        };
+
+        let new_color = if table.selected.contains(&data_index) {
+            SELECTED_ROW_COLOR
+        } else if row.index % 2 == 1 {
+            ALTERNATE_ROW_COLOR
+        } else {
+            ROW_COLOR
+        };
+
+        if color.color != new_color {
+            color.color = new_color;
+        }
+
+        let offsets = column_offsets(&table);
+        let row_cells = row_children
+            .map(|row_children| {
+                row_children
+                    .iter()
+                    .filter(|child| cells.contains(**child))
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // Columns got added or removed, the cells get rebuilt
+        if row_cells.len() != table.columns.len() {
+            for cell in row_cells {
+                commands.entity(cell).despawn_recursive();
+            }
+
+            for (column, offset) in offsets.iter().enumerate() {
+                let data_column = &table.columns[column];
+                let cell_position = Position::new(position.x + offset, position.y);
+                let cell_size = Size::new(data_column.width, size.height);
+                let (text_position, text_size) = cell_text_rect(&cell_position, &cell_size);
+
+                let text = commands
+                    .spawn((
+                        UiTextBundle {
+                            text: UiText(data_row.get(column).cloned().unwrap_or_default()),
+                            position: text_position,
+                            size: text_size,
+                            ..Default::default()
+                        },
+                        data_column.alignment,
+                        VerticalAlign::Center,
+                        TextOverflow::Ellipsis,
+                    ))
+                    .id();
+
+                let cell = commands
+                    .spawn((
+                        UiBoxBundle {
+                            position: cell_position,
+                            size: cell_size,
+                            color: ColoredElement::new(Color::NONE),
+                            ..Default::default()
+                        },
+                        DataTableCell { column, text },
+                    ))
+                    .add_child(text)
+                    .id();
+
+                commands.entity(entity).add_child(cell);
+            }
+
+            continue;
+        }
+
+        for cell in row_cells {
+            let Ok((table_cell, mut cell_position, mut cell_size)) = cells.get_mut(cell) else {
+                continue;
+            };
+
+            let Some(data_column) = table.columns.get(table_cell.column) else {
+                continue;
+            };
+
+            let new_position = Position::new(position.x + offsets[table_cell.column], position.y);
+            let new_size = Size::new(data_column.width, size.height);
+            let (text_position, text_size) = cell_text_rect(&new_position, &new_size);
+
+            set_rect(&mut cell_position, &mut cell_size, new_position, new_size);
+
+            if let Ok((mut text, mut position, mut size, mut alignment)) =
+                texts.get_mut(table_cell.text)
+            {
+                let cell_text = data_row
+                    .get(table_cell.column)
+                    .map(String::as_str)
+                    .unwrap_or_default();
+
+                set_rect(&mut position, &mut size, text_position, text_size);
+                set_text(&mut text, &mut alignment, cell_text, data_column.alignment);
+            }
+        }
+    }
+}
+
+fn cell_text_rect(position: &Position, size: &Size) -> (Position, Size) {
+    (
+        Position::new(position.x + CELL_PADDING, position.y),
+        Size::new(size.width.saturating_sub(CELL_PADDING * 2), size.height),
+    )
+}
+
+fn set_rect(
+    position: &mut Mut<Position>,
+    size: &mut Mut<Size>,
+    new_position: Position,
+    new_size: Size,
+) {
+    if **position != new_position {
+        **position = new_position;
+    }
+
+    if **size != new_size {
+        **size = new_size;
+    }
+}
+
+fn set_text(
+    text: &mut Mut<UiText>,
+    alignment: &mut Mut<TextAlign>,
+    new_text: &str,
+    new_alignment: TextAlign,
+) {
+    if text.0 != new_text {
+        text.0 = new_text.to_string();
+    }
+
+    if **alignment != new_alignment {
+        **alignment = new_alignment;
+    }
+}
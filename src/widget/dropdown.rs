@@ -0,0 +1,575 @@
+use std::time::Duration;
+
+use bevy::{
+    log::warn,
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component,
+        DespawnRecursiveExt, Entity, Event, EventReader, EventWriter, Has, Input,
+        IntoSystemConfigs, KeyCode, MouseButton, Plugin, Query, ReflectComponent, Res, Time,
+        Update, With, Without,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, ReceivedCharacter, Window},
+};
+
+use crate::{
+    element::{
+        r#box::{UiBox, UiBoxBundle},
+        text::{FontSize, TextOverflow, UiText, UiTextBundle, VerticalAlign},
+    },
+    event::{on_mouse_click_start, ClickEvent, PressEvent},
+    property::{
+        collision::BoxCollisionBundle,
+        focus::{Focusable, Focused},
+        state::{
+            disabled::{Disabled, DisabledEffect},
+            hover::{HoverEffect, HoverEffectTransition},
+        },
+        update::{AutoVisibleRegionUpdate, AutoZUpdate, Overlay},
+        ColoredElement, CornersRoundness, Position, Size, VisibleRegion, ZLevel,
+    },
+    widget::{WidgetPart, ACCENT_COLOR, POPUP_Z_INDEX},
+};
+
+pub(crate) struct UiDropdownPlugin;
+
+impl Plugin for UiDropdownPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<SelectionChangedEvent>().add_systems(
+            Update,
+            (
+                spawn_dropdown_labels,
+                (
+                    close_disabled_dropdowns,
+                    interact_with_dropdowns.after(on_mouse_click_start),
+                    update_dropdown_labels,
+                    highlight_dropdown_options,
+                )
+                    .chain(),
+            ),
+        );
+    }
+}
+
+// Typed characters further than this apart start a new type-ahead search
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+const OPTION_COLOR: Color = Color::WHITE;
+const HIGHLIGHTED_OPTION_COLOR: Color = Color::rgb(0.85, 0.89, 1.0);
+
+// Shows the selected option, or the placeholder while nothing is selected
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Dropdown {
+    pub options: Vec<String>,
+    pub selected: Option<usize>,
+    pub placeholder: String,
+}
+
+impl Dropdown {
+    pub fn new<S: Into<String>>(options: impl IntoIterator<Item = S>) -> Dropdown {
+        Dropdown {
+            options: options.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_selected(mut self, selected: usize) -> Dropdown {
+        self.selected = Some(selected);
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Dropdown {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn selected_option(&self) -> Option<&str> {
+        self.selected
+            .and_then(|selected| self.options.get(selected))
+            .map(String::as_str)
+    }
+}
+
+// Sent when the user picks another option, not when Dropdown::selected gets changed by code
+#[derive(Event, Debug, Clone)]
+pub struct SelectionChangedEvent {
+    pub dropdown: Entity,
+    pub index: usize,
+    pub value: String,
+}
+
+#[derive(Bundle)]
+pub struct UiDropdownBundle {
+    pub dropdown: Dropdown,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+    pub corners_roundness: CornersRoundness,
+
+    pub hover_effect: HoverEffectTransition<ColoredElement>,
+    pub disabled_effect: DisabledEffect<ColoredElement>,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl UiDropdownBundle {
+    pub fn new(dropdown: Dropdown) -> UiDropdownBundle {
+        UiDropdownBundle {
+            dropdown,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for UiDropdownBundle {
+    fn default() -> Self {
+        Self {
+            dropdown: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(200, 36),
+            color: ColoredElement::new(Color::GRAY),
+            corners_roundness: CornersRoundness::from_scalar(0.2),
+
+            hover_effect: HoverEffectTransition::new(
+                ColoredElement::new(Color::DARK_GRAY),
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+            ),
+            disabled_effect: DisabledEffect::new(ColoredElement::new(Color::SILVER)),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+// The open popup & the keyboard state, inserted along with the label
+#[derive(Component, Default)]
+struct DropdownState {
+    popup: Option<Entity>,
+    highlighted: usize,
+    type_ahead: String,
+    last_typed: Duration,
+}
+
+#[derive(Component)]
+struct DropdownLabel;
+
+// The option list, spawned as an overlay child of its dropdown while it's open
+#[derive(Component)]
+struct DropdownPopup {
+    dropdown: Entity,
+}
+
+#[derive(Component)]
+struct DropdownOption {
+    dropdown: Entity,
+    index: usize,
+}
+
+fn dropdown_label_layout(position: &Position, size: &Size) -> (Position, Size) {
+    let padding = size.height / 4;
+
+    (
+        Position::new(position.x + padding, position.y),
+        Size::new(size.width.saturating_sub(padding * 2), size.height),
+    )
+}
+
+fn spawn_dropdown_labels(
+    mut commands: Commands,
+    dropdowns: Query<(Entity, &Dropdown, &Position, &Size), Added<Dropdown>>,
+) {
+    for (entity, dropdown, position, size) in dropdowns.iter() {
+        let (label_position, label_size) = dropdown_label_layout(position, size);
+
+        let label = commands
+            .spawn((
+                UiTextBundle {
+                    text: UiText(dropdown_label_text(dropdown)),
+                    font_size: FontSize(20),
+                    position: label_position,
+                    size: label_size,
+                    ..Default::default()
+                },
+                VerticalAlign::Center,
+                TextOverflow::Ellipsis,
+                WidgetPart(dropdown_label_layout),
+                DropdownLabel,
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(DropdownState {
+                highlighted: dropdown.selected.unwrap_or_default(),
+                ..Default::default()
+            })
+            .add_child(label);
+    }
+}
+
+fn dropdown_label_text(dropdown: &Dropdown) -> String {
+    dropdown
+        .selected_option()
+        .unwrap_or(&dropdown.placeholder)
+        .to_string()
+}
+
+fn update_dropdown_labels(
+    dropdowns: Query<(&Dropdown, &Children), Changed<Dropdown>>,
+    mut labels: Query<&mut UiText, With<DropdownLabel>>,
+) {
+    for (dropdown, children) in dropdowns.iter() {
+        let label_text = dropdown_label_text(dropdown);
+        let mut label_texts = labels.iter_many_mut(children);
+
+        while let Some(mut text) = label_texts.fetch_next() {
+            if text.0 != label_text {
+                text.0 = label_text.clone();
+            }
+        }
+    }
+}
+
+// Clicking the dropdown toggles its popup, clicking an option selects it & pressing anywhere else closes it
+// While focused: Enter / Space / arrows open it, then arrows, Home & End move the highlight, Enter / Space select it & Escape closes it
+// Typing jumps to the first option starting with the typed text, selecting it right away when the popup is closed
+#[allow(clippy::too_many_arguments)]
+fn interact_with_dropdowns(
+    mut commands: Commands,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mut dropdowns: Query<
+        (
+            Entity,
+            &mut Dropdown,
+            &mut DropdownState,
+            &Position,
+            &Size,
+            Has<Focused>,
+        ),
+        Without<Disabled>,
+    >,
+    popups: Query<&DropdownPopup>,
+    options: Query<&DropdownOption>,
+    mut click_events: EventReader<ClickEvent>,
+    mut press_events: EventReader<PressEvent>,
+    mut characters: EventReader<ReceivedCharacter>,
+    mut selection_changed_events: EventWriter<SelectionChangedEvent>,
+) {
+    let clicked = click_events
+        .read()
+        .map(|click_event| click_event.element)
+        .collect::<Vec<_>>();
+
+    // The dropdowns the pressed elements belong to
+    let pressed = press_events
+        .read()
+        .map(|press_event| {
+            popups
+                .get(press_event.element)
+                .map(|popup| popup.dropdown)
+                .or_else(|_| {
+                    options
+                        .get(press_event.element)
+                        .map(|option| option.dropdown)
+                })
+                .unwrap_or(press_event.element)
+        })
+        .collect::<Vec<_>>();
+
+    let typed = characters
+        .read()
+        .map(|character| character.char)
+        .filter(|character| !character.is_control())
+        .collect::<String>();
+
+    let window_height = match primary_window.get_single() {
+        Ok(primary_window) => primary_window.height() as u32,
+        Err(_) => {
+            warn!("Couldn't get primary window");
+
+            u32::MAX
+        }
+    };
+
+    let outside_press = mouse_buttons.just_pressed(MouseButton::Left);
+    let confirm_pressed =
+        keys.any_just_pressed([KeyCode::Return, KeyCode::NumpadEnter, KeyCode::Space]);
+    let arrow_pressed = keys.any_just_pressed([KeyCode::Up, KeyCode::Down]);
+
+    for (entity, mut dropdown, mut state, position, size, focused) in dropdowns.iter_mut() {
+        let clicked_option = clicked
+            .iter()
+            .filter_map(|element| options.get(*element).ok())
+            .find(|option| option.dropdown == entity)
+            .map(|option| option.index);
+
+        if let Some(index) = clicked_option {
+            select_option(entity, &mut dropdown, index, &mut selection_changed_events);
+            close_popup(&mut commands, &mut state);
+            continue;
+        }
+
+        if state.popup.is_none() {
+            if clicked.contains(&entity) || (focused && (confirm_pressed || arrow_pressed)) {
+                let popup = spawn_popup(
+                    &mut commands,
+                    entity,
+                    &dropdown,
+                    dropdown.selected.unwrap_or_default(),
+                    position,
+                    size,
+                    window_height,
+                );
+
+                state.popup = Some(popup);
+                state.highlighted = dropdown.selected.unwrap_or_default();
+            } else if focused && !typed.is_empty() {
+                if let Some(index) = type_ahead(&dropdown, &mut state, &typed, time.elapsed()) {
+                    select_option(entity, &mut dropdown, index, &mut selection_changed_events);
+                }
+            }
+
+            continue;
+        }
+
+        if clicked.contains(&entity)
+            || (focused && keys.just_pressed(KeyCode::Escape))
+            || (outside_press && !pressed.contains(&entity))
+        {
+            close_popup(&mut commands, &mut state);
+            continue;
+        }
+
+        if !focused {
+            continue;
+        }
+
+        if confirm_pressed {
+            let index = state.highlighted;
+
+            select_option(entity, &mut dropdown, index, &mut selection_changed_events);
+            close_popup(&mut commands, &mut state);
+            continue;
+        }
+
+        let last_option = dropdown.options.len().saturating_sub(1);
+
+        let highlighted = if keys.just_pressed(KeyCode::Up) {
+            state.highlighted.saturating_sub(1)
+        } else if keys.just_pressed(KeyCode::Down) {
+            (state.highlighted + 1).min(last_option)
+        } else if keys.just_pressed(KeyCode::Home) {
+            0
+        } else if keys.just_pressed(KeyCode::End) {
+            last_option
+        } else if !typed.is_empty() {
+            type_ahead(&dropdown, &mut state, &typed, time.elapsed()).unwrap_or(state.highlighted)
+        } else {
+            state.highlighted
+        };
+
+        if state.highlighted != highlighted {
+            state.highlighted = highlighted;
+        }
+    }
+}
+
+// Disabled dropdowns don't get interacted with, so their popup gets closed here
+fn close_disabled_dropdowns(
+    mut commands: Commands,
+    mut dropdowns: Query<&mut DropdownState, With<Disabled>>,
+) {
+    for mut state in dropdowns.iter_mut() {
+        if state.popup.is_some() {
+            close_popup(&mut commands, &mut state);
+        }
+    }
+}
+
+fn select_option(
+    entity: Entity,
+    dropdown: &mut Dropdown,
+    index: usize,
+    selection_changed_events: &mut EventWriter<SelectionChangedEvent>,
+) {
+    let Some(value) = dropdown.options.get(index) else {
+        return;
+    };
+
+    if dropdown.selected == Some(index) {
+        return;
+    }
+
+    selection_changed_events.send(SelectionChangedEvent {
+        dropdown: entity,
+        index,
+        value: value.clone(),
+    });
+
+    dropdown.selected = Some(index);
+}
+
+// The first option starting with the text typed since the last pause, case insensitively
+fn type_ahead(
+    dropdown: &Dropdown,
+    state: &mut DropdownState,
+    typed: &str,
+    now: Duration,
+) -> Option<usize> {
+    if now.saturating_sub(state.last_typed) > TYPE_AHEAD_TIMEOUT {
+        state.type_ahead.clear();
+    }
+
+    state.type_ahead.push_str(&typed.to_lowercase());
+    state.last_typed = now;
+
+    dropdown
+        .options
+        .iter()
+        .position(|option| option.to_lowercase().starts_with(&state.type_ahead))
+}
+
+fn close_popup(commands: &mut Commands, state: &mut DropdownState) {
+    if let Some(popup) = state.popup.take() {
+        commands.entity(popup).despawn_recursive();
+    }
+}
+
+// Opens below the dropdown, or above it when there isn't enough room left in the window
+fn spawn_popup(
+    commands: &mut Commands,
+    entity: Entity,
+    dropdown: &Dropdown,
+    highlighted: usize,
+    position: &Position,
+    size: &Size,
+    window_height: u32,
+) -> Entity {
+    let option_height = size.height;
+    let popup_height = option_height * dropdown.options.len() as u32;
+
+    let below = position.y + size.height;
+    let popup_y = if below + popup_height > window_height && position.y >= popup_height {
+        position.y - popup_height
+    } else {
+        below
+    };
+
+    let (label_position, _) = dropdown_label_layout(position, size);
+    let label_offset = label_position.x - position.x;
+
+    let popup = commands
+        .spawn((
+            UiBoxBundle {
+                position: Position::new(position.x, popup_y),
+                size: Size::new(size.width, popup_height),
+                color: ColoredElement::new(OPTION_COLOR),
+                ..Default::default()
+            },
+            CornersRoundness::from_scalar(0.05),
+            BoxCollisionBundle::default(),
+            Overlay,
+            POPUP_Z_INDEX,
+            DropdownPopup { dropdown: entity },
+        ))
+        .with_children(|popup| {
+            for (index, option) in dropdown.options.iter().enumerate() {
+                let option_position =
+                    Position::new(position.x, popup_y + option_height * index as u32);
+                let option_color = if index == highlighted {
+                    HIGHLIGHTED_OPTION_COLOR
+                } else {
+                    OPTION_COLOR
+                };
+
+                popup
+                    .spawn((
+                        UiBoxBundle {
+                            position: option_position.clone(),
+                            size: Size::new(size.width, option_height),
+                            color: ColoredElement::new(option_color),
+                            ..Default::default()
+                        },
+                        HoverEffect::new(ColoredElement::new(ACCENT_COLOR.with_a(0.3))),
+                        BoxCollisionBundle::default(),
+                        DropdownOption {
+                            dropdown: entity,
+                            index,
+                        },
+                    ))
+                    .with_children(|option_box| {
+                        option_box.spawn((
+                            UiTextBundle {
+                                text: UiText(option.clone()),
+                                font_size: FontSize(20),
+                                position: Position::new(
+                                    option_position.x + label_offset,
+                                    option_position.y,
+                                ),
+                                size: Size::new(
+                                    size.width.saturating_sub(label_offset * 2),
+                                    option_height,
+                                ),
+                                ..Default::default()
+                            },
+                            VerticalAlign::Center,
+                            TextOverflow::Ellipsis,
+                        ));
+                    });
+            }
+        })
+        .id();
+
+    commands.entity(entity).add_child(popup);
+
+    popup
+}
+
+// Keyboard highlight, the hover effect of the options still shows on top of it
+fn highlight_dropdown_options(
+    states: Query<&DropdownState, Changed<DropdownState>>,
+    mut options: Query<(&DropdownOption, &mut ColoredElement)>,
+) {
+    if states.is_empty() {
+        return;
+    }
+
+    for (option, mut color) in options.iter_mut() {
+        let Ok(state) = states.get(option.dropdown) else {
+            continue;
+        };
+
+        let new_color = if option.index == state.highlighted {
+            HIGHLIGHTED_OPTION_COLOR
+        } else {
+            OPTION_COLOR
+        };
+
+        if color.color != new_color {
+            color.color = new_color;
+        }
+    }
+}
@@ -0,0 +1,1388 @@
+use std::time::Duration;
+
+use bevy::{
+    log::warn,
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component,
+        DespawnRecursiveExt, Entity, Event, EventReader, EventWriter, Has, Input,
+        IntoSystemConfigs, KeyCode, MouseButton, Mut, Or, Parent, Plugin, Query, ReflectComponent,
+        Res, Time, Timer, TimerMode, Update, Vec2, With, Without,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    element::{
+        r#box::{UiBox, UiBoxBundle},
+        text::{
+            FontSize, TextAlign, TextLayoutInfo, TextOverflow, UiText, UiTextBundle, VerticalAlign,
+        },
+    },
+    event::{on_mouse_click_start, ClickEvent, HoverEnterEvent, PressEvent, SecondaryPressEvent},
+    property::{
+        collision::BoxCollisionBundle,
+        focus::{Focusable, Focused},
+        state::{disabled::Disabled, hover::HoverEffect},
+        update::{AutoVisibleRegionUpdate, AutoZUpdate, Overlay},
+        ColoredElement, CornersRoundness, Position, Size, VisibleRegion, ZIndex, ZLevel,
+    },
+    widget::{ACCENT_COLOR, POPUP_Z_LEVEL},
+};
+
+pub(crate) struct UiMenuPlugin;
+
+impl Plugin for UiMenuPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<MenuItemActivatedEvent>().add_systems(
+            Update,
+            (
+                init_menus,
+                layout_menu_titles,
+                (
+                    open_menus,
+                    hover_menus,
+                    activate_clicked_menu_items,
+                    navigate_menus,
+                    trigger_menu_accelerators,
+                    highlight_menus,
+                )
+                    .chain()
+                    .after(on_mouse_click_start),
+            ),
+        );
+    }
+}
+
+const SUBMENU_DELAY: Duration = Duration::from_millis(300);
+
+const MENU_WIDTH: u32 = 240;
+const MENU_PADDING: u32 = 4;
+const ROW_HEIGHT: u32 = 28;
+const SEPARATOR_HEIGHT: u32 = 9;
+const TITLE_PADDING: u32 = 10;
+// Room on the sides of the rows for the checkmark & the submenu arrow, and for the accelerator's label
+const ROW_MARGIN: u32 = 28;
+const ACCELERATOR_WIDTH: u32 = 80;
+
+const MENU_COLOR: Color = Color::WHITE;
+const MENU_BAR_COLOR: Color = Color::rgb(0.93, 0.93, 0.93);
+const HIGHLIGHTED_ROW_COLOR: Color = Color::rgb(0.85, 0.89, 1.0);
+const SEPARATOR_COLOR: Color = Color::rgb(0.85, 0.85, 0.85);
+const ITEM_TEXT_COLOR: Color = Color::BLACK;
+const DISABLED_ITEM_TEXT_COLOR: Color = Color::GRAY;
+
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub enum MenuEntry {
+    Item(MenuItem),
+    Separator,
+}
+
+// An item with a submenu opens it instead of getting activated
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct MenuItem {
+    pub id: String,
+    pub label: String,
+    pub accelerator: Option<MenuAccelerator>,
+    pub disabled: bool,
+    // Checkmark items flip this when activated
+    pub checked: Option<bool>,
+    pub submenu: Vec<MenuEntry>,
+}
+
+impl MenuItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> MenuItem {
+        MenuItem {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn submenu(
+        label: impl Into<String>,
+        entries: impl IntoIterator<Item = MenuEntry>,
+    ) -> MenuItem {
+        let label = label.into();
+
+        MenuItem {
+            id: label.clone(),
+            label,
+            submenu: entries.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_accelerator(mut self, accelerator: MenuAccelerator) -> MenuItem {
+        self.accelerator = Some(accelerator);
+        self
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> MenuItem {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> MenuItem {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl From<MenuItem> for MenuEntry {
+    fn from(item: MenuItem) -> Self {
+        MenuEntry::Item(item)
+    }
+}
+
+// Activates its item when the key gets pressed with exactly these modifiers, even while the menu is closed
+// Menu bar accelerators work anywhere, context menu ones only while their element is focused
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+pub struct MenuAccelerator {
+    pub key: KeyCode,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl MenuAccelerator {
+    pub fn new(key: KeyCode) -> MenuAccelerator {
+        MenuAccelerator {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    pub fn ctrl(key: KeyCode) -> MenuAccelerator {
+        MenuAccelerator {
+            ctrl: true,
+            ..MenuAccelerator::new(key)
+        }
+    }
+
+    pub fn with_shift(mut self) -> MenuAccelerator {
+        self.shift = true;
+        self
+    }
+
+    pub fn with_alt(mut self) -> MenuAccelerator {
+        self.alt = true;
+        self
+    }
+
+    pub fn label(&self) -> String {
+        let key = format!("{:?}", self.key);
+        let key = key.strip_prefix("Key").unwrap_or(&key);
+
+        [
+            (self.ctrl, "Ctrl+"),
+            (self.shift, "Shift+"),
+            (self.alt, "Alt+"),
+        ]
+        .into_iter()
+        .filter(|(pressed, _)| *pressed)
+        .map(|(_, modifier)| modifier)
+        .chain([key])
+        .collect()
+    }
+
+    fn just_pressed(&self, keys: &Input<KeyCode>) -> bool {
+        keys.just_pressed(self.key)
+            && keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) == self.ctrl
+            && keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) == self.shift
+            && keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) == self.alt
+    }
+}
+
+// Opens at the cursor when the element gets right pressed, the element needs a collision
+// The element is made focusable, its accelerators only trigger while it's focused
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ContextMenu {
+    pub entries: Vec<MenuEntry>,
+}
+
+impl ContextMenu {
+    pub fn new(entries: impl IntoIterator<Item = MenuEntry>) -> ContextMenu {
+        ContextMenu {
+            entries: entries.into_iter().collect(),
+        }
+    }
+}
+
+// Each menu is an item with a submenu, shown as a title in the bar
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct MenuBar {
+    pub menus: Vec<MenuEntry>,
+}
+
+impl MenuBar {
+    pub fn new(menus: impl IntoIterator<Item = MenuItem>) -> MenuBar {
+        MenuBar {
+            menus: menus.into_iter().map(MenuEntry::Item).collect(),
+        }
+    }
+}
+
+// menu is the entity with the ContextMenu or the MenuBar, checked is the item's new value for checkmark items
+#[derive(Event, Debug, Clone)]
+pub struct MenuItemActivatedEvent {
+    pub menu: Entity,
+    pub id: String,
+    pub checked: Option<bool>,
+}
+
+#[derive(Bundle)]
+pub struct UiMenuBarBundle {
+    pub menu_bar: MenuBar,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+
+    pub collision: BoxCollisionBundle,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl UiMenuBarBundle {
+    pub fn new(menu_bar: MenuBar) -> UiMenuBarBundle {
+        UiMenuBarBundle {
+            menu_bar,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for UiMenuBarBundle {
+    fn default() -> Self {
+        Self {
+            menu_bar: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(800, 32),
+            color: ColoredElement::new(MENU_BAR_COLOR),
+
+            collision: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+// The open popups of a context menu or menu bar, from the outermost one to the deepest submenu
+#[derive(Component, Default)]
+struct MenuState {
+    // Each popup lists the entries of the submenu at its path, context menus open with an empty path
+    popups: Vec<(Entity, Vec<usize>)>,
+    highlighted: Option<Vec<usize>>,
+    pending_submenu: Option<(Vec<usize>, Timer)>,
+}
+
+impl MenuState {
+    fn is_open(&self) -> bool {
+        !self.popups.is_empty()
+    }
+
+    // The menu bar's open menu
+    fn title(&self) -> Option<usize> {
+        self.popups
+            .first()
+            .and_then(|(_, path)| path.first())
+            .copied()
+    }
+
+    fn close_from(&mut self, commands: &mut Commands, depth: usize) {
+        for (popup, _) in self.popups.drain(depth.min(self.popups.len())..) {
+            commands.entity(popup).despawn_recursive();
+        }
+
+        self.pending_submenu = None;
+
+        if self.popups.is_empty() {
+            self.highlighted = None;
+        }
+    }
+}
+
+#[derive(Component)]
+struct MenuPopup {
+    owner: Entity,
+}
+
+#[derive(Component)]
+struct MenuRow {
+    owner: Entity,
+    path: Vec<usize>,
+}
+
+#[derive(Component)]
+struct MenuTitle {
+    bar: Entity,
+    index: usize,
+}
+
+#[derive(Component)]
+struct MenuTitleText;
+
+fn entries_at<'a>(entries: &'a [MenuEntry], path: &[usize]) -> Option<&'a [MenuEntry]> {
+    path.iter()
+        .try_fold(entries, |entries, index| match entries.get(*index) {
+            Some(MenuEntry::Item(item)) => Some(item.submenu.as_slice()),
+            _ => None,
+        })
+}
+
+fn item_at<'a>(entries: &'a [MenuEntry], path: &[usize]) -> Option<&'a MenuItem> {
+    let (index, parent_path) = path.split_last()?;
+
+    match entries_at(entries, parent_path)?.get(*index)? {
+        MenuEntry::Item(item) => Some(item),
+        MenuEntry::Separator => None,
+    }
+}
+
+fn item_at_mut<'a>(entries: &'a mut [MenuEntry], path: &[usize]) -> Option<&'a mut MenuItem> {
+    let (index, rest) = path.split_first()?;
+
+    match entries.get_mut(*index)? {
+        MenuEntry::Item(item) if rest.is_empty() => Some(item),
+        MenuEntry::Item(item) => item_at_mut(&mut item.submenu, rest),
+        MenuEntry::Separator => None,
+    }
+}
+
+fn menu_entries<'a>(
+    context_menu: Option<&'a ContextMenu>,
+    menu_bar: Option<&'a MenuBar>,
+) -> &'a [MenuEntry] {
+    match (context_menu, menu_bar) {
+        (Some(context_menu), _) => &context_menu.entries,
+        (_, Some(menu_bar)) => &menu_bar.menus,
+        _ => &[],
+    }
+}
+
+fn is_selectable(entry: &MenuEntry) -> bool {
+    matches!(entry, MenuEntry::Item(item) if !item.disabled)
+}
+
+// The next selectable entry in the direction, wrapping around
+fn step_selection(entries: &[MenuEntry], current: Option<usize>, backwards: bool) -> Option<usize> {
+    let count = entries.len();
+
+    (1..=count)
+        .map(|step| match (current, backwards) {
+            (Some(current), false) => (current + step) % count,
+            (Some(current), true) => (current + count - step % count) % count,
+            (None, false) => step - 1,
+            (None, true) => count - step,
+        })
+        .find(|index| is_selectable(&entries[*index]))
+}
+
+fn window_size(primary_window: &Query<&Window, With<PrimaryWindow>>) -> Vec2 {
+    match primary_window.get_single() {
+        Ok(primary_window) => Vec2::new(primary_window.width(), primary_window.height()),
+        Err(_) => {
+            warn!("Couldn't get primary window");
+
+            Vec2::splat(f32::MAX)
+        }
+    }
+}
+
+fn popup_size(entries: &[MenuEntry]) -> Size {
+    let rows_height = entries
+        .iter()
+        .map(|entry| match entry {
+            MenuEntry::Item(_) => ROW_HEIGHT,
+            MenuEntry::Separator => SEPARATOR_HEIGHT,
+        })
+        .sum::<u32>();
+
+    Size::new(MENU_WIDTH, rows_height + MENU_PADDING * 2)
+}
+
+// Keeps the popup inside the window, preferring to cover its top left corner when it's larger than the window
+fn clamp_to_window(position: Vec2, size: &Size, window_size: Vec2) -> Position {
+    let max_position = (window_size - Vec2::from(size.clone())).max(Vec2::ZERO);
+
+    Position::from(position.clamp(Vec2::ZERO, max_position))
+}
+
+fn spawn_menu_popup(
+    commands: &mut Commands,
+    owner: Entity,
+    entries: &[MenuEntry],
+    path: Vec<usize>,
+    position: Position,
+) -> Entity {
+    let size = popup_size(entries);
+    let depth = path.len() as i32;
+
+    let popup = commands
+        .spawn((
+            UiBoxBundle {
+                position: position.clone(),
+                size: size.clone(),
+                color: ColoredElement::new(MENU_COLOR),
+                ..Default::default()
+            },
+            CornersRoundness::from_scalar(0.03),
+            BoxCollisionBundle::default(),
+            Overlay,
+            // Each submenu is painted over the menu it opened from
+            ZIndex::Global(POPUP_Z_LEVEL + depth * 10),
+            MenuPopup { owner },
+        ))
+        .id();
+
+    let mut row_y = position.y + MENU_PADDING;
+
+    for (index, entry) in entries.iter().enumerate() {
+        let row = match entry {
+            MenuEntry::Separator => {
+                let separator = commands
+                    .spawn(UiBoxBundle {
+                        position: Position::new(
+                            position.x + MENU_PADDING,
+                            row_y + SEPARATOR_HEIGHT / 2,
+                        ),
+                        size: Size::new(size.width - MENU_PADDING * 2, 1),
+                        color: ColoredElement::new(SEPARATOR_COLOR),
+                        ..Default::default()
+                    })
+                    .id();
+
+                row_y += SEPARATOR_HEIGHT;
+                separator
+            }
+            MenuEntry::Item(item) => {
+                let row_position = Position::new(position.x, row_y);
+                let row = spawn_menu_row(commands, owner, item, &path, index, row_position);
+
+                row_y += ROW_HEIGHT;
+                row
+            }
+        };
+
+        commands.entity(popup).add_child(row);
+    }
+
+    commands.entity(owner).add_child(popup);
+
+    popup
+}
+
+fn spawn_menu_row(
+    commands: &mut Commands,
+    owner: Entity,
+    item: &MenuItem,
+    path: &[usize],
+    index: usize,
+    position: Position,
+) -> Entity {
+    let text_color = if item.disabled {
+        DISABLED_ITEM_TEXT_COLOR
+    } else {
+        ITEM_TEXT_COLOR
+    };
+
+    let text = |text: String, x: u32, width: u32, align: TextAlign| {
+        (
+            UiTextBundle {
+                text: UiText(text),
+                font_size: FontSize(18),
+                color: ColoredElement::new(text_color),
+                position: Position::new(x, position.y),
+                size: Size::new(width, ROW_HEIGHT),
+                ..Default::default()
+            },
+            align,
+            VerticalAlign::Center,
+            TextOverflow::Ellipsis,
+        )
+    };
+
+    let accelerator_width = item.accelerator.map_or(0, |_| ACCELERATOR_WIDTH);
+    let label_width = MENU_WIDTH - ROW_MARGIN * 2 - accelerator_width;
+    let accelerator_x = position.x + MENU_WIDTH - ROW_MARGIN - ACCELERATOR_WIDTH;
+    let arrow_x = position.x + MENU_WIDTH - ROW_MARGIN;
+
+    let mut row = commands.spawn((
+        UiBoxBundle {
+            position: position.clone(),
+            size: Size::new(MENU_WIDTH, ROW_HEIGHT),
+            color: ColoredElement::new(MENU_COLOR),
+            ..Default::default()
+        },
+        BoxCollisionBundle::default(),
+        MenuRow {
+            owner,
+            path: path.iter().copied().chain([index]).collect(),
+        },
+    ));
+
+    // Disabled rows don't get hovered or clicked
+    if item.disabled {
+        row.insert(Disabled);
+    }
+
+    row.with_children(|row| {
+        if item.checked == Some(true) {
+            row.spawn(text(
+                String::from("✓"),
+                position.x,
+                ROW_MARGIN,
+                TextAlign::Center,
+            ));
+        }
+
+        row.spawn(text(
+            item.label.clone(),
+            position.x + ROW_MARGIN,
+            label_width,
+            TextAlign::Start,
+        ));
+
+        if let Some(accelerator) = &item.accelerator {
+            row.spawn(text(
+                accelerator.label(),
+                accelerator_x,
+                ACCELERATOR_WIDTH,
+                TextAlign::End,
+            ));
+        }
+
+        if !item.submenu.is_empty() {
+            row.spawn(text(
+                String::from("›"),
+                arrow_x,
+                ROW_MARGIN,
+                TextAlign::Center,
+            ));
+        }
+    });
+
+    row.id()
+}
+
+// Opens the submenu of the row next to the popup holding it, on the other side when there's no room left
+fn open_submenu(
+    commands: &mut Commands,
+    owner: Entity,
+    state: &mut MenuState,
+    entries: &[MenuEntry],
+    row_path: &[usize],
+    rows: &Query<(&MenuRow, &Position)>,
+    popups: &Query<(&Position, &Size), With<MenuPopup>>,
+    window_size: Vec2,
+) {
+    let Some(item) = item_at(entries, row_path) else {
+        return;
+    };
+
+    let depth = row_path.len() - entries_depth_offset(state);
+    state.close_from(commands, depth);
+
+    if item.disabled || item.submenu.is_empty() {
+        return;
+    }
+
+    let Some(row_position) = rows
+        .iter()
+        .find(|(row, _)| row.owner == owner && row.path == row_path)
+        .map(|(_, position)| position)
+    else {
+        return;
+    };
+
+    let Some((parent_position, parent_size)) = state
+        .popups
+        .last()
+        .and_then(|(popup, _)| popups.get(*popup).ok())
+    else {
+        return;
+    };
+
+    let size = popup_size(&item.submenu);
+    let right = parent_position.x as f32 + parent_size.width as f32 - MENU_PADDING as f32;
+    let left = parent_position.x as f32 - size.width as f32 + MENU_PADDING as f32;
+
+    let x = if right + size.width as f32 > window_size.x && left >= 0.0 {
+        left
+    } else {
+        right
+    };
+
+    let position = clamp_to_window(
+        Vec2::new(x, row_position.y as f32 - MENU_PADDING as f32),
+        &size,
+        window_size,
+    );
+
+    let popup = spawn_menu_popup(commands, owner, &item.submenu, row_path.to_vec(), position);
+    state.popups.push((popup, row_path.to_vec()));
+}
+
+// Paths in menu bars start with the index of the open menu, which has no popup of its own
+fn entries_depth_offset(state: &MenuState) -> usize {
+    state.popups.first().map_or(0, |(_, path)| path.len())
+}
+
+fn open_menu_bar_menu(
+    commands: &mut Commands,
+    bar: Entity,
+    state: &mut MenuState,
+    menus: &[MenuEntry],
+    index: usize,
+    titles: &Query<(&MenuTitle, &Position, &Size)>,
+    bar_rect: (&Position, &Size),
+    window_size: Vec2,
+) {
+    state.close_from(commands, 0);
+
+    let Some(MenuEntry::Item(menu)) = menus.get(index) else {
+        return;
+    };
+
+    if menu.disabled {
+        return;
+    }
+
+    let (bar_position, bar_size) = bar_rect;
+
+    let x = titles
+        .iter()
+        .find(|(title, _, _)| title.bar == bar && title.index == index)
+        .map_or(bar_position.x, |(_, position, _)| position.x);
+
+    let size = popup_size(&menu.submenu);
+    let position = clamp_to_window(
+        Vec2::new(x as f32, (bar_position.y + bar_size.height) as f32),
+        &size,
+        window_size,
+    );
+
+    let popup = spawn_menu_popup(commands, bar, &menu.submenu, vec![index], position);
+    state.popups.push((popup, vec![index]));
+}
+
+fn init_menus(
+    mut commands: Commands,
+    context_menus: Query<Entity, Added<ContextMenu>>,
+    menu_bars: Query<(Entity, &MenuBar, &Position, &Size), Added<MenuBar>>,
+) {
+    for entity in context_menus.iter() {
+        commands
+            .entity(entity)
+            .insert((MenuState::default(), Focusable));
+    }
+
+    // Titles get laid out side by side by layout_menu_titles once their text is shaped
+    for (entity, menu_bar, position, size) in menu_bars.iter() {
+        commands
+            .entity(entity)
+            .insert(MenuState::default())
+            .with_children(|bar| {
+                for (index, menu) in menu_bar.menus.iter().enumerate() {
+                    let MenuEntry::Item(menu) = menu else {
+                        continue;
+                    };
+
+                    let mut title = bar.spawn((
+                        UiBoxBundle {
+                            position: position.clone(),
+                            size: Size::new(0, size.height),
+                            color: ColoredElement::new(MENU_BAR_COLOR),
+                            ..Default::default()
+                        },
+                        HoverEffect::new(ColoredElement::new(HIGHLIGHTED_ROW_COLOR)),
+                        BoxCollisionBundle::default(),
+                        MenuTitle { bar: entity, index },
+                    ));
+
+                    if menu.disabled {
+                        title.insert(Disabled);
+                    }
+
+                    title.with_children(|title| {
+                        title.spawn((
+                            UiTextBundle {
+                                text: UiText(menu.label.clone()),
+                                font_size: FontSize(18),
+                                color: ColoredElement::new(if menu.disabled {
+                                    DISABLED_ITEM_TEXT_COLOR
+                                } else {
+                                    ITEM_TEXT_COLOR
+                                }),
+                                position: position.clone(),
+                                size: Size::new(MENU_WIDTH, size.height),
+                                ..Default::default()
+                            },
+                            VerticalAlign::Center,
+                            MenuTitleText,
+                        ));
+                    });
+                }
+            });
+    }
+}
+
+fn layout_menu_titles(
+    bars: Query<(&Position, &Size, &Children), With<MenuBar>>,
+    changed_bars: Query<Entity, (With<MenuBar>, Or<(Changed<Position>, Changed<Size>)>)>,
+    changed_texts: Query<&Parent, (With<MenuTitleText>, Changed<TextLayoutInfo>)>,
+    titles: Query<(&MenuTitle, &Children)>,
+    text_layouts: Query<&TextLayoutInfo, With<MenuTitleText>>,
+    mut rects: Query<(&mut Position, &mut Size), Without<MenuBar>>,
+) {
+    let changed_titles = changed_texts.iter().map(|parent| parent.get());
+    let changed_bars = changed_bars.iter().chain(
+        changed_titles.filter_map(|title| titles.get(title).ok().map(|(title, _)| title.bar)),
+    );
+
+    for bar in changed_bars {
+        let Ok((bar_position, bar_size, bar_children)) = bars.get(bar) else {
+            continue;
+        };
+
+        let mut x = bar_position.x + MENU_PADDING;
+
+        for title_entity in bar_children.iter() {
+            let Ok((_, title_children)) = titles.get(*title_entity) else {
+                continue;
+            };
+
+            let Some((text_entity, text_width)) = title_children.iter().find_map(|child| {
+                text_layouts.get(*child).ok().map(|layout_info| {
+                    let width = layout_info
+                        .glyphs
+                        .iter()
+                        .map(|glyph| glyph.rect.max.x)
+                        .fold(0.0f32, f32::max);
+
+                    (*child, width.ceil() as u32 + 1)
+                })
+            }) else {
+                continue;
+            };
+
+            let title_rect = (
+                Position::new(x, bar_position.y),
+                Size::new(text_width + TITLE_PADDING * 2, bar_size.height),
+            );
+            let text_rect = (
+                Position::new(x + TITLE_PADDING, bar_position.y),
+                Size::new(text_width, bar_size.height),
+            );
+
+            x += title_rect.1.width;
+
+            for (entity, (new_position, new_size)) in
+                [(*title_entity, title_rect), (text_entity, text_rect)]
+            {
+                let Ok((mut position, mut size)) = rects.get_mut(entity) else {
+                    continue;
+                };
+
+                if *position != new_position {
+                    *position = new_position;
+                }
+
+                if *size != new_size {
+                    *size = new_size;
+                }
+            }
+        }
+    }
+}
+
+// Right pressing an element opens its context menu at the cursor, pressing a title toggles its menu
+// Pressing anywhere outside of an open menu closes it
+#[allow(clippy::too_many_arguments)]
+fn open_menus(
+    mut commands: Commands,
+    mouse_buttons: Res<Input<MouseButton>>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mut owners: Query<(
+        Entity,
+        &mut MenuState,
+        Option<&ContextMenu>,
+        Option<&MenuBar>,
+        &Position,
+        &Size,
+    )>,
+    z_levels: Query<&ZLevel>,
+    popups: Query<&MenuPopup>,
+    rows: Query<&MenuRow>,
+    titles: Query<(&MenuTitle, &Position, &Size)>,
+    mut press_events: EventReader<PressEvent>,
+    mut secondary_press_events: EventReader<SecondaryPressEvent>,
+) {
+    let pressed = press_events
+        .read()
+        .map(|press_event| press_event.element)
+        .collect::<Vec<_>>();
+    let secondary_pressed = secondary_press_events
+        .read()
+        .map(|press_event| press_event.element)
+        .collect::<Vec<_>>();
+
+    if !mouse_buttons.any_just_pressed([MouseButton::Left, MouseButton::Right]) {
+        return;
+    }
+
+    // The menus the pressed elements belong to, pressing a context menu's element isn't pressing its menu
+    let pressed_owners = pressed
+        .iter()
+        .chain(&secondary_pressed)
+        .filter_map(|element| {
+            popups
+                .get(*element)
+                .map(|popup| popup.owner)
+                .or_else(|_| rows.get(*element).map(|row| row.owner))
+                .or_else(|_| titles.get(*element).map(|(title, _, _)| title.bar))
+                .ok()
+                .or_else(|| {
+                    owners
+                        .get(*element)
+                        .is_ok_and(|(_, _, _, menu_bar, ..)| menu_bar.is_some())
+                        .then_some(*element)
+                })
+        })
+        .collect::<Vec<_>>();
+
+    // Only the topmost of overlapping elements opens its context menu
+    let pressed_context_menu = secondary_pressed
+        .iter()
+        .filter(|element| {
+            owners
+                .get(**element)
+                .is_ok_and(|(_, _, context_menu, ..)| context_menu.is_some())
+        })
+        .max_by_key(|element| z_levels.get(**element).map_or(0, |z_level| z_level.0))
+        .copied();
+
+    let pressed_title = pressed
+        .iter()
+        .find_map(|element| titles.get(*element).ok())
+        .map(|(title, _, _)| (title.bar, title.index));
+
+    let window_size = window_size(&primary_window);
+
+    let cursor_position = primary_window
+        .get_single()
+        .ok()
+        .and_then(|primary_window| primary_window.cursor_position());
+
+    for (entity, mut state, context_menu, menu_bar, position, size) in owners.iter_mut() {
+        let entries = menu_entries(context_menu, menu_bar);
+
+        if pressed_context_menu == Some(entity) {
+            let Some(cursor_position) = cursor_position else {
+                continue;
+            };
+
+            state.close_from(&mut commands, 0);
+
+            let popup_position =
+                clamp_to_window(cursor_position, &popup_size(entries), window_size);
+            let popup =
+                spawn_menu_popup(&mut commands, entity, entries, Vec::new(), popup_position);
+
+            state.popups.push((popup, Vec::new()));
+            continue;
+        }
+
+        if let Some((_, index)) = pressed_title.filter(|(bar, _)| *bar == entity) {
+            if state.title() == Some(index) {
+                state.close_from(&mut commands, 0);
+            } else {
+                open_menu_bar_menu(
+                    &mut commands,
+                    entity,
+                    &mut state,
+                    entries,
+                    index,
+                    &titles,
+                    (position, size),
+                    window_size,
+                );
+            }
+
+            continue;
+        }
+
+        if state.is_open() && !pressed_owners.contains(&entity) {
+            state.close_from(&mut commands, 0);
+        }
+    }
+}
+
+// Hovering a row highlights it & opens its submenu after a delay, hovering another title switches the open menu bar menu
+#[allow(clippy::too_many_arguments)]
+fn hover_menus(
+    mut commands: Commands,
+    time: Res<Time>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mut owners: Query<(
+        Entity,
+        &mut MenuState,
+        Option<&ContextMenu>,
+        Option<&MenuBar>,
+        &Position,
+        &Size,
+    )>,
+    rows: Query<(&MenuRow, &Position)>,
+    popups: Query<(&Position, &Size), With<MenuPopup>>,
+    titles: Query<(&MenuTitle, &Position, &Size)>,
+    mut hover_enter_events: EventReader<HoverEnterEvent>,
+) {
+    let window_size = window_size(&primary_window);
+
+    for hover_enter_event in hover_enter_events.read() {
+        if let Ok((row, _)) = rows.get(hover_enter_event.element) {
+            let Ok((_, mut state, ..)) = owners.get_mut(row.owner) else {
+                continue;
+            };
+
+            state.highlighted = Some(row.path.clone());
+            state.pending_submenu =
+                Some((row.path.clone(), Timer::new(SUBMENU_DELAY, TimerMode::Once)));
+        } else if let Ok((title, _, _)) = titles.get(hover_enter_event.element) {
+            let Ok((bar, mut state, context_menu, menu_bar, position, size)) =
+                owners.get_mut(title.bar)
+            else {
+                continue;
+            };
+
+            if state.is_open() && state.title() != Some(title.index) {
+                open_menu_bar_menu(
+                    &mut commands,
+                    bar,
+                    &mut state,
+                    menu_entries(context_menu, menu_bar),
+                    title.index,
+                    &titles,
+                    (position, size),
+                    window_size,
+                );
+            }
+        }
+    }
+
+    for (entity, mut state, context_menu, menu_bar, _, _) in owners.iter_mut() {
+        let Some((_, timer)) = &mut state.pending_submenu else {
+            continue;
+        };
+
+        if !timer.tick(time.delta()).finished() {
+            continue;
+        }
+
+        let Some((row_path, _)) = state.pending_submenu.take() else {
+            continue;
+        };
+
+        open_submenu(
+            &mut commands,
+            entity,
+            &mut state,
+            menu_entries(context_menu, menu_bar),
+            &row_path,
+            &rows,
+            &popups,
+            window_size,
+        );
+    }
+}
+
+// Flips the checkmark of the item & closes the menu, items with a submenu aren't activated
+fn activate_menu_item(
+    commands: &mut Commands,
+    owner: Entity,
+    state: &mut MenuState,
+    context_menu: Option<Mut<ContextMenu>>,
+    menu_bar: Option<Mut<MenuBar>>,
+    path: &[usize],
+    activated_events: &mut EventWriter<MenuItemActivatedEvent>,
+) {
+    let Some(item) = item_at(
+        menu_entries(context_menu.as_deref(), menu_bar.as_deref()),
+        path,
+    ) else {
+        return;
+    };
+
+    if item.disabled || !item.submenu.is_empty() {
+        return;
+    }
+
+    let id = item.id.clone();
+    let checked = item.checked.map(|checked| !checked);
+
+    // Only checkable items get written back, so activating the others doesn't mark the menu changed
+    if checked.is_some() {
+        let entries = match (context_menu, menu_bar) {
+            (Some(context_menu), _) => &mut context_menu.into_inner().entries,
+            (_, Some(menu_bar)) => &mut menu_bar.into_inner().menus,
+            _ => return,
+        };
+
+        if let Some(item) = item_at_mut(entries, path) {
+            item.checked = checked;
+        }
+    }
+
+    activated_events.send(MenuItemActivatedEvent {
+        menu: owner,
+        id,
+        checked,
+    });
+
+    state.close_from(commands, 0);
+}
+
+fn activate_clicked_menu_items(
+    mut commands: Commands,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mut owners: Query<(
+        &mut MenuState,
+        Option<&mut ContextMenu>,
+        Option<&mut MenuBar>,
+    )>,
+    rows: Query<(&MenuRow, &Position)>,
+    popups: Query<(&Position, &Size), With<MenuPopup>>,
+    mut click_events: EventReader<ClickEvent>,
+    mut activated_events: EventWriter<MenuItemActivatedEvent>,
+) {
+    for click_event in click_events.read() {
+        let Ok((row, _)) = rows.get(click_event.element) else {
+            continue;
+        };
+
+        let Ok((mut state, context_menu, menu_bar)) = owners.get_mut(row.owner) else {
+            continue;
+        };
+
+        let has_submenu = item_at(
+            menu_entries(context_menu.as_deref(), menu_bar.as_deref()),
+            &row.path,
+        )
+        .is_some_and(|item| !item.submenu.is_empty());
+
+        // Clicking a row with a submenu opens it right away
+        if has_submenu {
+            open_submenu(
+                &mut commands,
+                row.owner,
+                &mut state,
+                menu_entries(context_menu.as_deref(), menu_bar.as_deref()),
+                &row.path,
+                &rows,
+                &popups,
+                window_size(&primary_window),
+            );
+        } else {
+            activate_menu_item(
+                &mut commands,
+                row.owner,
+                &mut state,
+                context_menu,
+                menu_bar,
+                &row.path,
+                &mut activated_events,
+            );
+        }
+    }
+}
+
+// While a menu is open: Up / Down move the highlight, Right / Enter open a submenu, Left / Escape close it
+// Enter / Space activate the highlighted item, Left / Right switch between the menus of a menu bar
+#[allow(clippy::too_many_arguments)]
+fn navigate_menus(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mut owners: Query<(
+        Entity,
+        &mut MenuState,
+        Option<&mut ContextMenu>,
+        Option<&mut MenuBar>,
+        &Position,
+        &Size,
+    )>,
+    rows: Query<(&MenuRow, &Position)>,
+    popups: Query<(&Position, &Size), With<MenuPopup>>,
+    titles: Query<(&MenuTitle, &Position, &Size)>,
+    mut activated_events: EventWriter<MenuItemActivatedEvent>,
+) {
+    const NAVIGATION_KEYS: [KeyCode; 8] = [
+        KeyCode::Up,
+        KeyCode::Down,
+        KeyCode::Left,
+        KeyCode::Right,
+        KeyCode::Return,
+        KeyCode::NumpadEnter,
+        KeyCode::Space,
+        KeyCode::Escape,
+    ];
+
+    if !keys.any_just_pressed(NAVIGATION_KEYS) {
+        return;
+    }
+
+    let window_size = window_size(&primary_window);
+
+    for (entity, mut state, context_menu, menu_bar, position, size) in owners.iter_mut() {
+        let Some((_, popup_path)) = state.popups.last().cloned() else {
+            continue;
+        };
+
+        let is_menu_bar = menu_bar.is_some();
+        let entries = menu_entries(context_menu.as_deref(), menu_bar.as_deref());
+
+        let Some(popup_entries) = entries_at(entries, &popup_path) else {
+            continue;
+        };
+
+        // The highlighted index in the deepest popup
+        let current = state
+            .highlighted
+            .as_ref()
+            .and_then(|highlighted| highlighted.split_last())
+            .filter(|(_, parent_path)| *parent_path == popup_path.as_slice())
+            .map(|(index, _)| *index);
+
+        let highlighted_path = current.map(|current| {
+            popup_path
+                .iter()
+                .copied()
+                .chain([current])
+                .collect::<Vec<_>>()
+        });
+
+        let highlighted_submenu = highlighted_path
+            .as_ref()
+            .and_then(|path| item_at(entries, path))
+            .is_some_and(|item| !item.disabled && !item.submenu.is_empty());
+
+        if keys.any_just_pressed([KeyCode::Up, KeyCode::Down]) {
+            let backwards = keys.just_pressed(KeyCode::Up);
+
+            if let Some(index) = step_selection(popup_entries, current, backwards) {
+                state.highlighted = Some(popup_path.iter().copied().chain([index]).collect());
+                state.pending_submenu = None;
+            }
+        } else if highlighted_submenu
+            && keys.any_just_pressed([KeyCode::Right, KeyCode::Return, KeyCode::NumpadEnter])
+        {
+            let Some(path) = highlighted_path else {
+                continue;
+            };
+
+            open_submenu(
+                &mut commands,
+                entity,
+                &mut state,
+                entries,
+                &path,
+                &rows,
+                &popups,
+                window_size,
+            );
+
+            if let Some(submenu) = entries_at(entries, &path) {
+                state.highlighted = step_selection(submenu, None, false)
+                    .map(|index| path.iter().copied().chain([index]).collect());
+            }
+        } else if keys.any_just_pressed([KeyCode::Left, KeyCode::Right])
+            && is_menu_bar
+            && (state.popups.len() == 1 || keys.just_pressed(KeyCode::Right))
+        {
+            let Some(title) = state.title() else {
+                continue;
+            };
+
+            let backwards = keys.just_pressed(KeyCode::Left);
+
+            if let Some(index) = step_selection(entries, Some(title), backwards) {
+                open_menu_bar_menu(
+                    &mut commands,
+                    entity,
+                    &mut state,
+                    entries,
+                    index,
+                    &titles,
+                    (position, size),
+                    window_size,
+                );
+
+                if let Some(submenu) = entries_at(entries, &[index]) {
+                    state.highlighted =
+                        step_selection(submenu, None, false).map(|first| vec![index, first]);
+                }
+            }
+        } else if keys.any_just_pressed([KeyCode::Left, KeyCode::Escape]) {
+            // Closing a submenu highlights the row it was opened from
+            let depth = state.popups.len() - 1;
+            state.close_from(&mut commands, depth);
+
+            if state.is_open() {
+                state.highlighted = Some(popup_path);
+            }
+        } else if keys.any_just_pressed([KeyCode::Return, KeyCode::NumpadEnter, KeyCode::Space]) {
+            let Some(path) = highlighted_path else {
+                continue;
+            };
+
+            activate_menu_item(
+                &mut commands,
+                entity,
+                &mut state,
+                context_menu,
+                menu_bar,
+                &path,
+                &mut activated_events,
+            );
+        }
+    }
+}
+
+fn trigger_menu_accelerators(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    mut owners: Query<(
+        Entity,
+        &mut MenuState,
+        Option<&mut ContextMenu>,
+        Option<&mut MenuBar>,
+        Has<Focused>,
+    )>,
+    mut activated_events: EventWriter<MenuItemActivatedEvent>,
+) {
+    if keys.get_just_pressed().next().is_none() {
+        return;
+    }
+
+    for (entity, mut state, context_menu, menu_bar, focused) in owners.iter_mut() {
+        if context_menu.is_some() && !focused {
+            continue;
+        }
+
+        let entries = menu_entries(context_menu.as_deref(), menu_bar.as_deref());
+
+        let Some(path) = accelerated_item(entries, &keys, &mut Vec::new()) else {
+            continue;
+        };
+
+        activate_menu_item(
+            &mut commands,
+            entity,
+            &mut state,
+            context_menu,
+            menu_bar,
+            &path,
+            &mut activated_events,
+        );
+    }
+}
+
+// The path of the first enabled item whose accelerator just got pressed, items in disabled submenus are skipped
+fn accelerated_item(
+    entries: &[MenuEntry],
+    keys: &Input<KeyCode>,
+    path: &mut Vec<usize>,
+) -> Option<Vec<usize>> {
+    for (index, entry) in entries.iter().enumerate() {
+        let MenuEntry::Item(item) = entry else {
+            continue;
+        };
+
+        if item.disabled {
+            continue;
+        }
+
+        path.push(index);
+
+        if item.submenu.is_empty() {
+            if item
+                .accelerator
+                .is_some_and(|accelerator| accelerator.just_pressed(keys))
+            {
+                return Some(path.clone());
+            }
+        } else if let Some(path) = accelerated_item(&item.submenu, keys, path) {
+            return Some(path);
+        }
+
+        path.pop();
+    }
+
+    None
+}
+
+// Highlights the row picked by the cursor or the keyboard, along with the rows leading to the open submenus
+fn highlight_menus(
+    states: Query<&MenuState, Changed<MenuState>>,
+    mut rows: Query<(&MenuRow, &mut ColoredElement), Without<MenuTitle>>,
+    mut titles: Query<(&MenuTitle, &mut ColoredElement), Without<MenuRow>>,
+) {
+    if states.is_empty() {
+        return;
+    }
+
+    for (row, mut color) in rows.iter_mut() {
+        let Ok(state) = states.get(row.owner) else {
+            continue;
+        };
+
+        let highlighted = state.highlighted.as_ref() == Some(&row.path)
+            || state
+                .popups
+                .iter()
+                .skip(1)
+                .any(|(_, path)| *path == row.path);
+
+        let new_color = if highlighted {
+            HIGHLIGHTED_ROW_COLOR
+        } else {
+            MENU_COLOR
+        };
+
+        if color.color != new_color {
+            color.color = new_color;
+        }
+    }
+
+    for (title, mut color) in titles.iter_mut() {
+        let Ok(state) = states.get(title.bar) else {
+            continue;
+        };
+
+        let new_color = if state.title() == Some(title.index) {
+            ACCENT_COLOR.with_a(0.3)
+        } else {
+            MENU_BAR_COLOR
+        };
+
+        if color.color != new_color {
+            color.color = new_color;
+        }
+    }
+}
@@ -0,0 +1,203 @@
+pub mod button;
+pub mod checkbox;
+pub mod data_table;
+pub mod dropdown;
+pub mod menu;
+pub mod radio;
+pub mod slider;
+pub mod toggle_switch;
+pub mod tooltip;
+pub mod tree_view;
+pub mod virtual_list;
+
+pub use button::{ButtonActivated, ButtonLabel, UiButton, UiButtonBundle};
+pub use checkbox::{Checkbox, UiCheckboxBundle};
+pub use data_table::{
+    DataColumn, DataTable, DataTableColumnResizedEvent, DataTableSelectionChangedEvent,
+    DataTableSort, DataTableSortChangedEvent, SortOrder, UiDataTableBundle,
+};
+pub use dropdown::{Dropdown, SelectionChangedEvent, UiDropdownBundle};
+pub use menu::{
+    ContextMenu, MenuAccelerator, MenuBar, MenuEntry, MenuItem, MenuItemActivatedEvent,
+    UiMenuBarBundle,
+};
+pub use radio::{RadioButton, RadioGroup, UiRadioButtonBundle};
+pub use slider::{
+    RangeSlider, Slider, SliderChangedEvent, SliderOrientation, SliderValue, UiRangeSliderBundle,
+    UiSliderBundle,
+};
+pub use toggle_switch::{ToggleSwitch, UiToggleSwitchBundle};
+pub use tooltip::{Tooltip, TooltipPlacement};
+pub use tree_view::{
+    TreeDropPlacement, TreeNode, TreeNodeDroppedEvent, TreeNodeToggledEvent,
+    TreeSelectionChangedEvent, TreeView, UiTreeNodeBundle, UiTreeViewBundle,
+};
+pub use virtual_list::{
+    RowHeight, UiVirtualListBundle, VirtualList, VirtualListRowBuilder, VirtualRow,
+};
+
+use bevy::prelude::{
+    Changed, Children, Color, Component, Entity, Event, EventReader, EventWriter, Has, Input,
+    KeyCode, Or, Plugin, Query, Res, Update, With, Without,
+};
+
+use crate::{
+    event::ClickEvent,
+    property::{
+        focus::Focused,
+        state::{checked::Checked, disabled::Disabled},
+        Position, Size, ZIndex,
+    },
+};
+use button::UiButtonPlugin;
+use checkbox::UiCheckboxPlugin;
+use data_table::UiDataTablePlugin;
+use dropdown::UiDropdownPlugin;
+use menu::UiMenuPlugin;
+use radio::UiRadioPlugin;
+use slider::UiSliderPlugin;
+use toggle_switch::UiToggleSwitchPlugin;
+use tooltip::UiTooltipPlugin;
+use tree_view::UiTreeViewPlugin;
+use virtual_list::UiVirtualListPlugin;
+
+#[doc(hidden)]
+pub mod prelude {
+    pub use crate::widget::{
+        ButtonActivated, ButtonLabel, Checkbox, ContextMenu, DataColumn, DataTable,
+        DataTableColumnResizedEvent, DataTableSelectionChangedEvent, DataTableSort,
+        DataTableSortChangedEvent, Dropdown, MenuAccelerator, MenuBar, MenuEntry, MenuItem,
+        MenuItemActivatedEvent, RadioButton, RadioGroup, RangeSlider, RowHeight,
+        SelectionChangedEvent, Slider, SliderChangedEvent, SliderOrientation, SliderValue,
+        SortOrder, ToggleSwitch, Tooltip, TooltipPlacement, TreeDropPlacement, TreeNode,
+        TreeNodeDroppedEvent, TreeNodeToggledEvent, TreeSelectionChangedEvent, TreeView, UiButton,
+        UiButtonBundle, UiCheckboxBundle, UiDataTableBundle, UiDropdownBundle, UiMenuBarBundle,
+        UiRadioButtonBundle, UiRangeSliderBundle, UiSliderBundle, UiToggleSwitchBundle,
+        UiTreeNodeBundle, UiTreeViewBundle, UiVirtualListBundle, ValueChangedEvent, VirtualList,
+        VirtualListRowBuilder, VirtualRow,
+    };
+}
+
+pub(crate) const ACCENT_COLOR: Color = Color::rgb(0.2, 0.4, 0.9);
+
+// Popups are overlays painted over everything else, modals included
+pub(crate) const POPUP_Z_LEVEL: i32 = 100_000;
+pub(crate) const POPUP_Z_INDEX: ZIndex = ZIndex::Global(POPUP_Z_LEVEL);
+pub(crate) const TOOLTIP_Z_INDEX: ZIndex = ZIndex::Global(200_000);
+
+pub(crate) struct UiWidgetPlugin;
+
+impl Plugin for UiWidgetPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<ValueChangedEvent<bool>>()
+            .add_plugins((
+                UiButtonPlugin,
+                UiCheckboxPlugin,
+                UiToggleSwitchPlugin,
+                UiRadioPlugin,
+                UiSliderPlugin,
+                UiDropdownPlugin,
+                UiTooltipPlugin,
+                UiMenuPlugin,
+                UiVirtualListPlugin,
+                UiTreeViewPlugin,
+                UiDataTablePlugin,
+            ))
+            .add_systems(
+                Update,
+                (
+                    layout_widget_parts,
+                    sync_checked_parts,
+                    toggle_checked_widgets,
+                ),
+            );
+    }
+}
+
+// Sent when the user changes the value of a widget, element is the widget (the group for radio buttons)
+#[derive(Event, Debug, Clone)]
+pub struct ValueChangedEvent<T: Send + Sync + 'static> {
+    pub element: Entity,
+    pub value: T,
+}
+
+// Child element drawn as a piece of its widget, laid out from the widget's position & size whenever they change
+#[derive(Component)]
+pub(crate) struct WidgetPart(pub(crate) fn(&Position, &Size) -> (Position, Size));
+
+fn layout_widget_parts(
+    widgets: Query<
+        (&Position, &Size, &Children),
+        (Without<WidgetPart>, Or<(Changed<Position>, Changed<Size>)>),
+    >,
+    mut parts: Query<(&WidgetPart, &mut Position, &mut Size)>,
+) {
+    for (position, size, children) in widgets.iter() {
+        let mut widget_parts = parts.iter_many_mut(children);
+
+        while let Some((widget_part, mut part_position, mut part_size)) = widget_parts.fetch_next()
+        {
+            let (new_position, new_size) = (widget_part.0)(position, size);
+
+            if *part_position != new_position {
+                *part_position = new_position;
+            }
+
+            if *part_size != new_size {
+                *part_size = new_size;
+            }
+        }
+    }
+}
+
+// Parts styled by their widget's Checked value, like the mark of a checkbox
+#[derive(Component)]
+pub(crate) struct CheckedPart;
+
+fn sync_checked_parts(
+    widgets: Query<(&Checked, &Children), (Changed<Checked>, Without<CheckedPart>)>,
+    mut parts: Query<&mut Checked, With<CheckedPart>>,
+) {
+    for (checked, children) in widgets.iter() {
+        let mut checked_parts = parts.iter_many_mut(children);
+
+        while let Some(mut part_checked) = checked_parts.fetch_next() {
+            if *part_checked != *checked {
+                *part_checked = *checked;
+            }
+        }
+    }
+}
+
+// Checkboxes & toggle switches flip on click, or on Space while focused
+fn toggle_checked_widgets(
+    keys: Res<Input<KeyCode>>,
+    mut widgets: Query<
+        (Entity, &mut Checked, Has<Focused>),
+        (Or<(With<Checkbox>, With<ToggleSwitch>)>, Without<Disabled>),
+    >,
+    mut click_events: EventReader<ClickEvent>,
+    mut value_changed_events: EventWriter<ValueChangedEvent<bool>>,
+) {
+    let clicked = click_events
+        .read()
+        .map(|click_event| click_event.element)
+        .collect::<Vec<_>>();
+
+    let space_pressed = keys.just_pressed(KeyCode::Space);
+
+    if clicked.is_empty() && !space_pressed {
+        return;
+    }
+
+    for (entity, mut checked, focused) in widgets.iter_mut() {
+        if (space_pressed && focused) || clicked.contains(&entity) {
+            checked.0 = !checked.0;
+
+            value_changed_events.send(ValueChangedEvent {
+                element: entity,
+                value: checked.0,
+            });
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use bevy::{
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component, Entity,
+        EventReader, EventWriter, Has, Input, KeyCode, Parent, Plugin, Query, ReflectComponent,
+        Res, Update, Without,
+    },
+    reflect::Reflect,
+};
+
+use crate::{
+    element::r#box::{UiBox, UiBoxBundle},
+    event::ClickEvent,
+    property::{
+        collision::BoxCollisionBundle,
+        focus::{Focusable, Focused},
+        state::{
+            checked::{Checked, CheckedEffectTransition},
+            disabled::{Disabled, DisabledEffect},
+        },
+        update::{AutoVisibleRegionUpdate, AutoZUpdate},
+        ColoredElement, CornersRoundness, Position, Size, VisibleRegion, ZLevel,
+    },
+    widget::{
+        checkbox::CHECKED_TRANSITION, CheckedPart, ValueChangedEvent, WidgetPart, ACCENT_COLOR,
+    },
+};
+
+pub(crate) struct UiRadioPlugin;
+
+impl Plugin for UiRadioPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<ValueChangedEvent<String>>().add_systems(
+            Update,
+            (spawn_radio_dots, select_radio_buttons, sync_radio_buttons),
+        );
+    }
+}
+
+// Its RadioButton children are mutually exclusive, selecting one unchecks the others
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct RadioGroup {
+    pub selected: Option<String>,
+}
+
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct RadioButton {
+    pub value: String,
+}
+
+#[derive(Bundle)]
+pub struct UiRadioButtonBundle {
+    pub radio_button: RadioButton,
+    pub checked: Checked,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+    pub corners_roundness: CornersRoundness,
+
+    pub checked_effect: CheckedEffectTransition<ColoredElement>,
+    pub disabled_effect: DisabledEffect<ColoredElement>,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl UiRadioButtonBundle {
+    pub fn new(value: impl Into<String>) -> UiRadioButtonBundle {
+        UiRadioButtonBundle {
+            radio_button: RadioButton {
+                value: value.into(),
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for UiRadioButtonBundle {
+    fn default() -> Self {
+        Self {
+            radio_button: Default::default(),
+            checked: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(22, 22),
+            color: ColoredElement::new(Color::GRAY),
+            corners_roundness: CornersRoundness::from_scalar(1.0),
+
+            checked_effect: CheckedEffectTransition::new(
+                ColoredElement::new(ACCENT_COLOR),
+                CHECKED_TRANSITION,
+                CHECKED_TRANSITION,
+            ),
+            disabled_effect: DisabledEffect::new(ColoredElement::new(Color::SILVER)),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+fn radio_dot_layout(position: &Position, size: &Size) -> (Position, Size) {
+    let inset = size.width.min(size.height) * 3 / 10;
+
+    (
+        Position::new(position.x + inset, position.y + inset),
+        Size::new(
+            size.width.saturating_sub(inset * 2),
+            size.height.saturating_sub(inset * 2),
+        ),
+    )
+}
+
+fn spawn_radio_dots(
+    mut commands: Commands,
+    radio_buttons: Query<(Entity, &Checked, &Position, &Size), Added<RadioButton>>,
+) {
+    for (entity, checked, position, size) in radio_buttons.iter() {
+        let (dot_position, dot_size) = radio_dot_layout(position, size);
+
+        let dot = commands
+            .spawn((
+                UiBoxBundle {
+                    position: dot_position,
+                    size: dot_size,
+                    color: ColoredElement::new(Color::NONE),
+                    ..Default::default()
+                },
+                CornersRoundness::from_scalar(1.0),
+                *checked,
+                CheckedEffectTransition::new(
+                    ColoredElement::new(Color::WHITE),
+                    CHECKED_TRANSITION,
+                    CHECKED_TRANSITION,
+                ),
+                WidgetPart(radio_dot_layout),
+                CheckedPart,
+            ))
+            .id();
+
+        commands.entity(entity).add_child(dot);
+    }
+}
+
+// Radio buttons get selected on click, or on Space while focused
+fn select_radio_buttons(
+    keys: Res<Input<KeyCode>>,
+    radio_buttons: Query<(&RadioButton, &Parent, Has<Focused>), Without<Disabled>>,
+    mut radio_groups: Query<&mut RadioGroup>,
+    mut click_events: EventReader<ClickEvent>,
+    mut value_changed_events: EventWriter<ValueChangedEvent<String>>,
+) {
+    let mut selected_radio_buttons = click_events
+        .read()
+        .filter_map(|click_event| radio_buttons.get(click_event.element).ok())
+        .collect::<Vec<_>>();
+
+    if keys.just_pressed(KeyCode::Space) {
+        selected_radio_buttons.extend(radio_buttons.iter().filter(|(_, _, focused)| *focused));
+    }
+
+    for (radio_button, parent, _) in selected_radio_buttons {
+        let Ok(mut radio_group) = radio_groups.get_mut(parent.get()) else {
+            continue;
+        };
+
+        if radio_group.selected.as_ref() != Some(&radio_button.value) {
+            radio_group.selected = Some(radio_button.value.clone());
+
+            value_changed_events.send(ValueChangedEvent {
+                element: parent.get(),
+                value: radio_button.value.clone(),
+            });
+        }
+    }
+}
+
+fn sync_radio_buttons(
+    radio_groups: Query<(&RadioGroup, &Children), Changed<RadioGroup>>,
+    mut radio_buttons: Query<(&RadioButton, &mut Checked)>,
+) {
+    for (radio_group, children) in radio_groups.iter() {
+        let mut group_radio_buttons = radio_buttons.iter_many_mut(children);
+
+        while let Some((radio_button, mut checked)) = group_radio_buttons.fetch_next() {
+            let selected = radio_group.selected.as_ref() == Some(&radio_button.value);
+
+            if checked.0 != selected {
+                checked.0 = selected;
+            }
+        }
+    }
+}
@@ -0,0 +1,575 @@
+use std::cmp::Ordering;
+
+use bevy::{
+    log::warn,
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component,
+        DetectChangesMut, Entity, Event, EventReader, EventWriter, Input, IntoSystemConfigs,
+        KeyCode, MouseButton, Mut, Or, Plugin, Query, ReflectComponent, Res, Update, Vec2, With,
+        Without,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    element::r#box::UiBoxBundle,
+    event::PressEvent,
+    property::{
+        collision::BoxCollisionBundle,
+        focus::{Focusable, Focused},
+        state::disabled::Disabled,
+        update::{AutoVisibleRegionUpdate, AutoZUpdate},
+        ColoredElement, CornersRoundness, Position, Size, VisibleRegion, ZLevel,
+    },
+    widget::ACCENT_COLOR,
+};
+
+pub(crate) struct UiSliderPlugin;
+
+impl Plugin for UiSliderPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<SliderChangedEvent>().add_systems(
+            Update,
+            (
+                spawn_slider_parts::<Slider>,
+                spawn_slider_parts::<RangeSlider>,
+                (
+                    drag_slider_thumbs::<Slider>,
+                    step_focused_sliders::<Slider>,
+                    layout_sliders::<Slider>,
+                )
+                    .chain(),
+                (
+                    drag_slider_thumbs::<RangeSlider>,
+                    step_focused_sliders::<RangeSlider>,
+                    layout_sliders::<RangeSlider>,
+                )
+                    .chain(),
+            ),
+        );
+    }
+}
+
+// A step of 0 makes the slider continuous
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Slider {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    pub value: f32,
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 1.0,
+            step: 0.0,
+            value: 0.0,
+        }
+    }
+}
+
+// Two thumbs selecting the range between low & high, the thumbs can't cross each other
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct RangeSlider {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    pub low: f32,
+    pub high: f32,
+}
+
+impl Default for RangeSlider {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 1.0,
+            step: 0.0,
+            low: 0.0,
+            high: 1.0,
+        }
+    }
+}
+
+// Vertical sliders have their minimum at the bottom
+#[derive(Component, Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum SliderOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SliderValue {
+    Single(f32),
+    Range(f32, f32),
+}
+
+// Sent when the user drags a thumb, clicks the track or uses the arrow keys
+#[derive(Event, Debug, Clone)]
+pub struct SliderChangedEvent {
+    pub slider: Entity,
+    pub value: SliderValue,
+}
+
+trait SliderThumbs: Component {
+    const THUMBS: usize;
+
+    fn bounds(&self) -> (f32, f32, f32);
+    fn thumb(&self, index: usize) -> f32;
+    fn set_thumb(&mut self, index: usize, value: f32);
+    fn value(&self) -> SliderValue;
+
+    // Clamps to the bounds & rounds to the closest step
+    fn snap(&self, value: f32) -> f32 {
+        let (min, max, step) = self.bounds();
+        let value = value.clamp(min.min(max), max.max(min));
+
+        if step > 0.0 {
+            (min + ((value - min) / step).round() * step).clamp(min.min(max), max.max(min))
+        } else {
+            value
+        }
+    }
+
+    fn fraction(&self, index: usize) -> f32 {
+        let (min, max, _) = self.bounds();
+
+        if max == min {
+            0.0
+        } else {
+            ((self.thumb(index) - min) / (max - min)).clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl SliderThumbs for Slider {
+    const THUMBS: usize = 1;
+
+    fn bounds(&self) -> (f32, f32, f32) {
+        (self.min, self.max, self.step)
+    }
+
+    fn thumb(&self, _: usize) -> f32 {
+        self.value
+    }
+
+    fn set_thumb(&mut self, _: usize, value: f32) {
+        self.value = value;
+    }
+
+    fn value(&self) -> SliderValue {
+        SliderValue::Single(self.value)
+    }
+}
+
+impl SliderThumbs for RangeSlider {
+    const THUMBS: usize = 2;
+
+    fn bounds(&self) -> (f32, f32, f32) {
+        (self.min, self.max, self.step)
+    }
+
+    fn thumb(&self, index: usize) -> f32 {
+        match index {
+            0 => self.low,
+            _ => self.high,
+        }
+    }
+
+    fn set_thumb(&mut self, index: usize, value: f32) {
+        match index {
+            0 => self.low = value.min(self.high),
+            _ => self.high = value.max(self.low),
+        }
+    }
+
+    fn value(&self) -> SliderValue {
+        SliderValue::Range(self.low, self.high)
+    }
+}
+
+#[derive(Bundle)]
+pub struct UiSliderBundle {
+    pub slider: Slider,
+    pub orientation: SliderOrientation,
+
+    pub position: Position,
+    pub size: Size,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl Default for UiSliderBundle {
+    fn default() -> Self {
+        Self {
+            slider: Default::default(),
+            orientation: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(200, 24),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct UiRangeSliderBundle {
+    pub slider: RangeSlider,
+    pub orientation: SliderOrientation,
+
+    pub position: Position,
+    pub size: Size,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl Default for UiRangeSliderBundle {
+    fn default() -> Self {
+        Self {
+            slider: Default::default(),
+            orientation: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(200, 24),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+// The slider itself is only the hit area, the track, fill & thumbs are child boxes
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum SliderPart {
+    Track,
+    Fill,
+    Thumb(usize),
+}
+
+fn spawn_slider_parts<T: SliderThumbs>(mut commands: Commands, sliders: Query<Entity, Added<T>>) {
+    for entity in sliders.iter() {
+        let parts = [
+            (SliderPart::Track, Color::GRAY),
+            (SliderPart::Fill, ACCENT_COLOR),
+        ]
+        .into_iter()
+        .chain((0..T::THUMBS).map(|index| (SliderPart::Thumb(index), Color::WHITE)));
+
+        for (slider_part, color) in parts {
+            let part = commands
+                .spawn((
+                    UiBoxBundle {
+                        color: ColoredElement::new(color),
+                        ..Default::default()
+                    },
+                    CornersRoundness::from_scalar(1.0),
+                    slider_part,
+                ))
+                .id();
+
+            commands.entity(entity).add_child(part);
+        }
+    }
+}
+
+// Distance along the slider's main axis, from its minimum end
+fn main_axis_offset(
+    point: Vec2,
+    position: &Position,
+    size: &Size,
+    orientation: SliderOrientation,
+) -> f32 {
+    match orientation {
+        SliderOrientation::Horizontal => point.x - position.x as f32,
+        SliderOrientation::Vertical => (position.y + size.height) as f32 - point.y,
+    }
+}
+
+// Returns the length & thickness of the slider, the thumbs' diameter is the thickness
+fn slider_axes(size: &Size, orientation: SliderOrientation) -> (f32, f32) {
+    match orientation {
+        SliderOrientation::Horizontal => (size.width as f32, size.height as f32),
+        SliderOrientation::Vertical => (size.height as f32, size.width as f32),
+    }
+}
+
+fn value_at<T: SliderThumbs>(
+    slider: &T,
+    offset: f32,
+    size: &Size,
+    orientation: SliderOrientation,
+) -> f32 {
+    let (length, thickness) = slider_axes(size, orientation);
+    let travel = length - thickness;
+
+    let fraction = if travel > 0.0 {
+        ((offset - thickness / 2.0) / travel).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    let (min, max, _) = slider.bounds();
+    slider.snap(min + (max - min) * fraction)
+}
+
+// The thumb closest to the value, a value past two stacked thumbs picks the one it's moving towards
+fn closest_thumb<T: SliderThumbs>(slider: &T, value: f32) -> usize {
+    (0..T::THUMBS)
+        .min_by(|a, b| {
+            let distance_a = (slider.thumb(*a) - value).abs();
+            let distance_b = (slider.thumb(*b) - value).abs();
+
+            distance_a.total_cmp(&distance_b).then_with(|| {
+                if value > slider.thumb(*a) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            })
+        })
+        .unwrap_or_default()
+}
+
+#[derive(Component)]
+struct SliderDrag {
+    thumb: usize,
+}
+
+// The thumb moved by the arrow keys, the last one pressed
+#[derive(Component)]
+struct ActiveThumb(usize);
+
+// Pressing the slider jumps the closest thumb to the cursor, which then follows the cursor until the button is released
+fn drag_slider_thumbs<T: SliderThumbs>(
+    mut commands: Commands,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut sliders: Query<
+        (
+            Entity,
+            &mut T,
+            &Position,
+            &Size,
+            Option<&SliderOrientation>,
+            Option<&SliderDrag>,
+        ),
+        Without<Disabled>,
+    >,
+    mut press_events: EventReader<PressEvent>,
+    mut changed_events: EventWriter<SliderChangedEvent>,
+) {
+    let pressed = press_events
+        .read()
+        .map(|press_event| press_event.element)
+        .collect::<Vec<_>>();
+
+    let Ok(primary_window) = primary_window.get_single() else {
+        warn!("Couldn't get primary window");
+
+        return;
+    };
+
+    let Some(cursor_position) = primary_window.cursor_position() else {
+        return;
+    };
+
+    for (entity, mut slider, position, size, orientation, drag) in sliders.iter_mut() {
+        let pressed = pressed.contains(&entity);
+
+        if !pressed && drag.is_none() {
+            continue;
+        }
+
+        let orientation = orientation.copied().unwrap_or_default();
+        let offset = main_axis_offset(cursor_position, position, size, orientation);
+        let value = value_at(slider.as_ref(), offset, size, orientation);
+
+        let thumb = match drag {
+            _ if pressed => {
+                let thumb = closest_thumb(slider.as_ref(), value);
+
+                commands
+                    .entity(entity)
+                    .insert((SliderDrag { thumb }, ActiveThumb(thumb)));
+
+                thumb
+            }
+            Some(drag) if mouse_buttons.pressed(MouseButton::Left) => drag.thumb,
+            _ => {
+                commands.entity(entity).remove::<SliderDrag>();
+                continue;
+            }
+        };
+
+        set_slider_thumb(entity, &mut slider, thumb, value, &mut changed_events);
+    }
+}
+
+// Only marks the slider as changed when the thumb actually moved, range thumbs can get stopped by the other one
+fn set_slider_thumb<T: SliderThumbs>(
+    entity: Entity,
+    slider: &mut Mut<T>,
+    thumb: usize,
+    value: f32,
+    changed_events: &mut EventWriter<SliderChangedEvent>,
+) {
+    let previous_value = slider.value();
+    slider.bypass_change_detection().set_thumb(thumb, value);
+
+    if slider.value() != previous_value {
+        slider.set_changed();
+
+        changed_events.send(SliderChangedEvent {
+            slider: entity,
+            value: slider.value(),
+        });
+    }
+}
+
+// Arrow keys move the active thumb by a step (a hundredth of the range for continuous sliders), Home & End to the ends
+fn step_focused_sliders<T: SliderThumbs>(
+    keys: Res<Input<KeyCode>>,
+    mut sliders: Query<(Entity, &mut T, Option<&ActiveThumb>), (With<Focused>, Without<Disabled>)>,
+    mut changed_events: EventWriter<SliderChangedEvent>,
+) {
+    for (entity, mut slider, active_thumb) in sliders.iter_mut() {
+        let thumb = active_thumb.map_or(0, |active_thumb| active_thumb.0);
+        let (min, max, step) = slider.bounds();
+        let step = if step > 0.0 {
+            step
+        } else {
+            (max - min) / 100.0
+        };
+
+        let value = if keys.any_just_pressed([KeyCode::Left, KeyCode::Down]) {
+            slider.thumb(thumb) - step
+        } else if keys.any_just_pressed([KeyCode::Right, KeyCode::Up]) {
+            slider.thumb(thumb) + step
+        } else if keys.just_pressed(KeyCode::Home) {
+            min
+        } else if keys.just_pressed(KeyCode::End) {
+            max
+        } else {
+            continue;
+        };
+
+        let value = slider.snap(value);
+        set_slider_thumb(entity, &mut slider, thumb, value, &mut changed_events);
+    }
+}
+
+fn layout_sliders<T: SliderThumbs>(
+    sliders: Query<
+        (&T, &Position, &Size, Option<&SliderOrientation>, &Children),
+        Or<(
+            Changed<T>,
+            Changed<Position>,
+            Changed<Size>,
+            Changed<SliderOrientation>,
+            Changed<Children>,
+        )>,
+    >,
+    mut parts: Query<(&SliderPart, &mut Position, &mut Size), Without<T>>,
+) {
+    for (slider, position, size, orientation, children) in sliders.iter() {
+        let orientation = orientation.copied().unwrap_or_default();
+        let (length, thickness) = slider_axes(size, orientation);
+        let radius = thickness / 2.0;
+        let travel = (length - thickness).max(0.0);
+        let track_thickness = (thickness / 3.0).max(2.0);
+
+        let thumb_center = |index: usize| radius + slider.fraction(index) * travel;
+
+        // Converts a span along the main axis & one along the cross axis into a rect
+        let part_rect = |(main_start, main_end): (f32, f32),
+                         (cross_start, cross_end): (f32, f32)| {
+            let (main_start, main_end) = (main_start.max(0.0), main_end.max(main_start));
+
+            let (x, y, width, height) = match orientation {
+                SliderOrientation::Horizontal => (
+                    main_start,
+                    cross_start,
+                    main_end - main_start,
+                    cross_end - cross_start,
+                ),
+                SliderOrientation::Vertical => (
+                    cross_start,
+                    length - main_end,
+                    cross_end - cross_start,
+                    main_end - main_start,
+                ),
+            };
+
+            (
+                Position::new(
+                    position.x + x.max(0.0).round() as u32,
+                    position.y + y.max(0.0).round() as u32,
+                ),
+                Size::new(width.round() as u32, height.round() as u32),
+            )
+        };
+
+        let track_cross = (
+            radius - track_thickness / 2.0,
+            radius + track_thickness / 2.0,
+        );
+        let mut slider_parts = parts.iter_many_mut(children);
+
+        while let Some((slider_part, mut part_position, mut part_size)) = slider_parts.fetch_next()
+        {
+            let (new_position, new_size) = match slider_part {
+                SliderPart::Track => part_rect((0.0, length), track_cross),
+                SliderPart::Fill if T::THUMBS == 1 => {
+                    part_rect((0.0, thumb_center(0)), track_cross)
+                }
+                SliderPart::Fill => part_rect((thumb_center(0), thumb_center(1)), track_cross),
+                SliderPart::Thumb(index) => part_rect(
+                    (thumb_center(*index) - radius, thumb_center(*index) + radius),
+                    (0.0, thickness),
+                ),
+            };
+
+            if *part_position != new_position {
+                *part_position = new_position;
+            }
+
+            if *part_size != new_size {
+                *part_size = new_size;
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use bevy::{
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component, Entity, Has,
+        Or, Plugin, Query, ReflectComponent, Update, With,
+    },
+    reflect::Reflect,
+};
+
+use crate::{
+    element::r#box::{UiBox, UiBoxBundle},
+    property::{
+        collision::BoxCollisionBundle,
+        focus::Focusable,
+        state::{
+            checked::{Checked, CheckedEffectTransition, CheckedValue},
+            disabled::DisabledEffect,
+        },
+        transition::Transition,
+        update::{AutoVisibleRegionUpdate, AutoZUpdate},
+        ColoredElement, CornersRoundness, Position, Size, VisibleRegion, ZLevel,
+    },
+    widget::{checkbox::CHECKED_TRANSITION, CheckedPart, WidgetPart, ACCENT_COLOR},
+};
+
+pub(crate) struct UiToggleSwitchPlugin;
+
+impl Plugin for UiToggleSwitchPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(Update, (spawn_toggle_knobs, update_toggle_knobs));
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, Hash, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct ToggleSwitch;
+
+#[derive(Bundle)]
+pub struct UiToggleSwitchBundle {
+    pub toggle_switch: ToggleSwitch,
+    pub checked: Checked,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+    pub corners_roundness: CornersRoundness,
+
+    pub checked_effect: CheckedEffectTransition<ColoredElement>,
+    pub disabled_effect: DisabledEffect<ColoredElement>,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl Default for UiToggleSwitchBundle {
+    fn default() -> Self {
+        Self {
+            toggle_switch: Default::default(),
+            checked: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(52, 28),
+            color: ColoredElement::new(Color::GRAY),
+            corners_roundness: CornersRoundness::from_scalar(1.0),
+
+            checked_effect: CheckedEffectTransition::new(
+                ColoredElement::new(ACCENT_COLOR),
+                CHECKED_TRANSITION,
+                CHECKED_TRANSITION,
+            ),
+            disabled_effect: DisabledEffect::new(ColoredElement::new(Color::SILVER)),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+const KNOB_INSET: u32 = 3;
+
+// The knob's base layout is the unchecked one, on the left of the track
+fn knob_layout(position: &Position, size: &Size) -> (Position, Size) {
+    let diameter = size.height.saturating_sub(KNOB_INSET * 2);
+
+    (
+        Position::new(position.x + KNOB_INSET, position.y + KNOB_INSET),
+        Size::new(diameter, diameter),
+    )
+}
+
+fn checked_knob_position(position: &Position, size: &Size) -> Position {
+    let diameter = size.height.saturating_sub(KNOB_INSET * 2);
+
+    Position::new(
+        (position.x + size.width).saturating_sub(KNOB_INSET + diameter),
+        position.y + KNOB_INSET,
+    )
+}
+
+#[derive(Component)]
+struct ToggleKnob;
+
+// The knob slides to the right of the track when the switch gets checked
+fn spawn_toggle_knobs(
+    mut commands: Commands,
+    toggle_switches: Query<(Entity, &Checked, &Position, &Size), Added<ToggleSwitch>>,
+) {
+    for (entity, checked, position, size) in toggle_switches.iter() {
+        let (knob_position, knob_size) = knob_layout(position, size);
+
+        let knob = commands
+            .spawn((
+                UiBoxBundle {
+                    position: knob_position,
+                    size: knob_size,
+                    color: ColoredElement::new(Color::WHITE),
+                    ..Default::default()
+                },
+                CornersRoundness::from_scalar(1.0),
+                *checked,
+                CheckedEffectTransition::new(
+                    checked_knob_position(position, size),
+                    CHECKED_TRANSITION,
+                    CHECKED_TRANSITION,
+                ),
+                WidgetPart(knob_layout),
+                CheckedPart,
+                ToggleKnob,
+            ))
+            .id();
+
+        commands.entity(entity).add_child(knob);
+    }
+}
+
+// Moves the checked knob position along with the track, WidgetPart only lays out the unchecked one
+fn update_toggle_knobs(
+    toggle_switches: Query<
+        (&Position, &Size, &Children),
+        (With<ToggleSwitch>, Or<(Changed<Position>, Changed<Size>)>),
+    >,
+    mut knobs: Query<
+        (
+            &mut CheckedEffectTransition<Position>,
+            Option<&mut CheckedValue<Position>>,
+            Has<Transition<CheckedValue<Position>>>,
+        ),
+        With<ToggleKnob>,
+    >,
+) {
+    for (position, size, children) in toggle_switches.iter() {
+        let checked_position = checked_knob_position(position, size);
+        let mut toggle_knobs = knobs.iter_many_mut(children);
+
+        while let Some((mut checked_effect, checked_value, transitioning)) =
+            toggle_knobs.fetch_next()
+        {
+            checked_effect.value = checked_position.clone();
+
+            // A running transition already ends on the previous position, it gets corrected on the next toggle
+            if let Some(mut checked_value) = checked_value.filter(|_| !transitioning) {
+                checked_value.value = checked_position.clone();
+            }
+        }
+    }
+}
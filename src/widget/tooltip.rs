@@ -0,0 +1,392 @@
+use std::time::Duration;
+
+use bevy::{
+    log::warn,
+    prelude::{
+        BuildChildren, Changed, Children, Color, Commands, Component, DespawnRecursiveExt, Entity,
+        EventReader, Parent, Plugin, Query, ReflectComponent, Res, Time, Timer, TimerMode, Update,
+        With, Without,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    element::{
+        r#box::UiBoxBundle,
+        text::{FontSize, TextLayoutInfo, UiText, UiTextBundle},
+    },
+    event::{HoverEnterEvent, HoverExitEvent, PressEvent},
+    property::{
+        transition::Transition, update::Overlay, ColoredElement, CornersRoundness, Position, Size,
+    },
+    widget::TOOLTIP_Z_INDEX,
+};
+
+pub(crate) struct UiTooltipPlugin;
+
+impl Plugin for UiTooltipPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(
+            Update,
+            (
+                track_tooltip_hovers,
+                show_tooltips,
+                update_tooltip_texts,
+                fit_tooltips,
+                despawn_faded_tooltips,
+            ),
+        );
+    }
+}
+
+const FADE_DURATION: Duration = Duration::from_millis(150);
+
+// Distance between the tooltip & its element, and between the tooltip's border & its text
+const TOOLTIP_GAP: u32 = 6;
+const TOOLTIP_PADDING: u32 = 6;
+// Longer contents wrap onto several lines
+const TOOLTIP_MAX_WIDTH: u32 = 320;
+
+const TOOLTIP_COLOR: Color = Color::rgba(0.1, 0.1, 0.1, 0.9);
+const TOOLTIP_TEXT_COLOR: Color = Color::WHITE;
+
+// Shown next to the element once the cursor rested on it for the delay, hidden when the element gets pressed or the cursor leaves it
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Tooltip {
+    pub content: String,
+    pub delay: Duration,
+    pub placement: TooltipPlacement,
+}
+
+impl Tooltip {
+    pub fn new(content: impl Into<String>) -> Tooltip {
+        Tooltip {
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Tooltip {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_placement(mut self, placement: TooltipPlacement) -> Tooltip {
+        self.placement = placement;
+        self
+    }
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self {
+            content: String::new(),
+            delay: Duration::from_millis(500),
+            placement: Default::default(),
+        }
+    }
+}
+
+// The preferred side, the tooltip flips to the opposite one when it doesn't fit in the window
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+pub enum TooltipPlacement {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+// Counts how long the cursor has been resting on the element
+#[derive(Component)]
+struct TooltipHover(Timer);
+
+#[derive(Component)]
+struct ShownTooltip(Entity);
+
+#[derive(Component)]
+struct TooltipPopup {
+    element: Entity,
+}
+
+#[derive(Component)]
+struct TooltipText;
+
+// Despawns the popup once it faded out
+#[derive(Component)]
+struct FadingTooltip(Timer);
+
+fn track_tooltip_hovers(
+    mut commands: Commands,
+    tooltips: Query<(&Tooltip, Option<&ShownTooltip>)>,
+    popups: Query<&Children, With<TooltipPopup>>,
+    mut hover_enter_events: EventReader<HoverEnterEvent>,
+    mut hover_exit_events: EventReader<HoverExitEvent>,
+    mut press_events: EventReader<PressEvent>,
+) {
+    for hover_enter_event in hover_enter_events.read() {
+        if let Ok((tooltip, _)) = tooltips.get(hover_enter_event.element) {
+            commands
+                .entity(hover_enter_event.element)
+                .insert(TooltipHover(Timer::new(tooltip.delay, TimerMode::Once)));
+        }
+    }
+
+    // Pressing hides the tooltip until the cursor enters the element again
+    let hidden_elements = hover_exit_events
+        .read()
+        .map(|hover_exit_event| hover_exit_event.element)
+        .chain(press_events.read().map(|press_event| press_event.element));
+
+    for element in hidden_elements {
+        let Ok((_, shown_tooltip)) = tooltips.get(element) else {
+            continue;
+        };
+
+        commands
+            .entity(element)
+            .remove::<(TooltipHover, ShownTooltip)>();
+
+        if let Some(ShownTooltip(popup)) = shown_tooltip {
+            hide_tooltip(&mut commands, *popup, popups.get(*popup).ok());
+        }
+    }
+}
+
+fn hide_tooltip(commands: &mut Commands, popup: Entity, children: Option<&Children>) {
+    let Some(mut popup_commands) = commands.get_entity(popup) else {
+        return;
+    };
+
+    popup_commands.insert((
+        Transition::new(ColoredElement::new(Color::NONE), FADE_DURATION),
+        FadingTooltip(Timer::new(FADE_DURATION, TimerMode::Once)),
+    ));
+
+    for child in children.into_iter().flatten() {
+        commands.entity(*child).insert(Transition::new(
+            ColoredElement::new(TOOLTIP_TEXT_COLOR.with_a(0.0)),
+            FADE_DURATION,
+        ));
+    }
+}
+
+// The popup starts transparent & gets sized to its text by fit_tooltips once it's shaped, before it finishes fading in
+fn show_tooltips(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tooltips: Query<(Entity, &Tooltip, &mut TooltipHover, &Position), Without<ShownTooltip>>,
+) {
+    for (entity, tooltip, mut hover, position) in tooltips.iter_mut() {
+        if !hover.0.tick(time.delta()).finished() {
+            continue;
+        }
+
+        let popup = commands
+            .spawn((
+                UiBoxBundle {
+                    position: position.clone(),
+                    color: ColoredElement::new(Color::NONE),
+                    ..Default::default()
+                },
+                CornersRoundness::from_scalar(0.2),
+                Transition::new(ColoredElement::new(TOOLTIP_COLOR), FADE_DURATION),
+                Overlay,
+                TOOLTIP_Z_INDEX,
+                TooltipPopup { element: entity },
+            ))
+            .with_children(|popup| {
+                popup.spawn((
+                    UiTextBundle {
+                        text: UiText(tooltip.content.clone()),
+                        font_size: FontSize(16),
+                        color: ColoredElement::new(TOOLTIP_TEXT_COLOR.with_a(0.0)),
+                        position: position.clone(),
+                        size: Size::new(TOOLTIP_MAX_WIDTH, u32::MAX / 2),
+                        ..Default::default()
+                    },
+                    Transition::new(ColoredElement::new(TOOLTIP_TEXT_COLOR), FADE_DURATION),
+                    TooltipText,
+                ));
+            })
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(ShownTooltip(popup))
+            .add_child(popup);
+    }
+}
+
+fn update_tooltip_texts(
+    tooltips: Query<(&Tooltip, &ShownTooltip), Changed<Tooltip>>,
+    popups: Query<&Children, With<TooltipPopup>>,
+    mut texts: Query<(&mut UiText, &mut Size), With<TooltipText>>,
+) {
+    for (tooltip, ShownTooltip(popup)) in tooltips.iter() {
+        let Ok(children) = popups.get(*popup) else {
+            continue;
+        };
+
+        let mut tooltip_texts = texts.iter_many_mut(children);
+
+        while let Some((mut text, mut size)) = tooltip_texts.fetch_next() {
+            if text.0 != tooltip.content {
+                text.0 = tooltip.content.clone();
+                // Lets the text wrap at the maximum width again before getting fitted
+                *size = Size::new(TOOLTIP_MAX_WIDTH, u32::MAX / 2);
+            }
+        }
+    }
+}
+
+// Sizes the popup around its shaped text & places it on the preferred side of its element, inside the window
+fn fit_tooltips(
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    texts: Query<(Entity, &TextLayoutInfo, &Parent), (With<TooltipText>, Changed<TextLayoutInfo>)>,
+    mut elements: Query<(
+        &mut Position,
+        &mut Size,
+        Option<&TooltipPopup>,
+        Option<&Tooltip>,
+    )>,
+) {
+    if texts.is_empty() {
+        return;
+    }
+
+    let Ok(primary_window) = primary_window.get_single() else {
+        warn!("Couldn't get primary window");
+
+        return;
+    };
+
+    let window_size = Size::new(
+        primary_window.width() as u32,
+        primary_window.height() as u32,
+    );
+
+    for (text_entity, layout_info, parent) in texts.iter() {
+        let popup_entity = parent.get();
+
+        let Ok((_, _, Some(popup), _)) = elements.get(popup_entity) else {
+            continue;
+        };
+
+        let element_entity = popup.element;
+
+        let Ok((element_position, element_size, _, Some(tooltip))) = elements.get(element_entity)
+        else {
+            continue;
+        };
+
+        let text_width = layout_info
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.rect.max.x)
+            .fold(0.0f32, f32::max)
+            .ceil() as u32;
+        let text_height = layout_info
+            .lines
+            .last()
+            .map_or(0.0, |line| line.top + line.height)
+            .ceil() as u32;
+
+        let text_size = Size::new(text_width + 1, text_height);
+        let popup_size = Size::new(
+            text_size.width + TOOLTIP_PADDING * 2,
+            text_size.height + TOOLTIP_PADDING * 2,
+        );
+
+        let popup_position = tooltip_position(
+            tooltip.placement,
+            element_position,
+            element_size,
+            &popup_size,
+            &window_size,
+        );
+        let text_position = Position::new(
+            popup_position.x + TOOLTIP_PADDING,
+            popup_position.y + TOOLTIP_PADDING,
+        );
+
+        for (entity, position, size) in [
+            (popup_entity, popup_position, popup_size),
+            (text_entity, text_position, text_size),
+        ] {
+            let Ok((mut current_position, mut current_size, _, _)) = elements.get_mut(entity)
+            else {
+                continue;
+            };
+
+            if *current_position != position {
+                *current_position = position;
+            }
+
+            if *current_size != size {
+                *current_size = size;
+            }
+        }
+    }
+}
+
+fn tooltip_position(
+    placement: TooltipPlacement,
+    element_position: &Position,
+    element_size: &Size,
+    popup_size: &Size,
+    window_size: &Size,
+) -> Position {
+    let (element_x, element_y) = (element_position.x as i64, element_position.y as i64);
+    let (element_width, element_height) = (element_size.width as i64, element_size.height as i64);
+    let (popup_width, popup_height) = (popup_size.width as i64, popup_size.height as i64);
+    let (window_width, window_height) = (window_size.width as i64, window_size.height as i64);
+    let gap = TOOLTIP_GAP as i64;
+
+    let above = element_y - gap - popup_height;
+    let below = element_y + element_height + gap;
+    let before = element_x - gap - popup_width;
+    let after = element_x + element_width + gap;
+
+    let fits_above = above >= 0;
+    let fits_below = below + popup_height <= window_height;
+    let fits_before = before >= 0;
+    let fits_after = after + popup_width <= window_width;
+
+    let centered_x = element_x + (element_width - popup_width) / 2;
+    let centered_y = element_y + (element_height - popup_height) / 2;
+
+    let (x, y) = match placement {
+        TooltipPlacement::Top if fits_above || !fits_below => (centered_x, above),
+        TooltipPlacement::Top | TooltipPlacement::Bottom if fits_below || !fits_above => {
+            (centered_x, below)
+        }
+        TooltipPlacement::Bottom => (centered_x, above),
+        TooltipPlacement::Left if fits_before || !fits_after => (before, centered_y),
+        TooltipPlacement::Left | TooltipPlacement::Right if fits_after || !fits_before => {
+            (after, centered_y)
+        }
+        TooltipPlacement::Right => (before, centered_y),
+    };
+
+    Position::new(
+        x.clamp(0, (window_width - popup_width).max(0)) as u32,
+        y.clamp(0, (window_height - popup_height).max(0)) as u32,
+    )
+}
+
+fn despawn_faded_tooltips(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut fading_tooltips: Query<(Entity, &mut FadingTooltip)>,
+) {
+    for (entity, mut fading_tooltip) in fading_tooltips.iter_mut() {
+        if fading_tooltip.0.tick(time.delta()).finished() {
+            if let Some(entity_commands) = commands.get_entity(entity) {
+                entity_commands.despawn_recursive();
+            }
+        }
+    }
+}
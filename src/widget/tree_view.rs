@@ -0,0 +1,1044 @@
+use std::{collections::HashSet, time::Duration};
+
+use bevy::{
+    hierarchy::HierarchyQueryExt,
+    log::warn,
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component, Entity, Event,
+        EventReader, EventWriter, Has, Input, IntoSystemConfigs, KeyCode, MouseButton, Or,
+        ParamSet, Parent, Plugin, Query, ReflectComponent, Res, Update, Vec2, With, Without,
+    },
+    reflect::Reflect,
+    window::{PrimaryWindow, Window},
+};
+
+use crate::{
+    element::{
+        r#box::{UiBox, UiBoxBundle},
+        text::{FontSize, TextOverflow, UiText, UiTextBundle, VerticalAlign},
+    },
+    event::{on_mouse_click_start, ClickEvent, PressEvent},
+    property::{
+        collision::BoxCollisionBundle,
+        focus::{Focusable, Focused},
+        state::{disabled::Disabled, hover::HoverEffect},
+        transition::Transition,
+        update::{AutoVisibleRegionUpdate, AutoZUpdate},
+        ColoredElement, Position, Size, VisibleRegion, ZLevel,
+    },
+    widget::ACCENT_COLOR,
+};
+
+pub(crate) struct UiTreeViewPlugin;
+
+impl Plugin for UiTreeViewPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_event::<TreeNodeToggledEvent>()
+            .add_event::<TreeSelectionChangedEvent>()
+            .add_event::<TreeNodeDroppedEvent>()
+            .add_systems(
+                Update,
+                (
+                    spawn_tree_parts,
+                    (
+                        select_tree_nodes.after(on_mouse_click_start),
+                        drag_tree_nodes,
+                        layout_tree_views,
+                        highlight_tree_rows,
+                    )
+                        .chain(),
+                ),
+            );
+    }
+}
+
+const TOGGLE_DURATION: Duration = Duration::from_millis(150);
+// How far the cursor moves with a row pressed before it starts dragging the selection
+const DRAG_THRESHOLD: f32 = 4.0;
+
+const SELECTED_ROW_COLOR: Color = Color::rgba(0.2, 0.4, 0.9, 0.35);
+const CURSOR_ROW_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.06);
+
+// Lays out the TreeNodes among its children as rows, indented by their depth & with their children below them
+// It doesn't scroll, rows past its bottom get clipped
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct TreeView {
+    // In the order they got selected
+    pub selected: Vec<Entity>,
+    pub row_height: u32,
+    pub indent: u32,
+}
+
+impl Default for TreeView {
+    fn default() -> Self {
+        Self {
+            selected: Vec::new(),
+            row_height: 28,
+            indent: 20,
+        }
+    }
+}
+
+// A row of a TreeView, its child nodes are the TreeNodes among its children
+// Its size covers its row & its expanded child nodes, collapsing it shrinks it over them
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct TreeNode {
+    pub label: String,
+    pub expanded: bool,
+}
+
+impl TreeNode {
+    pub fn new(label: impl Into<String>) -> TreeNode {
+        TreeNode {
+            label: label.into(),
+            expanded: false,
+        }
+    }
+
+    pub fn with_expanded(mut self, expanded: bool) -> TreeNode {
+        self.expanded = expanded;
+        self
+    }
+}
+
+// Sent when the user expands or collapses a node, not when TreeNode::expanded gets changed by code
+#[derive(Event, Debug, Clone)]
+pub struct TreeNodeToggledEvent {
+    pub tree: Entity,
+    pub node: Entity,
+    pub expanded: bool,
+}
+
+// Sent when the user changes the selection
+#[derive(Event, Debug, Clone)]
+pub struct TreeSelectionChangedEvent {
+    pub tree: Entity,
+    pub selected: Vec<Entity>,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+pub enum TreeDropPlacement {
+    Before,
+    Into,
+    After,
+}
+
+// Sent when dragged nodes get dropped on another node of their tree, moving them is left to the app
+// Never sent for drops on the dragged nodes or on their descendants
+#[derive(Event, Debug, Clone)]
+pub struct TreeNodeDroppedEvent {
+    pub tree: Entity,
+    pub nodes: Vec<Entity>,
+    pub target: Entity,
+    pub placement: TreeDropPlacement,
+}
+
+#[derive(Bundle)]
+pub struct UiTreeViewBundle {
+    pub tree_view: TreeView,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+
+    pub collision: BoxCollisionBundle,
+    pub focusable: Focusable,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl Default for UiTreeViewBundle {
+    fn default() -> Self {
+        Self {
+            tree_view: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(280, 400),
+            color: ColoredElement::new(Color::WHITE),
+
+            collision: Default::default(),
+            focusable: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+// Laid out by its TreeView
+#[derive(Bundle)]
+pub struct UiTreeNodeBundle {
+    pub node: TreeNode,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl UiTreeNodeBundle {
+    pub fn new(node: TreeNode) -> UiTreeNodeBundle {
+        UiTreeNodeBundle {
+            node,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for UiTreeNodeBundle {
+    fn default() -> Self {
+        Self {
+            node: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Default::default(),
+            color: ColoredElement::new(Color::NONE),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+// The keyboard cursor & where Shift selections start from, inserted along with the drop indicator
+#[derive(Component, Default)]
+struct TreeViewState {
+    cursor: Option<Entity>,
+    anchor: Option<Entity>,
+}
+
+// Kept apart from TreeViewState, so dragging doesn't restyle the rows every frame
+#[derive(Component, Default)]
+struct TreeViewDrag(Option<TreeDrag>);
+
+struct TreeDrag {
+    nodes: Vec<Entity>,
+    start: Vec2,
+    started: bool,
+    target: Option<(Entity, TreeDropPlacement)>,
+}
+
+#[derive(Component)]
+struct TreeNodeParts {
+    row: Entity,
+    disclosure: Entity,
+    label: Entity,
+    // Animates the node's size when it differs from TreeNode::expanded, None until it's laid out once
+    laid_out_expanded: Option<bool>,
+}
+
+#[derive(Component)]
+struct TreeNodeRow {
+    node: Entity,
+}
+
+#[derive(Component)]
+struct TreeNodeDisclosure {
+    node: Entity,
+}
+
+#[derive(Component)]
+struct TreeDropIndicator;
+
+fn spawn_tree_parts(
+    mut commands: Commands,
+    trees: Query<Entity, Added<TreeView>>,
+    nodes: Query<Entity, Added<TreeNode>>,
+) {
+    for entity in trees.iter() {
+        let drop_indicator = commands
+            .spawn((
+                UiBoxBundle {
+                    size: Size::new(0, 0),
+                    color: ColoredElement::new(Color::NONE),
+                    ..Default::default()
+                },
+                TreeDropIndicator,
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert((TreeViewState::default(), TreeViewDrag::default()))
+            .add_child(drop_indicator);
+    }
+
+    for entity in nodes.iter() {
+        let disclosure = commands
+            .spawn((
+                UiTextBundle {
+                    font_size: FontSize(16),
+                    ..Default::default()
+                },
+                VerticalAlign::Center,
+                BoxCollisionBundle::default(),
+                TreeNodeDisclosure { node: entity },
+            ))
+            .id();
+
+        let label = commands
+            .spawn((
+                UiTextBundle {
+                    font_size: FontSize(18),
+                    ..Default::default()
+                },
+                VerticalAlign::Center,
+                TextOverflow::Ellipsis,
+            ))
+            .id();
+
+        let row = commands
+            .spawn((
+                UiBoxBundle {
+                    color: ColoredElement::new(Color::NONE),
+                    ..Default::default()
+                },
+                HoverEffect::new(ColoredElement::new(ACCENT_COLOR.with_a(0.15))),
+                BoxCollisionBundle::default(),
+                TreeNodeRow { node: entity },
+            ))
+            .push_children(&[disclosure, label])
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(TreeNodeParts {
+                row,
+                disclosure,
+                label,
+                laid_out_expanded: None,
+            })
+            .insert_children(0, &[row]);
+    }
+}
+
+// Depth first, skipping the children of collapsed nodes
+fn visible_nodes(
+    children: &[Entity],
+    nodes: &Query<(&TreeNode, Option<&Children>)>,
+    visible: &mut Vec<Entity>,
+) {
+    for child in children {
+        let Ok((node, node_children)) = nodes.get(*child) else {
+            continue;
+        };
+
+        visible.push(*child);
+
+        if let (true, Some(node_children)) = (node.expanded, node_children) {
+            visible_nodes(node_children, nodes, visible);
+        }
+    }
+}
+
+fn has_child_nodes(
+    node_children: Option<&Children>,
+    nodes: &Query<(&TreeNode, Option<&Children>)>,
+) -> bool {
+    node_children
+        .is_some_and(|node_children| node_children.iter().any(|child| nodes.contains(*child)))
+}
+
+// Pressing a row selects it (Ctrl toggles it, Shift selects the rows from the last one selected),
+// pressing a selected row keeps the selection for dragging it & clicking it selects only it
+// Pressing the disclosure arrow expands or collapses the node
+// While focused: Up / Down / Home / End move the cursor (with Shift to extend the selection & Ctrl to leave it),
+// Left / Right collapse / expand or go to the parent / first child, Enter toggles & Space selects the cursor's node
+#[allow(clippy::too_many_arguments)]
+fn select_tree_nodes(
+    keys: Res<Input<KeyCode>>,
+    mut trees: Query<
+        (
+            Entity,
+            &mut TreeView,
+            &mut TreeViewState,
+            &TreeViewDrag,
+            &Children,
+            Has<Focused>,
+        ),
+        Without<Disabled>,
+    >,
+    mut nodes: ParamSet<(Query<(&TreeNode, Option<&Children>)>, Query<&mut TreeNode>)>,
+    rows: Query<&TreeNodeRow>,
+    disclosures: Query<&TreeNodeDisclosure>,
+    parents: Query<&Parent>,
+    mut press_events: EventReader<PressEvent>,
+    mut click_events: EventReader<ClickEvent>,
+    mut toggled_events: EventWriter<TreeNodeToggledEvent>,
+    mut selection_changed_events: EventWriter<TreeSelectionChangedEvent>,
+) {
+    let pressed = press_events
+        .read()
+        .map(|press_event| press_event.element)
+        .collect::<Vec<_>>();
+    let clicked = click_events
+        .read()
+        .map(|click_event| click_event.element)
+        .collect::<Vec<_>>();
+
+    // Pressing the arrow also presses its row, it doesn't change the selection though
+    let pressed_disclosures = pressed
+        .iter()
+        .filter_map(|element| disclosures.get(*element).ok())
+        .map(|disclosure| disclosure.node)
+        .collect::<Vec<_>>();
+    let clicked_disclosures = clicked
+        .iter()
+        .filter_map(|element| disclosures.get(*element).ok())
+        .map(|disclosure| disclosure.node)
+        .collect::<Vec<_>>();
+
+    let pressed_rows = pressed
+        .iter()
+        .filter_map(|element| rows.get(*element).ok())
+        .map(|row| row.node)
+        .filter(|node| !pressed_disclosures.contains(node))
+        .collect::<Vec<_>>();
+    let clicked_rows = clicked
+        .iter()
+        .filter_map(|element| rows.get(*element).ok())
+        .map(|row| row.node)
+        .filter(|node| !clicked_disclosures.contains(node))
+        .collect::<Vec<_>>();
+
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let mut toggles = Vec::new();
+
+    for (entity, mut tree, mut state, drag, tree_children, focused) in trees.iter_mut() {
+        let nodes = nodes.p0();
+
+        let mut visible = Vec::new();
+        visible_nodes(tree_children, &nodes, &mut visible);
+
+        let mut selected = tree.selected.clone();
+        selected.retain(|node| nodes.contains(*node));
+
+        let mut cursor = state.cursor.filter(|cursor| visible.contains(cursor));
+        let mut anchor = state.anchor.filter(|anchor| visible.contains(anchor));
+
+        let select_range = |anchor: Option<Entity>, node: Entity| {
+            let node_index = visible.iter().position(|visible| *visible == node);
+            let anchor_index = anchor
+                .and_then(|anchor| visible.iter().position(|visible| *visible == anchor))
+                .or(node_index);
+
+            match (anchor_index, node_index) {
+                (Some(anchor_index), Some(node_index)) => {
+                    visible[anchor_index.min(node_index)..=anchor_index.max(node_index)].to_vec()
+                }
+                _ => vec![node],
+            }
+        };
+
+        for node in pressed_disclosures
+            .iter()
+            .filter(|node| visible.contains(node))
+        {
+            let has_child_nodes = nodes
+                .get(*node)
+                .is_ok_and(|(_, node_children)| has_child_nodes(node_children, &nodes));
+
+            if has_child_nodes {
+                toggles.push((entity, *node));
+            }
+        }
+
+        for node in pressed_rows.iter().filter(|node| visible.contains(node)) {
+            cursor = Some(*node);
+
+            if ctrl {
+                match selected.iter().position(|selected| selected == node) {
+                    Some(index) => {
+                        selected.remove(index);
+                    }
+                    None => selected.push(*node),
+                }
+
+                anchor = Some(*node);
+            } else if shift {
+                selected = select_range(anchor, *node);
+            } else if !selected.contains(node) {
+                selected = vec![*node];
+                anchor = Some(*node);
+            }
+        }
+
+        let dragged = drag.0.as_ref().is_some_and(|drag| drag.started);
+
+        if !ctrl && !shift && !dragged {
+            for node in clicked_rows.iter().filter(|node| visible.contains(node)) {
+                selected = vec![*node];
+                anchor = Some(*node);
+            }
+        }
+
+        if focused && !visible.is_empty() {
+            let cursor_index =
+                cursor.and_then(|cursor| visible.iter().position(|node| *node == cursor));
+            let last_index = visible.len() - 1;
+
+            let cursor_node = cursor_index.and_then(|cursor_index| {
+                let node = visible[cursor_index];
+
+                nodes.get(node).ok().map(|(tree_node, node_children)| {
+                    (
+                        node,
+                        tree_node.expanded,
+                        has_child_nodes(node_children, &nodes),
+                    )
+                })
+            });
+
+            let moved_to = if keys.just_pressed(KeyCode::Up) {
+                Some(cursor_index.map_or(0, |cursor_index| cursor_index.saturating_sub(1)))
+            } else if keys.just_pressed(KeyCode::Down) {
+                Some(cursor_index.map_or(0, |cursor_index| (cursor_index + 1).min(last_index)))
+            } else if keys.just_pressed(KeyCode::Home) {
+                Some(0)
+            } else if keys.just_pressed(KeyCode::End) {
+                Some(last_index)
+            } else if keys.just_pressed(KeyCode::Left) {
+                match cursor_node {
+                    Some((node, true, true)) => {
+                        toggles.push((entity, node));
+                        None
+                    }
+                    Some((node, _, _)) => parents
+                        .get(node)
+                        .ok()
+                        .and_then(|parent| visible.iter().position(|node| *node == parent.get())),
+                    None => None,
+                }
+            } else if keys.just_pressed(KeyCode::Right) {
+                match (cursor_node, cursor_index) {
+                    (Some((node, false, true)), _) => {
+                        toggles.push((entity, node));
+                        None
+                    }
+                    (Some((_, true, true)), Some(cursor_index)) => Some(cursor_index + 1),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+            if let Some((node, _, true)) = cursor_node {
+                if keys.any_just_pressed([KeyCode::Return, KeyCode::NumpadEnter]) {
+                    toggles.push((entity, node));
+                }
+            }
+
+            if let (Some((node, _, _)), true) = (cursor_node, keys.just_pressed(KeyCode::Space)) {
+                if ctrl {
+                    match selected.iter().position(|selected| *selected == node) {
+                        Some(index) => {
+                            selected.remove(index);
+                        }
+                        None => selected.push(node),
+                    }
+                } else {
+                    selected = vec![node];
+                }
+
+                anchor = Some(node);
+            }
+
+            if let Some(moved_to) = moved_to {
+                let node = visible[moved_to];
+                cursor = Some(node);
+
+                if shift {
+                    selected = select_range(anchor, node);
+                } else if !ctrl {
+                    selected = vec![node];
+                    anchor = Some(node);
+                }
+            }
+        }
+
+        if tree.selected != selected {
+            tree.selected = selected.clone();
+            selection_changed_events.send(TreeSelectionChangedEvent {
+                tree: entity,
+                selected,
+            });
+        }
+
+        if state.cursor != cursor || state.anchor != anchor {
+            state.cursor = cursor;
+            state.anchor = anchor;
+        }
+    }
+
+    let mut nodes = nodes.p1();
+
+    for (tree, node) in toggles {
+        let Ok(mut tree_node) = nodes.get_mut(node) else {
+            continue;
+        };
+
+        tree_node.expanded = !tree_node.expanded;
+        toggled_events.send(TreeNodeToggledEvent {
+            tree,
+            node,
+            expanded: tree_node.expanded,
+        });
+    }
+}
+
+// Pressing a selected row & moving the cursor drags the selection, the drop indicator shows where it would go:
+// before / after the row under the cursor near its top / bottom edge, or into it around its middle
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn drag_tree_nodes(
+    mouse_buttons: Res<Input<MouseButton>>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    mut trees: Query<(Entity, &TreeView, &mut TreeViewDrag, &Children), Without<Disabled>>,
+    nodes: Query<(&TreeNode, Option<&Children>)>,
+    parts: Query<&TreeNodeParts>,
+    rows: Query<(&TreeNodeRow, &Position, &Size)>,
+    disclosures: Query<&TreeNodeDisclosure>,
+    parents: Query<&Parent>,
+    mut indicators: Query<
+        (&mut Position, &mut Size, &mut ColoredElement),
+        (With<TreeDropIndicator>, Without<TreeNodeRow>),
+    >,
+    mut press_events: EventReader<PressEvent>,
+    mut dropped_events: EventWriter<TreeNodeDroppedEvent>,
+) {
+    let pressed = press_events
+        .read()
+        .map(|press_event| press_event.element)
+        .collect::<Vec<_>>();
+
+    // Pressing the arrow toggles the node instead of dragging it
+    let pressed_disclosures = pressed
+        .iter()
+        .filter_map(|element| disclosures.get(*element).ok())
+        .map(|disclosure| disclosure.node)
+        .collect::<Vec<_>>();
+    let pressed_rows = pressed
+        .iter()
+        .filter_map(|element| rows.get(*element).ok())
+        .map(|(row, _, _)| row.node)
+        .filter(|node| !pressed_disclosures.contains(node))
+        .collect::<Vec<_>>();
+
+    let Ok(primary_window) = primary_window.get_single() else {
+        warn!("Couldn't get primary window");
+
+        return;
+    };
+
+    let cursor_position = primary_window.cursor_position();
+
+    for (entity, tree, mut drag, tree_children) in trees.iter_mut() {
+        if let (Some(cursor_position), true) = (cursor_position, drag.0.is_none()) {
+            let pressed_node = pressed_rows
+                .iter()
+                .find(|node| tree.selected.contains(node));
+
+            if pressed_node.is_some() {
+                drag.0 = Some(TreeDrag {
+                    nodes: tree.selected.clone(),
+                    start: cursor_position,
+                    started: false,
+                    target: None,
+                });
+            }
+        }
+
+        let Some(current_drag) = drag.0.as_mut() else {
+            continue;
+        };
+
+        if !mouse_buttons.pressed(MouseButton::Left) {
+            if let (true, Some((target, placement))) = (current_drag.started, current_drag.target) {
+                dropped_events.send(TreeNodeDroppedEvent {
+                    tree: entity,
+                    nodes: current_drag.nodes.clone(),
+                    target,
+                    placement,
+                });
+            }
+
+            drag.0 = None;
+            update_drop_indicator(tree_children, &mut indicators, None);
+            continue;
+        }
+
+        let Some(cursor_position) = cursor_position else {
+            continue;
+        };
+
+        if !current_drag.started {
+            if cursor_position.distance(current_drag.start) < DRAG_THRESHOLD {
+                continue;
+            }
+
+            current_drag.started = true;
+        }
+
+        let mut visible = Vec::new();
+        visible_nodes(tree_children, &nodes, &mut visible);
+
+        let hovered_row = visible.iter().find_map(|node| {
+            let (_, row_position, row_size) = rows.get(parts.get(*node).ok()?.row).ok()?;
+            let offset = cursor_position.y - row_position.y as f32;
+
+            (offset >= 0.0 && offset < row_size.height as f32).then_some((
+                *node,
+                offset / row_size.height as f32,
+                row_position,
+                row_size,
+            ))
+        });
+
+        let target = hovered_row.filter(|(node, _, _, _)| {
+            !current_drag.nodes.contains(node)
+                && !parents
+                    .iter_ancestors(*node)
+                    .any(|ancestor| current_drag.nodes.contains(&ancestor))
+        });
+
+        let (target, indicator) = match target {
+            Some((node, fraction, row_position, row_size)) => {
+                let placement = if fraction < 0.25 {
+                    TreeDropPlacement::Before
+                } else if fraction > 0.75 {
+                    TreeDropPlacement::After
+                } else {
+                    TreeDropPlacement::Into
+                };
+
+                let depth = parents
+                    .iter_ancestors(node)
+                    .filter(|ancestor| nodes.contains(*ancestor))
+                    .count() as u32;
+                let line_x = row_position.x + tree.indent * (depth + 1);
+                let line_width = row_size.width.saturating_sub(tree.indent * (depth + 1));
+
+                let indicator = match placement {
+                    TreeDropPlacement::Before => (
+                        Position::new(line_x, row_position.y),
+                        Size::new(line_width, 2),
+                        ACCENT_COLOR,
+                    ),
+                    TreeDropPlacement::After => (
+                        Position::new(line_x, (row_position.y + row_size.height).saturating_sub(2)),
+                        Size::new(line_width, 2),
+                        ACCENT_COLOR,
+                    ),
+                    TreeDropPlacement::Into => (
+                        row_position.clone(),
+                        row_size.clone(),
+                        ACCENT_COLOR.with_a(0.25),
+                    ),
+                };
+
+                (Some((node, placement)), Some(indicator))
+            }
+            None => (None, None),
+        };
+
+        if current_drag.target != target {
+            current_drag.target = target;
+            update_drop_indicator(tree_children, &mut indicators, indicator);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_drop_indicator(
+    tree_children: &Children,
+    indicators: &mut Query<
+        (&mut Position, &mut Size, &mut ColoredElement),
+        (With<TreeDropIndicator>, Without<TreeNodeRow>),
+    >,
+    indicator: Option<(Position, Size, Color)>,
+) {
+    // Hidden by having no size
+    let (new_position, new_size, new_color) =
+        indicator.unwrap_or((Position::new(0, 0), Size::new(0, 0), Color::NONE));
+
+    let mut indicators = indicators.iter_many_mut(tree_children);
+
+    while let Some((mut position, mut size, mut color)) = indicators.fetch_next() {
+        if *position != new_position {
+            *position = new_position.clone();
+        }
+
+        if *size != new_size {
+            *size = new_size.clone();
+        }
+
+        if color.color != new_color {
+            color.color = new_color;
+        }
+    }
+}
+
+struct TreeLayout {
+    x: u32,
+    width: u32,
+    row_height: u32,
+    indent: u32,
+}
+
+// Lays out every tree again when any of them changed, nodes moving along with the ones being toggled
+#[allow(clippy::type_complexity)]
+fn layout_tree_views(
+    mut commands: Commands,
+    trees: Query<(&TreeView, &Position, &Size, &Children)>,
+    mut elements: ParamSet<(
+        Query<
+            (),
+            (
+                Or<(With<TreeView>, With<TreeNode>)>,
+                Or<(
+                    Changed<TreeView>,
+                    Changed<TreeNode>,
+                    Changed<Position>,
+                    Changed<Size>,
+                    Changed<Children>,
+                )>,
+            ),
+        >,
+        Query<(&mut Position, &mut Size), Without<TreeView>>,
+    )>,
+    mut nodes: Query<(
+        &TreeNode,
+        &mut TreeNodeParts,
+        Has<Transition<Size>>,
+        Option<&Children>,
+    )>,
+    mut texts: Query<&mut UiText>,
+) {
+    if elements.p0().is_empty() {
+        return;
+    }
+
+    let mut rects = elements.p1();
+
+    for (tree, position, size, tree_children) in trees.iter() {
+        let layout = TreeLayout {
+            x: position.x,
+            width: size.width,
+            row_height: tree.row_height,
+            indent: tree.indent,
+        };
+
+        layout_tree_nodes(
+            &mut commands,
+            &mut nodes,
+            &mut rects,
+            &mut texts,
+            &layout,
+            tree_children.to_vec(),
+            0,
+            position.y,
+        );
+    }
+}
+
+// Lays out the nodes from the top down & returns the height they currently take
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn layout_tree_nodes(
+    commands: &mut Commands,
+    nodes: &mut Query<(
+        &TreeNode,
+        &mut TreeNodeParts,
+        Has<Transition<Size>>,
+        Option<&Children>,
+    )>,
+    rects: &mut Query<(&mut Position, &mut Size), Without<TreeView>>,
+    texts: &mut Query<&mut UiText>,
+    layout: &TreeLayout,
+    children: Vec<Entity>,
+    depth: u32,
+    top: u32,
+) -> u32 {
+    let mut y = top;
+
+    for child in children {
+        let Ok((_, _, _, node_children)) = nodes.get(child) else {
+            continue;
+        };
+
+        let node_children = node_children
+            .map(|node_children| node_children.to_vec())
+            .unwrap_or_default();
+        let has_child_nodes = node_children.iter().any(|child| nodes.contains(*child));
+
+        let child_nodes_height = layout_tree_nodes(
+            commands,
+            nodes,
+            rects,
+            texts,
+            layout,
+            node_children,
+            depth + 1,
+            y + layout.row_height,
+        );
+
+        let Ok((node, mut parts, transitioning, _)) = nodes.get_mut(child) else {
+            continue;
+        };
+
+        let expanded_height = if node.expanded { child_nodes_height } else { 0 };
+        let target_size = Size::new(layout.width, layout.row_height + expanded_height);
+
+        set_rect(rects, child, Position::new(layout.x, y), None);
+
+        // Toggled nodes grow or shrink over their child nodes, the nodes below them follow along
+        let height = match parts.laid_out_expanded {
+            Some(laid_out_expanded) if laid_out_expanded != node.expanded => {
+                commands
+                    .entity(child)
+                    .insert(Transition::new(target_size, TOGGLE_DURATION));
+
+                rects
+                    .get(child)
+                    .map_or(layout.row_height, |(_, size)| size.height)
+            }
+            _ if transitioning => rects
+                .get(child)
+                .map_or(layout.row_height, |(_, size)| size.height),
+            _ => {
+                let height = target_size.height;
+                set_rect(rects, child, Position::new(layout.x, y), Some(target_size));
+
+                height
+            }
+        };
+
+        if parts.laid_out_expanded != Some(node.expanded) {
+            parts.laid_out_expanded = Some(node.expanded);
+        }
+
+        let label_x = layout.x + layout.indent * (depth + 1);
+
+        set_rect(
+            rects,
+            parts.row,
+            Position::new(layout.x, y),
+            Some(Size::new(layout.width, layout.row_height)),
+        );
+        set_rect(
+            rects,
+            parts.disclosure,
+            Position::new(layout.x + layout.indent * depth, y),
+            Some(Size::new(layout.indent, layout.row_height)),
+        );
+        set_rect(
+            rects,
+            parts.label,
+            Position::new(label_x, y),
+            Some(Size::new(
+                (layout.x + layout.width).saturating_sub(label_x),
+                layout.row_height,
+            )),
+        );
+
+        let disclosure_text = match (has_child_nodes, node.expanded) {
+            (false, _) => "",
+            (true, false) => "▸",
+            (true, true) => "▾",
+        };
+
+        set_text(texts, parts.disclosure, disclosure_text);
+        set_text(texts, parts.label, &node.label);
+
+        y += height;
+    }
+
+    y - top
+}
+
+fn set_rect(
+    rects: &mut Query<(&mut Position, &mut Size), Without<TreeView>>,
+    entity: Entity,
+    new_position: Position,
+    new_size: Option<Size>,
+) {
+    let Ok((mut position, mut size)) = rects.get_mut(entity) else {
+        return;
+    };
+
+    if *position != new_position {
+        *position = new_position;
+    }
+
+    if let Some(new_size) = new_size {
+        if *size != new_size {
+            *size = new_size;
+        }
+    }
+}
+
+fn set_text(texts: &mut Query<&mut UiText>, entity: Entity, new_text: &str) {
+    let Ok(mut text) = texts.get_mut(entity) else {
+        return;
+    };
+
+    if text.0 != new_text {
+        text.0 = new_text.to_string();
+    }
+}
+
+// Restyles the rows when the selection or the cursor moves, their hover effect still shows on top of it
+fn highlight_tree_rows(
+    trees: Query<(&TreeView, &TreeViewState)>,
+    changed_trees: Query<(), Or<(Changed<TreeView>, Changed<TreeViewState>)>>,
+    added_rows: Query<(), Added<TreeNodeRow>>,
+    mut rows: Query<(&TreeNodeRow, &mut ColoredElement)>,
+) {
+    if changed_trees.is_empty() && added_rows.is_empty() {
+        return;
+    }
+
+    let selected = trees
+        .iter()
+        .flat_map(|(tree, _)| tree.selected.iter().copied())
+        .collect::<HashSet<_>>();
+    let cursors = trees
+        .iter()
+        .filter_map(|(_, state)| state.cursor)
+        .collect::<HashSet<_>>();
+
+    for (row, mut color) in rows.iter_mut() {
+        let new_color = if selected.contains(&row.node) {
+            SELECTED_ROW_COLOR
+        } else if cursors.contains(&row.node) {
+            CURSOR_ROW_COLOR
+        } else {
+            Color::NONE
+        };
+
+        if color.color != new_color {
+            color.color = new_color;
+        }
+    }
+}
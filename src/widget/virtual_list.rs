@@ -0,0 +1,512 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::system::EntityCommands,
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::{
+        Added, BuildChildren, Bundle, Changed, Children, Color, Commands, Component,
+        DespawnRecursiveExt, DetectChanges, Entity, EventReader, IntoSystemConfigs, Or, Parent,
+        Plugin, Query, Ref, ReflectComponent, Update, With, Without,
+    },
+    reflect::Reflect,
+};
+
+use crate::{
+    element::r#box::{UiBox, UiBoxBundle},
+    event::ElementHovered,
+    property::{
+        collision::BoxCollisionBundle,
+        update::{AutoVisibleRegionUpdate, AutoZUpdate},
+        ColoredElement, CornersRoundness, Position, Size, VisibleRegion, ZLevel,
+    },
+};
+
+pub(crate) struct UiVirtualListPlugin;
+
+impl Plugin for UiVirtualListPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_virtual_list_scrollbars,
+                (
+                    scroll_virtual_lists,
+                    measure_virtual_list_rows,
+                    update_virtual_lists,
+                )
+                    .chain(),
+            ),
+        );
+    }
+}
+
+// Rows built beyond each edge of the list, so fast scrolling doesn't show them getting built
+const OVERSCAN_ROWS: usize = 2;
+// Pixels scrolled per wheel line
+const SCROLL_LINE_HEIGHT: f32 = 40.0;
+const SCROLLBAR_WIDTH: u32 = 6;
+const MIN_SCROLLBAR_THUMB_HEIGHT: u32 = 20;
+
+// Scrolls with the mouse wheel while hovered & only keeps entities for the rows in view
+// Rows scrolled out of view get rebuilt for the rows scrolled into view by the VirtualListRowBuilder
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct VirtualList {
+    pub item_count: usize,
+    pub row_height: RowHeight,
+    // Pixels scrolled from the top of the content
+    pub scroll_offset: f32,
+}
+
+impl VirtualList {
+    pub fn new(item_count: usize, row_height: RowHeight) -> VirtualList {
+        VirtualList {
+            item_count,
+            row_height,
+            scroll_offset: 0.0,
+        }
+    }
+}
+
+impl Default for VirtualList {
+    fn default() -> Self {
+        VirtualList::new(0, RowHeight::Fixed(28))
+    }
+}
+
+// Measured rows take the height the builder gives to the row's Size, rows that weren't built yet count with the estimate
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
+pub enum RowHeight {
+    Fixed(u32),
+    Measured { estimate: u32 },
+}
+
+// Where the row being built goes, its content is expected to stay within it
+#[derive(Debug, Clone)]
+pub struct VirtualRow {
+    pub index: usize,
+    pub position: Position,
+    pub size: Size,
+}
+
+// Called with the row's entity each time it gets (re)bound to an item, after its previous children were despawned
+#[derive(Component, Clone)]
+pub struct VirtualListRowBuilder(
+    Arc<dyn Fn(&mut EntityCommands, &VirtualRow) + Send + Sync + 'static>,
+);
+
+impl VirtualListRowBuilder {
+    pub fn new(
+        builder: impl Fn(&mut EntityCommands, &VirtualRow) + Send + Sync + 'static,
+    ) -> VirtualListRowBuilder {
+        VirtualListRowBuilder(Arc::new(builder))
+    }
+}
+
+impl Default for VirtualListRowBuilder {
+    fn default() -> Self {
+        VirtualListRowBuilder::new(|_, _| {})
+    }
+}
+
+#[derive(Bundle)]
+pub struct UiVirtualListBundle {
+    pub list: VirtualList,
+    pub row_builder: VirtualListRowBuilder,
+    pub ui_box: UiBox,
+
+    pub position: Position,
+    pub size: Size,
+    pub color: ColoredElement,
+
+    // Lets the list get hovered, for the mouse wheel to scroll it
+    pub collision: BoxCollisionBundle,
+
+    pub z_level: ZLevel,
+    pub auto_z_update: AutoZUpdate,
+
+    pub visible_region: VisibleRegion,
+    pub auto_visible_region_update: AutoVisibleRegionUpdate,
+}
+
+impl UiVirtualListBundle {
+    pub fn new(list: VirtualList, row_builder: VirtualListRowBuilder) -> UiVirtualListBundle {
+        UiVirtualListBundle {
+            list,
+            row_builder,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for UiVirtualListBundle {
+    fn default() -> Self {
+        Self {
+            list: Default::default(),
+            row_builder: Default::default(),
+            ui_box: Default::default(),
+
+            position: Default::default(),
+            size: Size::new(300, 400),
+            color: ColoredElement::new(Color::WHITE),
+
+            collision: Default::default(),
+
+            z_level: Default::default(),
+            auto_z_update: Default::default(),
+
+            visible_region: Default::default(),
+            auto_visible_region_update: Default::default(),
+        }
+    }
+}
+
+// Measured heights & the resulting row offsets, inserted along with the scrollbar
+#[derive(Component, Default)]
+struct VirtualListLayout {
+    heights: Vec<Option<u32>>,
+    // Top of each row from the top of the content, followed by the content's height
+    offsets: Vec<u32>,
+    dirty: bool,
+    // Item count the rows were last built for
+    bound_item_count: usize,
+}
+
+impl VirtualListLayout {
+    fn update_offsets(&mut self, list: &VirtualList) {
+        let RowHeight::Measured { estimate } = list.row_height else {
+            return;
+        };
+
+        if !self.dirty && self.offsets.len() == list.item_count + 1 {
+            return;
+        }
+
+        self.heights.resize(list.item_count, None);
+        self.offsets.clear();
+        self.offsets.reserve(list.item_count + 1);
+
+        let mut offset = 0;
+        self.offsets.push(offset);
+
+        for height in &self.heights {
+            offset += height.unwrap_or(estimate);
+            self.offsets.push(offset);
+        }
+
+        self.dirty = false;
+    }
+
+    fn row_top(&self, list: &VirtualList, index: usize) -> u32 {
+        match list.row_height {
+            RowHeight::Fixed(height) => height * index as u32,
+            RowHeight::Measured { .. } => self.offsets.get(index).copied().unwrap_or_default(),
+        }
+    }
+
+    fn row_height(&self, list: &VirtualList, index: usize) -> u32 {
+        match list.row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured { estimate } => self
+                .heights
+                .get(index)
+                .copied()
+                .flatten()
+                .unwrap_or(estimate),
+        }
+    }
+
+    fn content_height(&self, list: &VirtualList) -> u32 {
+        self.row_top(list, list.item_count)
+    }
+
+    // The row covering the offset from the top of the content
+    fn row_at(&self, list: &VirtualList, offset: u32) -> usize {
+        let index = match list.row_height {
+            RowHeight::Fixed(height) => (offset / height.max(1)) as usize,
+            RowHeight::Measured { .. } => self
+                .offsets
+                .partition_point(|row_top| *row_top <= offset)
+                .saturating_sub(1),
+        };
+
+        index.min(list.item_count.saturating_sub(1))
+    }
+}
+
+fn max_scroll_offset(layout: &VirtualListLayout, list: &VirtualList, size: &Size) -> f32 {
+    layout.content_height(list).saturating_sub(size.height) as f32
+}
+
+#[derive(Component)]
+struct VirtualListRow {
+    index: usize,
+    // Where the row's top is, rows are only bound while it's inside the window
+    y: i64,
+}
+
+#[derive(Component)]
+struct VirtualListScrollbar;
+
+fn spawn_virtual_list_scrollbars(mut commands: Commands, lists: Query<Entity, Added<VirtualList>>) {
+    for entity in lists.iter() {
+        let scrollbar = commands
+            .spawn((
+                UiBoxBundle {
+                    color: ColoredElement::new(Color::rgba(0.0, 0.0, 0.0, 0.3)),
+                    ..Default::default()
+                },
+                CornersRoundness::from_scalar(1.0),
+                VirtualListScrollbar,
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(VirtualListLayout::default())
+            .add_child(scrollbar);
+    }
+}
+
+fn scroll_virtual_lists(
+    mut lists: Query<(&mut VirtualList, &VirtualListLayout, &Size), With<ElementHovered>>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+) {
+    let scrolled = mouse_wheel_events
+        .read()
+        .map(|mouse_wheel_event| match mouse_wheel_event.unit {
+            MouseScrollUnit::Line => mouse_wheel_event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => mouse_wheel_event.y,
+        })
+        .sum::<f32>();
+
+    if scrolled == 0.0 {
+        return;
+    }
+
+    for (mut list, layout, size) in lists.iter_mut() {
+        let max_scroll_offset = max_scroll_offset(layout, &list, size);
+        let scroll_offset = (list.scroll_offset - scrolled).clamp(0.0, max_scroll_offset);
+
+        if list.scroll_offset != scroll_offset {
+            list.scroll_offset = scroll_offset;
+        }
+    }
+}
+
+// Picks up the heights the row builders gave to the rows of measured lists
+fn measure_virtual_list_rows(
+    rows: Query<(&VirtualListRow, &Size, &Parent), Changed<Size>>,
+    mut lists: Query<(&VirtualList, &mut VirtualListLayout)>,
+) {
+    for (row, size, parent) in rows.iter() {
+        let Ok((list, mut layout)) = lists.get_mut(parent.get()) else {
+            continue;
+        };
+
+        if !matches!(list.row_height, RowHeight::Measured { .. }) {
+            continue;
+        }
+
+        if layout.heights.len() <= row.index {
+            layout.heights.resize(row.index + 1, None);
+        }
+
+        if layout.heights[row.index] != Some(size.height) {
+            layout.heights[row.index] = Some(size.height);
+            layout.dirty = true;
+        }
+    }
+}
+
+// Keeps a row entity for each row in view: rows still in view get moved along with their content,
+// the others get rebuilt for the rows scrolled into view, then spare rows get despawned
+#[allow(clippy::type_complexity)]
+fn update_virtual_lists(
+    mut commands: Commands,
+    mut lists: Query<
+        (
+            Entity,
+            Ref<VirtualList>,
+            &VirtualListRowBuilder,
+            &mut VirtualListLayout,
+            Ref<Position>,
+            Ref<Size>,
+            &Children,
+        ),
+        Or<(
+            Changed<VirtualList>,
+            Changed<VirtualListLayout>,
+            Changed<Position>,
+            Changed<Size>,
+            Changed<Children>,
+        )>,
+    >,
+    rows: Query<&VirtualListRow>,
+    children: Query<&Children>,
+    mut rects: Query<
+        (&mut Position, &mut Size),
+        (Without<VirtualList>, Without<VirtualListLayout>),
+    >,
+    scrollbars: Query<(), With<VirtualListScrollbar>>,
+) {
+    for (entity, list, row_builder, mut layout, position, size, list_children) in lists.iter_mut() {
+        // Updating the offsets isn't a change, it would update the list every frame otherwise
+        layout.bypass_change_detection().update_offsets(&list);
+
+        // Moving or resizing the list or changing its item count rebuilds every row
+        let rebuild_all = layout.bound_item_count != list.item_count
+            || position.is_changed()
+            || size.is_changed();
+        layout.bypass_change_detection().bound_item_count = list.item_count;
+
+        let max_scroll_offset = max_scroll_offset(&layout, &list, &size);
+        let scroll_offset = list.scroll_offset.clamp(0.0, max_scroll_offset) as u32;
+        let row_width = size.width.saturating_sub(SCROLLBAR_WIDTH);
+
+        let visible_rows = if list.item_count == 0 {
+            0..0
+        } else {
+            let first_row = layout
+                .row_at(&list, scroll_offset)
+                .saturating_sub(OVERSCAN_ROWS);
+            let last_row = layout.row_at(&list, scroll_offset + size.height) + OVERSCAN_ROWS;
+
+            first_row..(last_row + 1).min(list.item_count)
+        };
+
+        let row_y = |index: usize| {
+            position.y as i64 + layout.row_top(&list, index) as i64 - scroll_offset as i64
+        };
+
+        let mut bound_rows = Vec::new();
+        let mut spare_rows = Vec::new();
+
+        for child in list_children.iter() {
+            let Ok(row) = rows.get(*child) else {
+                continue;
+            };
+
+            let new_y = row_y(row.index);
+
+            if rebuild_all || !visible_rows.contains(&row.index) || new_y < 0 {
+                spare_rows.push(*child);
+                continue;
+            }
+
+            bound_rows.push(row.index);
+
+            // Moves the row's content by as much as the row moved
+            if new_y != row.y {
+                shift_element(*child, new_y - row.y, &children, &mut rects);
+                commands.entity(*child).insert(VirtualListRow {
+                    index: row.index,
+                    y: new_y,
+                });
+            }
+        }
+
+        // Positions can't go above the top of the window, rows crossing it are left out rather than
+        // being bound at a clamped position where they'd overlap the next row
+        for index in visible_rows.filter(|index| !bound_rows.contains(index) && row_y(*index) >= 0)
+        {
+            let y = row_y(index);
+            let virtual_row = VirtualRow {
+                index,
+                position: Position::new(position.x, y as u32),
+                size: Size::new(row_width, layout.row_height(&list, index)),
+            };
+
+            let row = match spare_rows.pop() {
+                Some(row) => {
+                    commands.entity(row).despawn_descendants();
+                    row
+                }
+                None => {
+                    let row = commands
+                        .spawn(UiBoxBundle {
+                            color: ColoredElement::new(Color::NONE),
+                            ..Default::default()
+                        })
+                        .id();
+
+                    commands.entity(entity).add_child(row);
+                    row
+                }
+            };
+
+            let mut row_commands = commands.entity(row);
+            row_commands.insert((
+                VirtualListRow { index, y },
+                virtual_row.position.clone(),
+                virtual_row.size.clone(),
+            ));
+
+            (row_builder.0)(&mut row_commands, &virtual_row);
+        }
+
+        for row in spare_rows {
+            commands.entity(row).despawn_recursive();
+        }
+
+        // The thumb's height shows how much of the content is in view
+        let content_height = layout.content_height(&list);
+        let thumb_height = if content_height > size.height {
+            ((size.height as u64 * size.height as u64 / content_height as u64) as u32)
+                .max(MIN_SCROLLBAR_THUMB_HEIGHT)
+                .min(size.height)
+        } else {
+            0
+        };
+        let thumb_y = if max_scroll_offset > 0.0 {
+            size.height.saturating_sub(thumb_height) as f32 * scroll_offset as f32
+                / max_scroll_offset
+        } else {
+            0.0
+        };
+
+        let thumb_rect = (
+            Position::new(position.x + row_width, position.y + thumb_y as u32),
+            Size::new(SCROLLBAR_WIDTH, thumb_height),
+        );
+
+        for child in list_children
+            .iter()
+            .filter(|child| scrollbars.contains(**child))
+        {
+            let Ok((mut thumb_position, mut thumb_size)) = rects.get_mut(*child) else {
+                continue;
+            };
+
+            if *thumb_position != thumb_rect.0 {
+                *thumb_position = thumb_rect.0.clone();
+            }
+
+            if *thumb_size != thumb_rect.1 {
+                *thumb_size = thumb_rect.1.clone();
+            }
+        }
+    }
+}
+
+fn shift_element(
+    entity: Entity,
+    delta: i64,
+    children: &Query<&Children>,
+    rects: &mut Query<
+        (&mut Position, &mut Size),
+        (Without<VirtualList>, Without<VirtualListLayout>),
+    >,
+) {
+    let mut pending_entities = vec![entity];
+
+    while let Some(entity) = pending_entities.pop() {
+        if let Ok((mut position, _)) = rects.get_mut(entity) {
+            position.y = (position.y as i64 + delta).max(0) as u32;
+        }
+
+        if let Ok(entity_children) = children.get(entity) {
+            pending_entities.extend(entity_children.iter());
+        }
+    }
+}